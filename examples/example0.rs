@@ -146,7 +146,7 @@ fn test_column_access() -> AxionResult<()> {
     println!("Name列类型: {:?}", name_col.dtype());
     
     let age_col: &Series<i32> = df.downcast_column("Age")?;
-    println!("Age列值: {:?}", age_col.data);
+    println!("Age列值: {:?}", age_col.data_internal());
     println!("第一个年龄值: {:?}", age_col.get(0));
     
     assert_eq!(age_col.len(), 2);
@@ -165,7 +165,7 @@ fn test_filtering() -> AxionResult<()> {
     // 创建过滤条件
     let age_col: &Series<i32> = df.downcast_column("Age")?;
     let age_mask = age_col.gt(26)?;
-    println!("年龄 > 26的掩码: {:?}", age_mask.data);
+    println!("年龄 > 26的掩码: {:?}", age_mask.data_internal());
     
     // 使用filter方法
     let filtered_df = df.filter(&age_mask)?;
@@ -191,12 +191,12 @@ fn test_series_comparisons() -> AxionResult<()> {
     let ge_mask = series.gte(3)?;
     let le_mask = series.lte(3)?;
     
-    println!("原始数据: {:?}", series.data);
-    println!("值 > 3: {:?}", gt_mask.data);
-    println!("值 < 5: {:?}", lt_mask.data);
-    println!("值 == 5: {:?}", eq_mask.data);
-    println!("值 >= 3: {:?}", ge_mask.data);
-    println!("值 <= 3: {:?}", le_mask.data);
+    println!("原始数据: {:?}", series.data_internal());
+    println!("值 > 3: {:?}", gt_mask.data_internal());
+    println!("值 < 5: {:?}", lt_mask.data_internal());
+    println!("值 == 5: {:?}", eq_mask.data_internal());
+    println!("值 >= 3: {:?}", ge_mask.data_internal());
+    println!("值 <= 3: {:?}", le_mask.data_internal());
     
     Ok(())
 }
@@ -212,13 +212,13 @@ fn test_null_handling() -> AxionResult<()> {
     let null_mask = series_with_nulls.is_null();
     let not_null_mask = series_with_nulls.not_null();
     
-    println!("原始数列: {:?}", series_with_nulls.data);
-    println!("空值掩码: {:?}", null_mask.data);
-    println!("非空值掩码: {:?}", not_null_mask.data);
+    println!("原始数列: {:?}", series_with_nulls.data_internal());
+    println!("空值掩码: {:?}", null_mask.data_internal());
+    println!("非空值掩码: {:?}", not_null_mask.data_internal());
     
     // 使用fill_null方法
     let filled_series = series_with_nulls.fill_null(0);
-    println!("用0填充空值后: {:?}", filled_series.data);
+    println!("用0填充空值后: {:?}", filled_series.data_internal());
     
     // 测试有效值迭代器
     println!("有效值:");
@@ -241,19 +241,19 @@ fn test_arithmetic_operations() -> AxionResult<()> {
     let mul_result = &series1 * &series2;
     let div_result = &series2 / &series1;
     
-    println!("数列1: {:?}", series1.data);
-    println!("数列2: {:?}", series2.data);
-    println!("加法: {:?}", sum_result.data);
-    println!("减法: {:?}", sub_result.data);
-    println!("乘法: {:?}", mul_result.data);
-    println!("除法: {:?}", div_result.data);
+    println!("数列1: {:?}", series1.data_internal());
+    println!("数列2: {:?}", series2.data_internal());
+    println!("加法: {:?}", sum_result.data_internal());
+    println!("减法: {:?}", sub_result.data_internal());
+    println!("乘法: {:?}", mul_result.data_internal());
+    println!("除法: {:?}", div_result.data_internal());
     
     // 与标量运算
     let scalar_mul = &series1 * 2;
     let scalar_add = &series1 + 10;
     
-    println!("数列 * 2: {:?}", scalar_mul.data);
-    println!("数列 + 10: {:?}", scalar_add.data);
+    println!("数列 * 2: {:?}", scalar_mul.data_internal());
+    println!("数列 + 10: {:?}", scalar_add.data_internal());
     
     Ok(())
 }
@@ -282,22 +282,22 @@ fn test_apply_operations() -> AxionResult<()> {
         opt_val.map(|&x| x * x)
     });
     
-    println!("原始数据: {:?}", series.data);
-    println!("平方: {:?}", squared.data);
+    println!("原始数据: {:?}", series.data_internal());
+    println!("平方: {:?}", squared.data_internal());
     
     // 并行apply
     let par_cubed = series.par_apply(|opt_val| {
         opt_val.map(|&x| x * x * x)
     });
     
-    println!("并行立方: {:?}", par_cubed.data);
+    println!("并行立方: {:?}", par_cubed.data_internal());
     
     // 复杂的apply操作
     let complex_transform = series.apply(|opt_val| {
         opt_val.map(|&x| if x % 2 == 0 { x * 10 } else { x })
     });
     
-    println!("复杂变换 (偶数 * 10): {:?}", complex_transform.data);
+    println!("复杂变换 (偶数 * 10): {:?}", complex_transform.data_internal());
     
     assert_eq!(squared.len(), series.len());
     assert_eq!(par_cubed.len(), series.len());
@@ -321,19 +321,19 @@ fn test_join_operations() -> AxionResult<()> {
     println!("右表DataFrame:\n{}", right_df);
     
     // 内连接
-    let inner_joined = left_df.inner_join(&right_df, "key", "key")?;
+    let inner_joined = left_df.inner_join(&right_df, &["key"], &["key"])?;
     println!("内连接结果:\n{}", inner_joined);
     
     // 左连接
-    let left_joined = left_df.left_join(&right_df, "key", "key")?;
+    let left_joined = left_df.left_join(&right_df, &["key"], &["key"])?;
     println!("左连接结果:\n{}", left_joined);
     
     // 右连接
-    let right_joined = left_df.right_join(&right_df, "key", "key")?;
+    let right_joined = left_df.right_join(&right_df, &["key"], &["key"])?;
     println!("右连接结果:\n{}", right_joined);
     
     // 外连接
-    let outer_joined = left_df.outer_join(&right_df, "key", "key")?;
+    let outer_joined = left_df.outer_join(&right_df, &["key"], &["key"])?;
     println!("外连接结果:\n{}", outer_joined);
     
     Ok(())
@@ -377,17 +377,17 @@ fn test_groupby_operations() -> AxionResult<()> {
 fn test_sorting() -> AxionResult<()> {
     let series = Series::new("values".to_string(), vec![30, 10, 25, 5, 20]);
     
-    println!("原始数列: {:?}", series.data);
+    println!("原始数列: {:?}", series.data_internal());
     
     // 测试升序排序
     let mut ascending_series = series.clone();
     ascending_series.sort(false); // false = ascending
-    println!("升序排序: {:?}", ascending_series.data);
+    println!("升序排序: {:?}", ascending_series.data_internal());
     
     // 测试降序排序
     let mut descending_series = series.clone();
     descending_series.sort(true); // true = descending
-    println!("降序排序: {:?}", descending_series.data);
+    println!("降序排序: {:?}", descending_series.data_internal());
     
     // 测试排序状态检查
     println!("升序排序后是否已排序: {}", ascending_series.is_sorted());
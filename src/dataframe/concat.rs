@@ -0,0 +1,124 @@
+//! 拼接操作模块
+//!
+//! 提供多个 DataFrame 的纵向、横向和对角拼接。
+
+use crate::dataframe::DataFrame;
+use crate::error::{AxionError, AxionResult};
+use crate::series::SeriesTrait;
+
+/// 拼接方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConcatType {
+    /// 纵向拼接，所有 DataFrame 必须具有相同的列名集合，同名列的数据类型必须一致，按列名对齐
+    Vertical,
+    /// 横向拼接，所有 DataFrame 的行数必须相同，且列名不能重复
+    Horizontal,
+    /// 对角拼接，按列名对齐并取所有列的并集，缺失的列以 null 填充
+    Diagonal,
+}
+
+/// 拼接多个 DataFrame。
+///
+/// 没有任何列的 DataFrame 会被跳过。
+///
+/// # 参数
+///
+/// * `frames` - 要拼接的 DataFrame 列表，按顺序拼接
+/// * `how` - 拼接方式
+///
+/// # 返回值
+///
+/// 返回拼接后的新 DataFrame，列按首次出现的顺序排列；输入为空时返回空 DataFrame
+///
+/// # 错误
+///
+/// * `AxionError::InvalidArgument` - 纵向拼接时各 DataFrame 的列名集合不一致
+/// * `AxionError::TypeMismatch` - 纵向或对角拼接时同名列的数据类型不一致
+/// * `AxionError::MismatchedLengths` - 横向拼接时行数不一致
+/// * `AxionError::DuplicateColumnName` - 横向拼接时存在重复的列名
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::{concat, df, AxionError, ConcatType};
+/// # let df_2023 = df!("year" => vec![2023], "sales" => vec![10.5])?;
+/// # let df_2024 = df!("year" => vec![2024], "sales" => vec![12.0])?;
+/// # let features = df!("x" => vec![1.0, 2.0])?;
+/// # let labels = df!("y" => vec![0, 1])?;
+/// let stacked = concat(&[df_2023, df_2024], ConcatType::Vertical)?;
+/// let widened = concat(&[features, labels], ConcatType::Horizontal)?;
+/// # assert_eq!(stacked.shape(), (2, 2));
+/// # assert_eq!(widened.shape(), (2, 2));
+/// # Ok::<(), AxionError>(())
+/// ```
+pub fn concat(frames: &[DataFrame], how: ConcatType) -> AxionResult<DataFrame> {
+    let frames: Vec<&DataFrame> = frames.iter().filter(|frame| frame.width() > 0).collect();
+    if frames.is_empty() {
+        return Ok(DataFrame::new_empty());
+    }
+
+    match how {
+        ConcatType::Vertical => {
+            let names = frames[0].columns_names();
+            for frame in &frames[1..] {
+                let same_columns = frame.width() == names.len()
+                    && names.iter().all(|name| frame.column(name).is_ok());
+                if !same_columns {
+                    return Err(AxionError::InvalidArgument(format!(
+                        "纵向拼接要求所有 DataFrame 具有相同的列: {:?} 与 {:?}",
+                        names,
+                        frame.columns_names()
+                    )));
+                }
+            }
+            stack_columns(&frames, &names)
+        }
+        ConcatType::Diagonal => {
+            let mut names: Vec<&str> = Vec::new();
+            for frame in &frames {
+                for name in frame.columns_names() {
+                    if !names.contains(&name) {
+                        names.push(name);
+                    }
+                }
+            }
+            stack_columns(&frames, &names)
+        }
+        ConcatType::Horizontal => {
+            let columns = frames
+                .iter()
+                .flat_map(|frame| frame.columns.iter().map(|col| col.clone_box()))
+                .collect();
+            DataFrame::new(columns)
+        }
+    }
+}
+
+/// 按列名将各 DataFrame 的列依次纵向堆叠，缺失的列以同类型的 null 填充
+fn stack_columns(frames: &[&DataFrame], names: &[&str]) -> AxionResult<DataFrame> {
+    let mut columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(names.len());
+    for name in names {
+        // 用首个包含该列的 DataFrame 作为模板，为缺失该列的 DataFrame 生成 null
+        let template = frames
+            .iter()
+            .find_map(|frame| frame.column(name).ok())
+            .ok_or_else(|| AxionError::ColumnNotFound(name.to_string()))?;
+
+        let mut stacked: Option<Box<dyn SeriesTrait>> = None;
+        for frame in frames {
+            let part = match frame.column(name) {
+                Ok(col) => col.clone_box(),
+                Err(_) => template.take_indices_option(&vec![None; frame.height()])?,
+            };
+            match stacked.as_mut() {
+                Some(target) => target.append_series(part.as_ref())?,
+                None => stacked = Some(part),
+            }
+        }
+        if let Some(mut column) = stacked {
+            column.rename(name);
+            columns.push(column);
+        }
+    }
+    DataFrame::new(columns)
+}
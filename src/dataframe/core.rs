@@ -1,8 +1,11 @@
 use crate::series::{SeriesTrait, Series};
 use crate::dtype::{DataType, DataTypeTrait};
 use crate::error::{AxionError, AxionResult};
-use super::groupby::GroupBy;
-use std::collections::{HashMap, HashSet};
+use super::groupby::{Agg, GroupBy, GroupByOptions};
+use super::reshape::{explode, implode, melt, pivot, unnest};
+use super::dynamic::{groupby_dynamic, ClosedWindow};
+use super::join::{asof_join, cross_join, hash_join, AsofJoinOptions, JoinOptions, JoinType};
+use std::collections::HashMap;
 use std::fmt::{self, Debug};
 use std::cmp::Ordering;
 use rayon::prelude::*;
@@ -27,19 +30,20 @@ use std::path::Path;
 /// # 示例
 /// 
 /// ```rust
-/// use axion::dataframe::DataFrame;
-/// use axion::series::Series;
+/// use axion_data::dataframe::DataFrame;
+/// use axion_data::series::{Series, SeriesTrait};
 /// 
 /// // 创建一个简单的 DataFrame
 /// let name_series = Series::new("姓名".to_string(), vec!["张三", "李四", "王五"]);
 /// let age_series = Series::new("年龄".to_string(), vec![25, 30, 35]);
 /// 
 /// let df = DataFrame::new(vec![
-///     Box::new(name_series),
+///     Box::new(name_series) as Box<dyn SeriesTrait>,
 ///     Box::new(age_series),
 /// ])?;
 /// 
 /// println!("{}", df);
+/// # Ok::<(), axion_data::AxionError>(())
 /// ```
 #[derive(Clone)]
 pub struct DataFrame {
@@ -70,11 +74,14 @@ impl DataFrame {
     /// # 示例
     /// 
     /// ```rust
-    /// let columns = vec![
+    /// # use axion_data::{DataFrame, Series, SeriesTrait};
+    /// let columns: Vec<Box<dyn SeriesTrait>> = vec![
     ///     Box::new(Series::new("A".to_string(), vec![1, 2, 3])),
     ///     Box::new(Series::new("B".to_string(), vec![4, 5, 6])),
     /// ];
     /// let df = DataFrame::new(columns)?;
+    /// # assert_eq!(df.shape(), (3, 2));
+    /// # Ok::<(), axion_data::AxionError>(())
     /// ```
     pub fn new(columns: Vec<Box<dyn SeriesTrait>>) -> AxionResult<Self> {
         let height = columns.first().map_or(0, |col| col.len());
@@ -105,6 +112,7 @@ impl DataFrame {
     /// # 示例
     /// 
     /// ```rust
+    /// # use axion_data::DataFrame;
     /// let empty_df = DataFrame::new_empty();
     /// assert_eq!(empty_df.shape(), (0, 0));
     /// ```
@@ -125,8 +133,10 @@ impl DataFrame {
     /// # 示例
     /// 
     /// ```rust
+    /// # let df = axion_data::df!("a" => vec![1, 2, 3])?;
     /// let (rows, cols) = df.shape();
     /// println!("DataFrame 有 {} 行 {} 列", rows, cols);
+    /// # Ok::<(), axion_data::AxionError>(())
     /// ```
     pub fn shape(&self) -> (usize, usize) {
         (self.height, self.columns.len())
@@ -225,8 +235,11 @@ impl DataFrame {
     /// # 示例
     /// 
     /// ```rust
+    /// # use axion_data::Series;
+    /// # let mut df = axion_data::df!("a" => vec![1, 2, 3])?;
     /// let new_col = Series::new("新列".to_string(), vec![1, 2, 3]);
     /// df.add_column(Box::new(new_col))?;
+    /// # Ok::<(), axion_data::AxionError>(())
     /// ```
     pub fn add_column(&mut self, series: Box<dyn SeriesTrait>) -> AxionResult<()> {
         if self.columns.is_empty() && self.height == 0 {
@@ -248,6 +261,126 @@ impl DataFrame {
         Ok(())
     }
 
+    /// 添加新列，或替换同名的现有列。
+    ///
+    /// 替换时保持该列在 DataFrame 中的位置不变，新列的数据类型可以与原列不同。
+    ///
+    /// # 参数
+    /// 
+    /// * `series` - 要添加或替换的列
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::MismatchedLengths` - 新列长度与现有行数不匹配
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::{df, AxionError, Series, SeriesArithScalar};
+    /// # let mut df = df!("fare" => vec![10.0, 20.0])?;
+    /// let fare: &Series<f64> = df.downcast_column("fare")?;
+    /// let discounted = fare.mul_scalar(0.9)?.with_name("fare".to_string());
+    /// df.with_column(Box::new(discounted))?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn with_column(&mut self, series: Box<dyn SeriesTrait>) -> AxionResult<()> {
+        match self.columns.iter().position(|col| col.name() == series.name()) {
+            Some(pos) => {
+                if series.len() != self.height {
+                    return Err(AxionError::MismatchedLengths {
+                        expected: self.height,
+                        found: series.len(),
+                        name: series.name().to_string(),
+                    });
+                }
+                self.schema.insert(series.name().to_string(), series.dtype());
+                self.columns[pos] = series;
+                Ok(())
+            }
+            None => self.add_column(series),
+        }
+    }
+
+    /// 在指定位置插入新列。
+    ///
+    /// # 参数
+    /// 
+    /// * `index` - 插入位置，取值范围为 `0..=width`，等于 `width` 时追加到末尾
+    /// * `series` - 要插入的列
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::IndexOutOfBounds` - 插入位置超出范围
+    /// * `AxionError::MismatchedLengths` - 新列长度与现有行数不匹配
+    /// * `AxionError::DuplicateColumnName` - 列名已存在
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::Series;
+    /// # let mut df = axion_data::df!("name" => vec!["a", "b", "c"])?;
+    /// let id = Series::new("id".to_string(), vec![1, 2, 3]);
+    /// df.insert_column(0, Box::new(id))?;
+    /// # assert_eq!(df.columns_names(), vec!["id", "name"]);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn insert_column(&mut self, index: usize, series: Box<dyn SeriesTrait>) -> AxionResult<()> {
+        if index > self.columns.len() {
+            return Err(AxionError::IndexOutOfBounds(index, self.columns.len()));
+        }
+        self.add_column(series)?;
+        // 新列被追加在末尾，将其轮换到目标位置
+        self.columns[index..].rotate_right(1);
+        Ok(())
+    }
+
+    /// 将指定列中掩码为 true 的行原地设置为给定值。
+    ///
+    /// 掩码中的 null 视为 false，其余行保持不变。
+    ///
+    /// # 类型参数
+    /// 
+    /// * `T` - 列的数据类型
+    ///
+    /// # 参数
+    /// 
+    /// * `column` - 列名
+    /// * `mask` - 与 DataFrame 等高的布尔掩码
+    /// * `value` - 要写入的值，传入 `None` 表示设置为 null
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 指定列不存在
+    /// * `AxionError::TypeMismatch` - 列的数据类型与 `T` 不一致
+    /// * `AxionError::MismatchedLengths` - 掩码长度与行数不一致
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::{df, AxionError, SeriesCompare};
+    /// # let mut df = df!("age": i32 => vec![25, -1, 40])?;
+    /// // 将所有负数年龄置为 null
+    /// let mask = df.downcast_column::<i32>("age")?.lt(0)?;
+    /// df.set_where::<i32>("age", &mask, None)?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn set_where<T>(&mut self, column: &str, mask: &Series<bool>, value: Option<T>) -> AxionResult<()>
+    where
+        T: DataTypeTrait + Clone + 'static,
+    {
+        let series_trait = self.column_mut(column)?;
+        let found = series_trait.dtype();
+        let series = series_trait
+            .as_any_mut()
+            .downcast_mut::<Series<T>>()
+            .ok_or_else(|| AxionError::TypeMismatch {
+                expected: T::DTYPE,
+                found,
+                name: column.to_string(),
+            })?;
+        series.set_where(mask, value)
+    }
+
     /// 从 DataFrame 中删除指定列。
     ///
     /// # 参数
@@ -503,352 +636,546 @@ impl DataFrame {
         DataFrame::new(new_columns)
     }
 
-    /// 内连接操作。
+    /// 按指定连接类型和选项连接两个 DataFrame。
     ///
-    /// 只保留两个 DataFrame 中连接键都存在的行。
+    /// 连接键可以是任意非 List 类型的列，也可以由多列组成复合键，
+    /// 左右两侧对应键列的数据类型必须一致。
     ///
     /// # 参数
     /// 
     /// * `right` - 右侧 DataFrame
     /// * `left_on` - 左侧连接键列名
-    /// * `right_on` - 右侧连接键列名
+    /// * `right_on` - 右侧连接键列名，与 `left_on` 一一对应
+    /// * `how` - 连接类型
+    /// * `options` - 可选的连接配置，`None` 时使用默认值（null 键不匹配、不做基数校验）
     ///
     /// # 返回值
     /// 
     /// 返回连接后的新 DataFrame
-    pub fn inner_join(
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::InvalidArgument` - 连接键为空或两侧数量不一致
+    /// * `AxionError::ColumnNotFound` - 连接键列不存在
+    /// * `AxionError::JoinKeyTypeError` - 两侧对应键列的数据类型不一致
+    /// * `AxionError::JoinValidationError` - 连接键不满足 `options.validate` 的唯一性要求
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::dataframe::{JoinOptions, JoinType, JoinValidation};
+    /// # use axion_data::{df, AxionError};
+    /// # let sales = df!("store_id" => vec![1, 2], "date" => vec!["2024-01-01", "2024-01-01"])?;
+    /// # let stores = df!("id" => vec![1, 2], "date" => vec!["2024-01-01", "2024-01-01"])?;
+    /// let options = JoinOptions::builder()
+    ///     .with_right_suffix("_store")
+    ///     .validate(JoinValidation::ManyToOne)
+    ///     .build();
+    /// let joined = sales.join(&stores, &["store_id", "date"], &["id", "date"], JoinType::Left, Some(options))?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn join(
         &self,
         right: &DataFrame,
-        left_on: &str,
-        right_on: &str,
+        left_on: &[&str],
+        right_on: &[&str],
+        how: JoinType,
+        options: Option<JoinOptions>,
     ) -> AxionResult<DataFrame> {
-        let left_key_col: &Series<String> = self.downcast_column(left_on).map_err(|e| match e {
-            AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("左侧连接键列 '{}'", left_on)),
-            AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                side: "左侧".to_string(),
-                name,
-                expected: DataType::String,
-                found,
-            },
-            other => other,
-        })?;
-        let right_key_col: &Series<String> = right.downcast_column(right_on).map_err(|e| match e {
-            AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("右侧连接键列 '{}'", right_on)),
-            AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                side: "右侧".to_string(),
-                name,
-                expected: DataType::String,
-                found,
-            },
-            other => other,
-        })?;
-
-        let mut right_indices_map: HashMap<&Option<String>, Vec<usize>> = HashMap::new();
-        for (idx, opt_key) in right_key_col.data_internal().iter().enumerate() {
-            right_indices_map.entry(opt_key).or_default().push(idx);
-        }
-
-        let mut join_indices: Vec<(usize, usize)> = Vec::new();
-        for (left_idx, left_opt_key) in left_key_col.data_internal().iter().enumerate() {
-            if let Some(right_indices) = right_indices_map.get(left_opt_key) {
-                for &right_idx in right_indices {
-                    join_indices.push((left_idx, right_idx));
-                }
-            }
-        }
-
-        let (left_result_indices, right_result_indices): (Vec<usize>, Vec<usize>) =
-            join_indices.into_iter().unzip();
-
-        let mut result_columns: Vec<Box<dyn SeriesTrait>> =
-            Vec::with_capacity(self.width() + right.width() - 1);
-        let mut left_column_names: HashSet<String> = HashSet::with_capacity(self.width());
-
-        for col in &self.columns {
-            let taken_left_col = col.take_indices(&left_result_indices)?;
-            left_column_names.insert(taken_left_col.name().to_string());
-            result_columns.push(taken_left_col);
-        }
-
-        for col in &right.columns {
-            if col.name() != right_on {
-                let original_right_name = col.name();
-                let mut taken_right_col = col.take_indices(&right_result_indices)?;
-
-                if left_column_names.contains(original_right_name) {
-                    let new_name = format!("{}_right", original_right_name);
-                    taken_right_col.rename(&new_name);
-                    result_columns.push(taken_right_col);
-                } else {
-                    result_columns.push(taken_right_col);
-                }
-            }
-        }
+        let options = options.unwrap_or_default();
+        hash_join(self, right, left_on, right_on, how, &options)
+    }
 
-        DataFrame::new(result_columns)
+    /// 内连接操作。
+    ///
+    /// 只保留两个 DataFrame 中连接键都存在的行，使用默认连接选项。
+    /// 参数和错误与 [`DataFrame::join`] 相同。
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::{df, AxionError};
+    /// # let sales = df!("store_id" => vec![1, 2], "date" => vec!["2024-01-01", "2024-01-02"])?;
+    /// # let stores = df!("id" => vec![1, 2], "date" => vec!["2024-01-01", "2024-01-01"])?;
+    /// // 按整数 ID 和日期两列连接
+    /// let joined = sales.inner_join(&stores, &["store_id", "date"], &["id", "date"])?;
+    /// # assert_eq!(joined.height(), 1);
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn inner_join(
+        &self,
+        right: &DataFrame,
+        left_on: &[&str],
+        right_on: &[&str],
+    ) -> AxionResult<DataFrame> {
+        self.join(right, left_on, right_on, JoinType::Inner, None)
     }
 
     /// 左连接操作。
     ///
     /// 保留左侧 DataFrame 的所有行，如果右侧没有匹配则填充空值。
+    /// 参数和错误与 [`DataFrame::join`] 相同。
     pub fn left_join(
         &self,
         right: &DataFrame,
-        left_on: &str,
-        right_on: &str,
+        left_on: &[&str],
+        right_on: &[&str],
     ) -> AxionResult<DataFrame> {
-        let left_key_col: &Series<String> = self
-            .downcast_column(left_on)
-            .map_err(|e| match e {
-                AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("left key column '{}'", left_on)),
-                AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                    side: "left".to_string(), name, expected: DataType::String, found,
-                },
-                other => other,
-            })?;
-
-        let right_key_col: &Series<String> = right
-            .downcast_column(right_on)
-            .map_err(|e| match e {
-                AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("right key column '{}'", right_on)),
-                AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                    side: "right".to_string(), name, expected: DataType::String, found,
-                },
-                other => other,
-            })?;
-
-        let mut right_indices_map: HashMap<&Option<String>, Vec<usize>> = HashMap::new();
-        for (idx, opt_key) in right_key_col.data_internal().iter().enumerate() {
-            right_indices_map.entry(opt_key).or_default().push(idx);
-        }
-
-        let mut join_indices: Vec<(usize, Option<usize>)> = Vec::new();
-        for (left_idx, left_opt_key) in left_key_col.data_internal().iter().enumerate() {
-            if let Some(right_indices) = right_indices_map.get(left_opt_key) {
-                for &right_idx in right_indices {
-                    join_indices.push((left_idx, Some(right_idx)));
-                }
-            } else {
-                join_indices.push((left_idx, None));
-            }
-        }
-
-        let (left_result_indices, right_result_indices): (Vec<usize>, Vec<Option<usize>>) =
-            join_indices.into_iter().unzip();
-
-        let mut result_columns: Vec<Box<dyn SeriesTrait>> =
-            Vec::with_capacity(self.width() + right.width() - 1);
-        let mut left_column_names: HashSet<String> = HashSet::with_capacity(self.width());
-
-        for col in &self.columns {
-            let taken_left_col = col.take_indices(&left_result_indices)?;
-            left_column_names.insert(taken_left_col.name().to_string());
-            result_columns.push(taken_left_col);
-        }
-
-        for col in &right.columns {
-            if col.name() != right_on {
-                let original_right_name = col.name();
-                let mut taken_right_col = col.take_indices_option(&right_result_indices)?;
-
-                if left_column_names.contains(original_right_name) {
-                    let new_name = format!("{}_right", original_right_name);
-                    taken_right_col.rename(&new_name);
-                    result_columns.push(taken_right_col);
-                } else {
-                    result_columns.push(taken_right_col);
-                }
-            }
-        }
-
-        DataFrame::new(result_columns)
+        self.join(right, left_on, right_on, JoinType::Left, None)
     }
 
     /// 右连接操作。
     ///
     /// 保留右侧 DataFrame 的所有行，如果左侧没有匹配则填充空值。
+    /// 参数和错误与 [`DataFrame::join`] 相同。
     pub fn right_join(
         &self,
         right: &DataFrame,
-        left_on: &str,
-        right_on: &str,
+        left_on: &[&str],
+        right_on: &[&str],
     ) -> AxionResult<DataFrame> {
-        let left_key_col: &Series<String> = self
-            .downcast_column(left_on)
-            .map_err(|e| match e {
-                AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("left key column '{}'", left_on)),
-                AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                    side: "left".to_string(), name, expected: DataType::String, found,
-                },
-                other => other,
-            })?;
-
-        let right_key_col: &Series<String> = right
-            .downcast_column(right_on)
-            .map_err(|e| match e {
-                AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("right key column '{}'", right_on)),
-                AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                    side: "right".to_string(), name, expected: DataType::String, found,
-                },
-                other => other,
-            })?;
-
-        let mut left_indices_map: HashMap<&Option<String>, Vec<usize>> = HashMap::new();
-        for (idx, opt_key) in left_key_col.data_internal().iter().enumerate() {
-            left_indices_map.entry(opt_key).or_default().push(idx);
-        }
-
-        let mut join_indices: Vec<(Option<usize>, usize)> = Vec::new();
-        for (right_idx, right_opt_key) in right_key_col.data_internal().iter().enumerate() {
-            if let Some(left_indices) = left_indices_map.get(right_opt_key) {
-                for &left_idx in left_indices {
-                    join_indices.push((Some(left_idx), right_idx));
-                }
-            } else {
-                join_indices.push((None, right_idx));
-            }
-        }
-
-        let (left_result_indices, right_result_indices): (Vec<Option<usize>>, Vec<usize>) =
-            join_indices.into_iter().unzip();
+        self.join(right, left_on, right_on, JoinType::Right, None)
+    }
 
-        let mut result_columns: Vec<Box<dyn SeriesTrait>> =
-            Vec::with_capacity(self.width() + right.width() - 1);
-        let mut right_column_names: HashSet<String> = HashSet::with_capacity(right.width());
+    /// 外连接操作。
+    ///
+    /// 保留两个 DataFrame 的所有行，没有匹配的地方填充空值。
+    /// 参数和错误与 [`DataFrame::join`] 相同。
+    pub fn outer_join(
+        &self,
+        right: &DataFrame,
+        left_on: &[&str],
+        right_on: &[&str],
+    ) -> AxionResult<DataFrame> {
+        self.join(right, left_on, right_on, JoinType::Outer, None)
+    }
 
-        for col in &right.columns {
-            let taken_right_col = col.take_indices(&right_result_indices)?;
-            right_column_names.insert(taken_right_col.name().to_string());
-            result_columns.push(taken_right_col);
-        }
+    /// 半连接操作。
+    ///
+    /// 只保留在右侧 DataFrame 中存在匹配的左侧行，结果只包含左侧的列，
+    /// 且每个左侧行最多出现一次。参数和错误与 [`DataFrame::join`] 相同。
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::{df, AxionError};
+    /// # let customers = df!("id" => vec![1, 2, 3])?;
+    /// # let orders = df!("customer_id" => vec![1, 1, 3])?;
+    /// // 只保留有订单的客户
+    /// let active = customers.semi_join(&orders, &["id"], &["customer_id"])?;
+    /// # assert_eq!(active.height(), 2);
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn semi_join(
+        &self,
+        right: &DataFrame,
+        left_on: &[&str],
+        right_on: &[&str],
+    ) -> AxionResult<DataFrame> {
+        self.join(right, left_on, right_on, JoinType::Semi, None)
+    }
 
-        for col in &self.columns {
-            if col.name() != left_on {
-                let original_left_name = col.name();
-                let mut taken_left_col = col.take_indices_option(&left_result_indices)?;
-
-                if right_column_names.contains(original_left_name) {
-                    let new_name = format!("{}_left", original_left_name);
-                    taken_left_col.rename(&new_name);
-                    result_columns.push(taken_left_col);
-                } else {
-                    result_columns.push(taken_left_col);
-                }
-            }
-        }
+    /// 反连接操作。
+    ///
+    /// 只保留在右侧 DataFrame 中没有匹配的左侧行，结果只包含左侧的列。
+    /// 参数和错误与 [`DataFrame::join`] 相同。
+    pub fn anti_join(
+        &self,
+        right: &DataFrame,
+        left_on: &[&str],
+        right_on: &[&str],
+    ) -> AxionResult<DataFrame> {
+        self.join(right, left_on, right_on, JoinType::Anti, None)
+    }
 
-        DataFrame::new(result_columns)
+    /// 交叉连接操作（笛卡尔积）。
+    ///
+    /// 左侧每一行与右侧每一行组合，结果行数为两侧行数之积。
+    /// 右侧与左侧重名的列添加 `_right` 后缀。
+    ///
+    /// # 参数
+    /// 
+    /// * `right` - 右侧 DataFrame
+    ///
+    /// # 返回值
+    /// 
+    /// 返回连接后的新 DataFrame
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::InvalidArgument` - 结果行数溢出
+    /// * `AxionError::DuplicateColumnName` - 添加后缀后仍存在重复列名
+    pub fn cross_join(&self, right: &DataFrame) -> AxionResult<DataFrame> {
+        cross_join(self, right, &JoinOptions::default())
     }
 
-    /// 外连接操作。
+    /// as-of 连接操作（最近键连接）。
     ///
-    /// 保留两个 DataFrame 的所有行，没有匹配的地方填充空值。
-    pub fn outer_join(
+    /// 对每个左侧行，匹配键不大于左键的最后一个右侧行，常用于将读数关联到
+    /// 最近一次的标定记录。可通过选项限制最大键差，并按分组列分别匹配。
+    /// 左右两侧的键列已标记为升序排序时不会重新排序。
+    ///
+    /// # 参数
+    /// 
+    /// * `right` - 右侧 DataFrame
+    /// * `left_on` - 左侧键列名，需为数值或时间类型
+    /// * `right_on` - 右侧键列名，数据类型与左侧相同
+    /// * `options` - 可选的 as-of 连接配置
+    ///
+    /// # 返回值
+    /// 
+    /// 返回与左侧行数相同的新 DataFrame，没有匹配的行右侧列为空值。
+    /// 右侧分组列不输出，右键列与左键列同名时也不输出。
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 键列或分组列不存在
+    /// * `AxionError::JoinKeyTypeError` - 两侧键列或分组列的数据类型不一致
+    /// * `AxionError::UnsupportedOperation` - 键列不是数值或时间类型
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::dataframe::AsofJoinOptions;
+    /// # use axion_data::{df, AxionError};
+    /// # let readings = df!("sensor_id" => vec![1, 2], "ts" => vec![100_i64, 200])?;
+    /// # let calibrations = df!("sensor_id" => vec![1, 2], "ts" => vec![90_i64, 100])?;
+    /// let options = AsofJoinOptions::builder()
+    ///     .with_by(&["sensor_id"])
+    ///     .with_tolerance(60.0)
+    ///     .build();
+    /// let joined = readings.join_asof(&calibrations, "ts", "ts", Some(options))?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn join_asof(
         &self,
         right: &DataFrame,
         left_on: &str,
         right_on: &str,
+        options: Option<AsofJoinOptions>,
     ) -> AxionResult<DataFrame> {
-        let left_key_col: &Series<String> = self
-            .downcast_column(left_on)
-            .map_err(|e| match e {
-                AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("left key column '{}'", left_on)),
-                AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                    side: "left".to_string(),
-                    name,
-                    expected: DataType::String,
-                    found,
-                },
-                other => other,
-            })?;
-        let right_key_col: &Series<String> = right
-            .downcast_column(right_on)
-            .map_err(|e| match e {
-                AxionError::ColumnNotFound(_) => AxionError::ColumnNotFound(format!("right key column '{}'", right_on)),
-                AxionError::TypeMismatch { expected: _, found, name } => AxionError::JoinKeyTypeError {
-                    side: "right".to_string(),
-                    name,
-                    expected: DataType::String,
-                    found,
-                },
-                other => other,
-            })?;
-
-        let mut right_indices_map: HashMap<&Option<String>, Vec<usize>> = HashMap::new();
-        for (idx, opt_key) in right_key_col.data_internal().iter().enumerate() {
-            right_indices_map.entry(opt_key).or_default().push(idx);
-        }
+        let options = options.unwrap_or_default();
+        asof_join(self, right, left_on, right_on, &options)
+    }
 
-        let mut join_indices: Vec<(Option<usize>, Option<usize>)> = Vec::new();
-        let mut used_right_indices: HashSet<usize> = HashSet::new();
+    /// 创建分组操作对象。
+    ///
+    /// 各组按分组键首次出现的顺序输出。
+    /// 分组键中包含 null 的行会被丢弃，如需保留或按键排序请使用 [`DataFrame::groupby_with_options`]。
+    ///
+    /// # 参数
+    /// 
+    /// * `keys` - 用于分组的列名数组
+    ///
+    /// # 返回值
+    /// 
+    /// 返回 GroupBy 对象，可用于执行聚合操作
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # let df = axion_data::df!("类别" => vec!["A", "B", "A"], "值" => vec![1, 2, 3])?;
+    /// let grouped = df.groupby(&["类别"])?;
+    /// let result = grouped.sum()?;
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn groupby<'a>(&'a self, keys: &[&str]) -> AxionResult<GroupBy<'a>> {
+        self.groupby_with_options(keys, GroupByOptions::default())
+    }
 
-        for (left_idx, left_opt_key) in left_key_col.data_internal().iter().enumerate() {
-            if let Some(right_indices) = right_indices_map.get(left_opt_key) {
-                for &right_idx in right_indices {
-                    join_indices.push((Some(left_idx), Some(right_idx)));
-                    used_right_indices.insert(right_idx);
-                }
-            } else {
-                join_indices.push((Some(left_idx), None));
-            }
-        }
+    /// 使用自定义配置创建分组操作对象。
+    ///
+    /// # 参数
+    /// 
+    /// * `keys` - 用于分组的列名数组
+    /// * `options` - 分组配置
+    ///
+    /// # 返回值
+    /// 
+    /// 返回 GroupBy 对象，可用于执行聚合操作
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::dataframe::GroupByOptions;
+    /// # use axion_data::{df, AxionError};
+    /// # let df = df!("类别" => vec![Some("A"), None, Some("A")])?;
+    /// // 保留 null 键作为单独的分组
+    /// let options = GroupByOptions::builder().dropna(false).build();
+    /// let counts = df.groupby_with_options(&["类别"], options)?.count()?;
+    /// # assert_eq!(counts.height(), 2);
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn groupby_with_options<'a>(&'a self, keys: &[&str], options: GroupByOptions) -> AxionResult<GroupBy<'a>> {
+        let key_strings: Vec<String> = keys.iter().map(|s| s.to_string()).collect();
+        GroupBy::new(self, key_strings, options)
+    }
 
-        for (right_idx, _right_opt_key) in right_key_col.data_internal().iter().enumerate() {
-            if !used_right_indices.contains(&right_idx) {
-                join_indices.push((None, Some(right_idx)));
-            }
-        }
+    /// 并行创建分组操作对象。
+    ///
+    /// 使用 Rayon 按分区并行构建分组，并在后续聚合中并行处理各列，
+    /// 结果与 [`DataFrame::groupby`] 完全一致，适合大型数据集。
+    ///
+    /// # 参数
+    /// 
+    /// * `keys` - 用于分组的列名数组
+    ///
+    /// # 返回值
+    /// 
+    /// 返回 GroupBy 对象，可用于执行聚合操作
+    pub fn par_groupby<'a>(&'a self, keys: &[&str]) -> AxionResult<GroupBy<'a>> {
+        self.groupby_with_options(keys, GroupByOptions::builder().parallel(true).build())
+    }
 
-        let (left_result_indices, right_result_indices): (Vec<Option<usize>>, Vec<Option<usize>>) =
-            join_indices.into_iter().unzip();
+    /// 按时间窗口分组，窗口可以重叠，返回的分组对象支持所有常规聚合方法。
+    ///
+    /// 窗口起点从时间列最小值按 `every` 截断（再加上 `offset`）开始，每次前进 `every`，
+    /// 每个窗口覆盖 `[起点, 起点 + period)`（闭合方式由 `closed` 决定），不含任何行的窗口会被跳过。
+    /// 输出的键列与时间列同名，取值为窗口起点；时间列的 null 行不属于任何窗口。
+    ///
+    /// # 参数
+    ///
+    /// * `time_col` - 时间列名，支持 Datetime、Date、ISO-8601 字符串列和整数列
+    /// * `every` - 窗口步长，时间列使用如 `"1h"`、`"1d"`、`"1mo"` 的间隔字符串，整数列使用如 `"10i"` 的整数
+    /// * `period` - 窗口长度，为 `None` 时等于 `every`
+    /// * `offset` - 窗口起点的偏移量，可以为负，为 `None` 时不偏移
+    /// * `closed` - 窗口边界的闭合方式
+    ///
+    /// # 返回值
+    ///
+    /// 返回按窗口起点升序排列的分组对象，组内行按时间升序排列
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::ColumnNotFound` - 时间列不存在
+    /// * `AxionError::TypeMismatch` - 时间列不是时间、字符串或整数类型
+    /// * `AxionError::InvalidArgument` - 间隔字符串无效，`every`、`period` 不为正，
+    ///   或整数列的窗口起点因 `offset` 超出该列类型的范围
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::dataframe::{Agg, ClosedWindow};
+    /// # use axion_data::{df, AxionError};
+    /// # let df = df!("ts" => vec!["2024-03-15T10:00:00", "2024-03-15T10:40:00"], "price" => vec![10.0, 11.0])?;
+    /// // 每 30 分钟计算一次过去 1 小时的平均值
+    /// let rolling = df
+    ///     .groupby_dynamic("ts", "30m", Some("1h"), None, ClosedWindow::Left)?
+    ///     .agg(&[("price", Agg::Mean, "avg_price"), ("price", Agg::Count, "trades")])?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn groupby_dynamic<'a>(
+        &'a self,
+        time_col: &str,
+        every: &str,
+        period: Option<&str>,
+        offset: Option<&str>,
+        closed: ClosedWindow,
+    ) -> AxionResult<GroupBy<'a>> {
+        groupby_dynamic(self, time_col, every, period, offset, closed)
+    }
 
-        let mut result_columns: Vec<Box<dyn SeriesTrait>> =
-            Vec::with_capacity(self.width() + right.width() - 1);
-        let mut left_column_names: HashSet<String> = HashSet::with_capacity(self.width());
+    /// 按固定时间窗口重采样，等价于 `groupby_dynamic(time_col, every, None, None, ClosedWindow::Left)`。
+    ///
+    /// # 参数
+    ///
+    /// * `time_col` - 时间列名
+    /// * `every` - 窗口长度，如 `"1h"`、`"1d"`、`"1w"`、`"1mo"`
+    ///
+    /// # 错误
+    ///
+    /// 同 [`DataFrame::groupby_dynamic`]
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::{df, AxionError};
+    /// # let df = df!("ts" => vec!["2024-03-15T10:00:00", "2024-03-16T09:00:00"], "price" => vec![10.0, 11.0])?;
+    /// let daily = df.resample("ts", "1d")?.sum()?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn resample<'a>(&'a self, time_col: &str, every: &str) -> AxionResult<GroupBy<'a>> {
+        groupby_dynamic(self, time_col, every, None, None, ClosedWindow::Left)
+    }
 
-        for col in &self.columns {
-            let taken_left_col = col.take_indices_option(&left_result_indices)?;
-            left_column_names.insert(taken_left_col.name().to_string());
-            result_columns.push(taken_left_col);
-        }
+    /// 创建透视表，将 `columns` 列的每个不同取值展开为一个输出列。
+    ///
+    /// 按 `index` 列的组合分行、按 `columns` 列的取值分列，每个单元格为对应行中
+    /// `values` 列的聚合结果，没有数据的单元格为 null。输出的行和列都按首次出现的顺序排列，
+    /// null 键会单独成行或成列（列名为 "null"）。
+    ///
+    /// # 参数
+    /// 
+    /// * `index` - 作为输出行标识的列名数组
+    /// * `columns` - 其取值将成为输出列名的列
+    /// * `values` - 要聚合的值列
+    /// * `agg` - 聚合函数
+    ///
+    /// # 返回值
+    /// 
+    /// 返回以 `index` 列开头、后跟各透视列的新 DataFrame
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 指定的列不存在
+    /// * `AxionError::InvalidArgument` - `index`、`columns` 和 `values` 中存在相同的列
+    /// * `AxionError::UnsupportedOperation` - 聚合函数不支持值列的数据类型
+    /// * `AxionError::DuplicateColumnName` - 透视列名与 `index` 列重名
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::dataframe::Agg;
+    /// # use axion_data::{df, AxionError};
+    /// # let df = df!("city" => vec!["北京", "北京"], "month" => vec!["jan", "feb"], "sales" => vec![10.0, 12.0])?;
+    /// // 每个城市一行，每个月份一列，单元格为销售额之和
+    /// let wide = df.pivot(&["city"], "month", "sales", Agg::Sum)?;
+    /// # assert_eq!(wide.shape(), (1, 3));
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn pivot(&self, index: &[&str], columns: &str, values: &str, agg: Agg) -> AxionResult<DataFrame> {
+        pivot(self, index, columns, values, agg)
+    }
 
-        for col in &right.columns {
-            if col.name() != right_on {
-                let original_right_name = col.name();
-                let mut taken_right_col = col.take_indices_option(&right_result_indices)?;
+    /// 将宽表转换为长表（逆透视）。
+    ///
+    /// `value_vars` 中的每一列都会折叠为 `variable`（原列名）和 `value`（原值）两列，
+    /// `id_vars` 列在每个值列对应的行中重复出现。所有值列的数据类型必须相同。
+    ///
+    /// # 参数
+    /// 
+    /// * `id_vars` - 保持不变的标识列
+    /// * `value_vars` - 要折叠的值列，为空时使用除 `id_vars` 外的所有列
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含 `id_vars`、`variable` 和 `value` 列的新 DataFrame，
+    /// 行数为原行数乘以值列数量
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 指定的列不存在
+    /// * `AxionError::InvalidArgument` - 没有可折叠的值列
+    /// * `AxionError::TypeMismatch` - 值列的数据类型不一致
+    /// * `AxionError::DuplicateColumnName` - `id_vars` 中包含名为 `variable` 或 `value` 的列
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # let wide = axion_data::df!("city" => vec!["北京"], "jan" => vec![10.0], "feb" => vec![12.0])?;
+    /// let long = wide.melt(&["city"], &["jan", "feb"])?;
+    /// # assert_eq!(long.height(), 2);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn melt(&self, id_vars: &[&str], value_vars: &[&str]) -> AxionResult<DataFrame> {
+        melt(self, id_vars, value_vars)
+    }
 
-                if left_column_names.contains(original_right_name) {
-                    let new_name = format!("{}_right", original_right_name);
-                    taken_right_col.rename(&new_name);
-                    result_columns.push(taken_right_col);
-                } else {
-                    result_columns.push(taken_right_col);
-                }
-            }
-        }
+    /// 将结构体列展开为顶层列。
+    ///
+    /// 每个指定的结构体列在原位置被替换为其所有字段列，字段名即新列名，
+    /// 整行为 null 的位置在字段列中也为 null。
+    ///
+    /// # 参数
+    /// 
+    /// * `columns` - 要展开的结构体列名
+    ///
+    /// # 返回值
+    /// 
+    /// 返回展开后的新 DataFrame
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 指定的列不存在
+    /// * `AxionError::TypeMismatch` - 指定的列不是结构体列
+    /// * `AxionError::DuplicateColumnName` - 字段名与其他列重名
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::{AxionError, DataFrame, Series, SeriesTrait, StructSeries};
+    /// # let address = StructSeries::new("address".to_string(), vec![
+    /// #     Box::new(Series::new("street".to_string(), vec!["Main St"])) as Box<dyn SeriesTrait>,
+    /// #     Box::new(Series::new("city".to_string(), vec!["Springfield"])),
+    /// #     Box::new(Series::new("zip".to_string(), vec![10001_i64])),
+    /// # ])?;
+    /// # let df = DataFrame::new(vec![Box::new(address)])?;
+    /// // "address" 列展开为 "street"、"city"、"zip" 三列
+    /// let flat = df.unnest(&["address"])?;
+    /// # assert_eq!(flat.columns_names(), vec!["street", "city", "zip"]);
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn unnest(&self, columns: &[&str]) -> AxionResult<DataFrame> {
+        unnest(self, columns)
+    }
 
-        DataFrame::new(result_columns)
+    /// 将列表列的每个元素展开为单独的一行。
+    ///
+    /// 其他列的值按元素个数重复；空列表和 null 行各保留一行，展开列的值为 null。
+    ///
+    /// # 参数
+    /// 
+    /// * `column` - 要展开的列表列名
+    ///
+    /// # 返回值
+    /// 
+    /// 返回展开后的新 DataFrame，展开列的类型为列表的内部元素类型
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 指定的列不存在
+    /// * `AxionError::TypeMismatch` - 指定的列不是列表列
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::{new_list_series, AxionError, DataFrame, Series};
+    /// # let tags = new_list_series("tags".to_string(), vec![
+    /// #     Box::new(Series::new(String::new(), vec!["a", "b"])),
+    /// #     Box::new(Series::new(String::new(), vec!["c"])),
+    /// # ])?;
+    /// # let df = DataFrame::new(vec![Box::new(tags)])?;
+    /// // "tags" 列为 [["a", "b"], ["c"]] 时，展开后得到 3 行
+    /// let exploded = df.explode("tags")?;
+    /// # assert_eq!(exploded.height(), 3);
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn explode(&self, column: &str) -> AxionResult<DataFrame> {
+        explode(self, column)
     }
 
-    /// 创建分组操作对象。
+    /// 按分组键将其余各列收集为列表列，是 [`DataFrame::explode`] 的逆操作。
+    ///
+    /// 各组按分组键首次出现的顺序输出，null 键单独成组，组内元素保持原有顺序。
     ///
     /// # 参数
     /// 
-    /// * `keys` - 用于分组的列名数组
+    /// * `by` - 分组键列名
     ///
     /// # 返回值
     /// 
-    /// 返回 GroupBy 对象，可用于执行聚合操作
+    /// 返回包含分组键列和各同名列表列的新 DataFrame
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 分组键列不存在
+    /// * `AxionError::UnsupportedOperation` - 分组键列的数据类型不支持分组
     ///
     /// # 示例
     /// 
     /// ```rust
-    /// let grouped = df.groupby(&["类别"])?;
-    /// let result = grouped.sum()?;
+    /// # use axion_data::{new_list_series, AxionError, DataFrame, Series, SeriesTrait};
+    /// # let tags = new_list_series("tags".to_string(), vec![
+    /// #     Box::new(Series::new(String::new(), vec!["a", "b"])),
+    /// #     Box::new(Series::new(String::new(), vec!["c"])),
+    /// # ])?;
+    /// # let df = DataFrame::new(vec![Box::new(Series::new("id".to_string(), vec![1, 2])) as Box<dyn SeriesTrait>, Box::new(tags)])?;
+    /// let round_trip = df.explode("tags")?.implode(&["id"])?;
+    /// # assert_eq!(round_trip.height(), 2);
+    /// # Ok::<(), AxionError>(())
     /// ```
-    pub fn groupby<'a>(&'a self, keys: &[&str]) -> AxionResult<GroupBy<'a>> {
-        let key_strings: Vec<String> = keys.iter().map(|s| s.to_string()).collect();
-        GroupBy::new(self, key_strings)
+    pub fn implode(&self, by: &[&str]) -> AxionResult<DataFrame> {
+        implode(self, by)
     }
 
     /// 对 DataFrame 进行排序。
@@ -870,8 +1197,10 @@ impl DataFrame {
     /// # 示例
     /// 
     /// ```rust
+    /// # let df = axion_data::df!("姓名" => vec!["张三", "李四"], "年龄" => vec![30, 25])?;
     /// // 按年龄升序，姓名降序排序
     /// let sorted_df = df.sort(&["年龄", "姓名"], &[false, true])?;
+    /// # Ok::<(), axion_data::AxionError>(())
     /// ```
     pub fn sort(&self, by: &[&str], descending: &[bool]) -> AxionResult<DataFrame> {
         if by.is_empty() {
@@ -931,8 +1260,9 @@ impl DataFrame {
     ///
     /// # 示例
     /// 
-    /// ```rust
-    /// use axion::io::csv::WriteCsvOptions;
+    /// ```rust,no_run
+    /// use axion_data::io::csv::WriteCsvOptions;
+    /// # let df = axion_data::df!("a" => vec![1, 2, 3])?;
     /// 
     /// // 使用默认配置导出
     /// df.to_csv("output.csv", None)?;
@@ -944,6 +1274,7 @@ impl DataFrame {
     ///     ..Default::default()
     /// };
     /// df.to_csv("output.csv", Some(options))?;
+    /// # Ok::<(), axion_data::AxionError>(())
     /// ```
     pub fn to_csv(&self, filepath: impl AsRef<Path>, options: Option<WriteCsvOptions>) -> AxionResult<()> {
         let path_ref = filepath.as_ref();
@@ -0,0 +1,206 @@
+//! 动态窗口分组模块
+//!
+//! 按时间列（或整数列）将行划分到固定步长的时间窗口中，窗口可以重叠，
+//! 划分结果以 `GroupBy` 的形式返回，复用现有的聚合方法。
+
+use crate::dataframe::groupby::GroupBy;
+use crate::dataframe::DataFrame;
+use crate::dtype::DataType;
+use crate::error::{AxionError, AxionResult};
+use crate::series::datetime::DatetimeAccessor;
+use crate::series::{Series, SeriesTrait};
+use crate::temporal::Interval;
+
+/// 时间窗口的闭合方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClosedWindow {
+    /// 左闭右开 `[start, end)`，默认值
+    #[default]
+    Left,
+    /// 左开右闭 `(start, end]`
+    Right,
+    /// 两端闭合 `[start, end]`
+    Both,
+    /// 两端开放 `(start, end)`
+    None,
+}
+
+impl ClosedWindow {
+    fn includes_start(&self) -> bool {
+        matches!(self, ClosedWindow::Left | ClosedWindow::Both)
+    }
+
+    fn includes_end(&self) -> bool {
+        matches!(self, ClosedWindow::Right | ClosedWindow::Both)
+    }
+}
+
+/// 窗口步长：时间列使用日历间隔，整数列使用整数步长
+#[derive(Debug, Clone, Copy)]
+enum WindowStep {
+    Calendar(Interval),
+    Integer(i128),
+}
+
+impl WindowStep {
+    /// 解析步长字符串，整数列接受 `"10"` 或 `"10i"` 形式
+    fn parse(s: &str, integer: bool) -> AxionResult<Self> {
+        if !integer {
+            return Interval::parse(s).map(WindowStep::Calendar);
+        }
+        let trimmed = s.trim();
+        trimmed
+            .strip_suffix('i')
+            .unwrap_or(trimmed)
+            .parse::<i128>()
+            .map(WindowStep::Integer)
+            .map_err(|_| AxionError::InvalidArgument(format!("整数列的窗口间隔必须为整数（如 \"10i\"），实际为 '{}'", s)))
+    }
+
+    /// 检查步长是否为正
+    fn check_positive(&self, name: &str, s: &str) -> AxionResult<()> {
+        let positive = match self {
+            WindowStep::Calendar(interval) => {
+                interval.months >= 0 && interval.nanos >= 0 && (interval.months > 0 || interval.nanos > 0)
+            }
+            WindowStep::Integer(step) => *step > 0,
+        };
+        if positive {
+            Ok(())
+        } else {
+            Err(AxionError::InvalidArgument(format!("{} 必须为正的间隔，实际为 '{}'", name, s)))
+        }
+    }
+
+    fn add_to(&self, value: i128) -> i128 {
+        match self {
+            WindowStep::Calendar(interval) => interval.add_to(value),
+            WindowStep::Integer(step) => value + step,
+        }
+    }
+
+    fn sub_from(&self, value: i128) -> i128 {
+        match self {
+            WindowStep::Calendar(interval) => interval.negate().add_to(value),
+            WindowStep::Integer(step) => value - step,
+        }
+    }
+
+    fn truncate(&self, value: i128) -> i128 {
+        match self {
+            WindowStep::Calendar(interval) => interval.truncate(value),
+            WindowStep::Integer(step) => value - value.rem_euclid(*step),
+        }
+    }
+
+    /// 固定长度的步长，日历月间隔返回 `None`
+    fn fixed_size(&self) -> Option<i128> {
+        match self {
+            WindowStep::Calendar(interval) if interval.months == 0 => Some(interval.nanos),
+            WindowStep::Calendar(_) => None,
+            WindowStep::Integer(step) => Some(*step),
+        }
+    }
+}
+
+/// 按时间窗口对 DataFrame 分组（由 `DataFrame::groupby_dynamic` 调用）
+pub(crate) fn groupby_dynamic<'a>(
+    df: &'a DataFrame,
+    time_col: &str,
+    every: &str,
+    period: Option<&str>,
+    offset: Option<&str>,
+    closed: ClosedWindow,
+) -> AxionResult<GroupBy<'a>> {
+    let column = df.column(time_col)?;
+    let is_integer = column.dtype().is_integer();
+
+    let every_step = WindowStep::parse(every, is_integer)?;
+    every_step.check_positive("every", every)?;
+    if let WindowStep::Calendar(interval) = &every_step {
+        interval.check_truncatable(every)?;
+    }
+    let period_step = match period {
+        Some(p) => {
+            let step = WindowStep::parse(p, is_integer)?;
+            step.check_positive("period", p)?;
+            step
+        }
+        None => every_step,
+    };
+    let offset_step = offset.map(|o| WindowStep::parse(o, is_integer)).transpose()?;
+
+    // 时间列统一转换为 i128：整数列取原值，时间列取本地时间的纳秒数
+    let (times, accessor) = if is_integer {
+        let as_i64 = column.cast(DataType::Int64)?;
+        let values: &Series<i64> = as_i64.as_any().downcast_ref().ok_or_else(|| {
+            AxionError::InternalError(format!("列 '{}' 转换为 Int64 失败", time_col))
+        })?;
+        (values.iter().map(|opt| opt.map(|v| *v as i128)).collect::<Vec<_>>(), None)
+    } else {
+        let accessor = DatetimeAccessor::from_series(column)?;
+        (accessor.local_instants(), Some(accessor))
+    };
+
+    let mut order: Vec<usize> = (0..times.len()).filter(|&row| times[row].is_some()).collect();
+    order.sort_by_key(|&row| times[row]);
+    let sorted_times: Vec<i128> = order.iter().filter_map(|&row| times[row]).collect();
+
+    let mut starts: Vec<i128> = Vec::new();
+    let mut windows: Vec<Vec<usize>> = Vec::new();
+    if let (Some(&t_min), Some(&t_max)) = (sorted_times.first(), sorted_times.last()) {
+        let mut start = every_step.truncate(t_min);
+        if let Some(step) = &offset_step {
+            start = step.add_to(start);
+        }
+        while start > t_min || (start == t_min && !closed.includes_start()) {
+            start = every_step.sub_from(start);
+        }
+
+        while start <= t_max {
+            let end = period_step.add_to(start);
+            let lower = sorted_times.partition_point(|&t| if closed.includes_start() { t < start } else { t <= start });
+            let upper = sorted_times.partition_point(|&t| if closed.includes_end() { t <= end } else { t < end });
+            if lower < upper {
+                starts.push(start);
+                windows.push(order[lower..upper].to_vec());
+            }
+            start = every_step.add_to(start);
+            let next_pos = sorted_times.partition_point(|&t| t < start);
+            if next_pos == sorted_times.len() {
+                break;
+            }
+            // 固定步长时直接跳过不可能包含剩余数据的空窗口
+            if let (Some(every_size), Some(period_size)) = (every_step.fixed_size(), period_step.fixed_size()) {
+                let skip = (sorted_times[next_pos] - period_size - start).div_euclid(every_size);
+                if skip > 0 {
+                    start += skip * every_size;
+                }
+            }
+        }
+    }
+
+    let window_keys: Box<dyn SeriesTrait> = match &accessor {
+        Some(accessor) => Box::new(accessor.datetime_series_from_local(time_col.to_string(), &starts)?),
+        None => {
+            // 起点按升序排列且不超过最大值，只有第一个窗口可能因负的 offset 落在列类型的范围之外（如无符号列中的负数）
+            let out_of_range = || {
+                AxionError::InvalidArgument(format!(
+                    "列 '{}' 的第一个窗口起点 {} 超出数据类型 {:?} 的范围，请调整 offset",
+                    time_col,
+                    starts[0],
+                    column.dtype()
+                ))
+            };
+            let data = starts
+                .iter()
+                .map(|start| i64::try_from(*start).map(Some))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| out_of_range())?;
+            SeriesTrait::cast(&Series::new_from_options(time_col.to_string(), data), column.dtype())
+                .map_err(|_| out_of_range())?
+        }
+    };
+
+    Ok(GroupBy::from_windows(df, time_col.to_string(), window_keys, windows))
+}
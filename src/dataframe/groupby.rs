@@ -1,33 +1,97 @@
-use crate::dataframe::DataFrame;
+use crate::dataframe::{concat, ConcatType, DataFrame};
 use crate::error::{AxionError, AxionResult};
-use crate::series::{SeriesTrait, Series};
+use crate::series::{CategoricalSeries, ListSeries, SeriesTrait, Series};
 use crate::dtype::{DataType, DataTypeTrait};
-use std::collections::HashMap;
+use crate::decimal::{self, Decimal};
+use crate::temporal::{Date, Datetime, Duration};
+use std::collections::hash_map::RandomState;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+use rayon::prelude::*;
+use std::cmp::Ordering;
 use std::any::Any;
-use num_traits::Float;
+use num_traits::{Float, SaturatingAdd, Zero};
 use std::fmt::Debug;
 
 use super::types::{GroupKeyValue, GroupKey};
 
 /// 根据数据类型创建空的 Series
-fn create_empty_series_from_dtype(name: String, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+pub(crate) fn create_empty_series_from_dtype(name: String, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
     match dtype {
         DataType::Int8 => Ok(Box::new(Series::<i8>::new_empty(name, dtype))),
         DataType::Int16 => Ok(Box::new(Series::<i16>::new_empty(name, dtype))),
         DataType::Int32 => Ok(Box::new(Series::<i32>::new_empty(name, dtype))),
         DataType::Int64 => Ok(Box::new(Series::<i64>::new_empty(name, dtype))),
+        DataType::Int128 => Ok(Box::new(Series::<i128>::new_empty(name, dtype))),
         DataType::UInt8 => Ok(Box::new(Series::<u8>::new_empty(name, dtype))),
         DataType::UInt16 => Ok(Box::new(Series::<u16>::new_empty(name, dtype))),
         DataType::UInt32 => Ok(Box::new(Series::<u32>::new_empty(name, dtype))),
         DataType::UInt64 => Ok(Box::new(Series::<u64>::new_empty(name, dtype))),
+        DataType::UInt128 => Ok(Box::new(Series::<u128>::new_empty(name, dtype))),
         DataType::Float32 => Ok(Box::new(Series::<f32>::new_empty(name, dtype))),
         DataType::Float64 => Ok(Box::new(Series::<f64>::new_empty(name, dtype))),
         DataType::String => Ok(Box::new(Series::<String>::new_empty(name, dtype))),
         DataType::Bool => Ok(Box::new(Series::<bool>::new_empty(name, dtype))),
+        DataType::Decimal(_, _) => Ok(Box::new(Series::<Decimal>::new_empty(name, dtype))),
+        DataType::Date => Ok(Box::new(Series::<Date>::new_empty(name, dtype))),
+        DataType::Datetime(_, _) => Ok(Box::new(Series::<Datetime>::new_empty(name, dtype))),
+        DataType::Duration(_) => Ok(Box::new(Series::<Duration>::new_empty(name, dtype))),
         _ => Err(AxionError::UnsupportedOperation(format!("无法为数据类型 {:?} 创建空 Series", dtype))),
     }
 }
 
+/// 将单个分组键列转换为分组键值向量，`None` 表示该行的键为 null
+pub(crate) fn column_group_values(col: &dyn SeriesTrait) -> AxionResult<Vec<Option<GroupKeyValue>>> {
+    macro_rules! collect_group_values {
+        ($t:ty, $v:ident => $conv:expr) => {{
+            let series = col.as_any().downcast_ref::<Series<$t>>().ok_or_else(|| {
+                AxionError::InternalError(format!(
+                    "无法将分组列 '{}' 向下转型为 {}",
+                    col.name(),
+                    std::any::type_name::<$t>()
+                ))
+            })?;
+            Ok(series.iter().map(|opt| opt.map(|$v| $conv)).collect())
+        }};
+    }
+
+    match col.dtype() {
+        DataType::Int8 => collect_group_values!(i8, v => GroupKeyValue::Int8(*v)),
+        DataType::Int16 => collect_group_values!(i16, v => GroupKeyValue::Int16(*v)),
+        DataType::Int32 => collect_group_values!(i32, v => GroupKeyValue::Int32(*v)),
+        DataType::Int64 => collect_group_values!(i64, v => GroupKeyValue::Int64(*v)),
+        DataType::Int128 => collect_group_values!(i128, v => GroupKeyValue::Int128(*v)),
+        DataType::UInt8 => collect_group_values!(u8, v => GroupKeyValue::UInt8(*v)),
+        DataType::UInt16 => collect_group_values!(u16, v => GroupKeyValue::UInt16(*v)),
+        DataType::UInt32 => collect_group_values!(u32, v => GroupKeyValue::UInt32(*v)),
+        DataType::UInt64 => collect_group_values!(u64, v => GroupKeyValue::UInt64(*v)),
+        DataType::UInt128 => collect_group_values!(u128, v => GroupKeyValue::UInt128(*v)),
+        DataType::Float32 => collect_group_values!(f32, v => GroupKeyValue::from_f32(*v)),
+        DataType::Float64 => collect_group_values!(f64, v => GroupKeyValue::from_f64(*v)),
+        DataType::String => collect_group_values!(String, v => GroupKeyValue::Str(v.clone())),
+        DataType::Bool => collect_group_values!(bool, v => GroupKeyValue::Bool(*v)),
+        // 时间类型按底层整数分组，同一列内的时间单位和时区相同
+        DataType::Date => collect_group_values!(Date, v => GroupKeyValue::Int32(v.0)),
+        DataType::Datetime(_, _) => collect_group_values!(Datetime, v => GroupKeyValue::Int64(v.0)),
+        DataType::Duration(_) => collect_group_values!(Duration, v => GroupKeyValue::Int64(v.0)),
+        // 同一列内的小数位数相同，直接按缩放后的整数分组
+        DataType::Decimal(_, _) => collect_group_values!(Decimal, v => GroupKeyValue::Int128(v.0)),
+        DataType::Categorical => {
+            let series = col.as_any().downcast_ref::<CategoricalSeries>().ok_or_else(|| {
+                AxionError::InternalError(format!("无法将分组列 '{}' 向下转型为 CategoricalSeries", col.name()))
+            })?;
+            // 同一列内编码与类别一一对应，直接按编码分组
+            Ok(series.codes().iter().map(|opt| opt.map(|code| GroupKeyValue::UInt32(*code))).collect())
+        }
+        DataType::Null => Ok(vec![None; col.len()]),
+        unsupported_dtype => Err(AxionError::UnsupportedOperation(format!(
+            "列 '{}' 的数据类型 {:?} 不支持分组操作",
+            col.name(),
+            unsupported_dtype
+        ))),
+    }
+}
+
 /// 聚合值枚举，用于表示分组聚合操作的结果
 #[derive(Debug, Clone, PartialEq)]
 enum AggValue {
@@ -35,12 +99,18 @@ enum AggValue {
     Int16(Option<i16>), 
     Int32(Option<i32>), 
     Int64(Option<i64>),
+    Int128(Option<i128>),
     UInt8(Option<u8>), 
     UInt16(Option<u16>), 
     UInt32(Option<u32>), 
     UInt64(Option<u64>),
+    UInt128(Option<u128>),
     Float32(Option<f32>), 
     Float64(Option<f64>),
+    Decimal(Option<Decimal>),
+    Date(Option<Date>),
+    Datetime(Option<Datetime>),
+    Duration(Option<Duration>),
     String(Option<String>), 
     Bool(Option<bool>),
     None, // 表示组内全为 null 或类型不匹配
@@ -56,12 +126,18 @@ impl AggValue {
                 else if let Some(v) = any_val.downcast_ref::<i16>() { AggValue::Int16(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<i32>() { AggValue::Int32(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<i64>() { AggValue::Int64(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<i128>() { AggValue::Int128(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<u8>() { AggValue::UInt8(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<u16>() { AggValue::UInt16(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<u32>() { AggValue::UInt32(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<u64>() { AggValue::UInt64(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<u128>() { AggValue::UInt128(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<f32>() { AggValue::Float32(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<f64>() { AggValue::Float64(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<Decimal>() { AggValue::Decimal(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<Date>() { AggValue::Date(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<Datetime>() { AggValue::Datetime(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<Duration>() { AggValue::Duration(Some(*v)) }
                 else if let Some(v) = any_val.downcast_ref::<String>() { AggValue::String(Some(v.clone())) }
                 else if let Some(v) = any_val.downcast_ref::<bool>() { AggValue::Bool(Some(*v)) }
                 else {
@@ -153,12 +229,18 @@ macro_rules! dispatch_min_max {
             DataType::Int16 => calculate_min_max::<i16>($series_trait, $indices, $find_min),
             DataType::Int32 => calculate_min_max::<i32>($series_trait, $indices, $find_min),
             DataType::Int64 => calculate_min_max::<i64>($series_trait, $indices, $find_min),
+            DataType::Int128 => calculate_min_max::<i128>($series_trait, $indices, $find_min),
             DataType::UInt8 => calculate_min_max::<u8>($series_trait, $indices, $find_min),
             DataType::UInt16 => calculate_min_max::<u16>($series_trait, $indices, $find_min),
             DataType::UInt32 => calculate_min_max::<u32>($series_trait, $indices, $find_min),
             DataType::UInt64 => calculate_min_max::<u64>($series_trait, $indices, $find_min),
+            DataType::UInt128 => calculate_min_max::<u128>($series_trait, $indices, $find_min),
             DataType::Float32 => calculate_min_max_float::<f32>($series_trait, $indices, $find_min),
             DataType::Float64 => calculate_min_max_float::<f64>($series_trait, $indices, $find_min),
+            DataType::Decimal(_, _) => calculate_min_max::<Decimal>($series_trait, $indices, $find_min),
+            DataType::Date => calculate_min_max::<Date>($series_trait, $indices, $find_min),
+            DataType::Datetime(_, _) => calculate_min_max::<Datetime>($series_trait, $indices, $find_min),
+            DataType::Duration(_) => calculate_min_max::<Duration>($series_trait, $indices, $find_min),
             DataType::String => calculate_min_max::<String>($series_trait, $indices, $find_min),
             DataType::Bool => calculate_min_max::<bool>($series_trait, $indices, $find_min),
             _ => Err(AxionError::UnsupportedOperation(format!("数据类型 {:?} 不支持 Min/Max 操作", $dtype))),
@@ -166,6 +248,538 @@ macro_rules! dispatch_min_max {
     };
 }
 
+/// 计算整数求和的泛型函数（溢出时饱和）
+fn calculate_sum_int<T>(series_trait: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<AggValue>
+where
+    T: DataTypeTrait + SaturatingAdd + Zero + Clone + Debug + 'static,
+{
+    let series = series_trait.as_any().downcast_ref::<Series<T>>()
+        .ok_or_else(|| AxionError::InternalError(format!("无法将 Series 向下转型为预期类型 {:?}", std::any::type_name::<T>())))?;
+
+    let mut current_sum: Option<T> = None;
+    for &idx in indices {
+        if let Some(val) = series.get(idx) {
+            current_sum = Some(current_sum.unwrap_or_else(T::zero).saturating_add(val));
+        }
+    }
+    Ok(AggValue::from_option(current_sum))
+}
+
+/// 计算 8 位和 16 位整数求和的泛型函数，先扩展为 64 位再累加（溢出时饱和）
+fn calculate_sum_widened<T, W>(series_trait: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<AggValue>
+where
+    T: DataTypeTrait + Copy + Into<W> + 'static,
+    W: SaturatingAdd + Zero + Clone + Debug + 'static,
+{
+    let series = series_trait.as_any().downcast_ref::<Series<T>>()
+        .ok_or_else(|| AxionError::InternalError(format!("无法将 Series 向下转型为预期类型 {:?}", std::any::type_name::<T>())))?;
+
+    let mut current_sum: Option<W> = None;
+    for &idx in indices {
+        if let Some(val) = series.get(idx) {
+            current_sum = Some(current_sum.unwrap_or_else(W::zero).saturating_add(&(*val).into()));
+        }
+    }
+    Ok(AggValue::from_option(current_sum))
+}
+
+/// 计算浮点数求和的泛型函数（忽略 NaN）
+fn calculate_sum_float<T>(series_trait: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<AggValue>
+where
+    T: DataTypeTrait + Float + Clone + Debug + 'static,
+{
+    let series = series_trait.as_any().downcast_ref::<Series<T>>()
+        .ok_or_else(|| AxionError::InternalError(format!("无法将 Series 向下转型为预期浮点类型 {:?}", std::any::type_name::<T>())))?;
+
+    let mut current_sum: Option<T> = None;
+    for &idx in indices {
+        if let Some(val) = series.get(idx) {
+            if val.is_nan() {
+                continue;
+            }
+            current_sum = Some(current_sum.unwrap_or_else(T::zero) + *val);
+        }
+    }
+    Ok(AggValue::from_option(current_sum))
+}
+
+/// 根据数据类型分发求和计算的宏
+macro_rules! dispatch_sum {
+    ($series_trait:expr, $dtype:expr, $indices:expr) => {
+        match $dtype {
+            DataType::Int8 => calculate_sum_widened::<i8, i64>($series_trait, $indices),
+            DataType::Int16 => calculate_sum_widened::<i16, i64>($series_trait, $indices),
+            DataType::Int32 => calculate_sum_int::<i32>($series_trait, $indices),
+            DataType::Int64 => calculate_sum_int::<i64>($series_trait, $indices),
+            DataType::Int128 => calculate_sum_int::<i128>($series_trait, $indices),
+            DataType::UInt8 => calculate_sum_widened::<u8, u64>($series_trait, $indices),
+            DataType::UInt16 => calculate_sum_widened::<u16, u64>($series_trait, $indices),
+            DataType::UInt32 => calculate_sum_int::<u32>($series_trait, $indices),
+            DataType::UInt64 => calculate_sum_int::<u64>($series_trait, $indices),
+            DataType::UInt128 => calculate_sum_int::<u128>($series_trait, $indices),
+            DataType::Float32 => calculate_sum_float::<f32>($series_trait, $indices),
+            DataType::Float64 => calculate_sum_float::<f64>($series_trait, $indices),
+            DataType::Decimal(_, _) => calculate_sum_int::<Decimal>($series_trait, $indices),
+            _ => Err(AxionError::UnsupportedOperation(format!("数据类型 {:?} 不支持 Sum 操作", $dtype))),
+        }
+    };
+}
+
+/// 计算组内非 NaN 数值的平均值
+fn calculate_mean(series_trait: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<AggValue> {
+    let mut current_sum: f64 = 0.0;
+    let mut current_count: u32 = 0;
+    for &idx in indices {
+        if let Some(value_f64) = series_trait.get_as_f64(idx)? {
+            if !value_f64.is_nan() {
+                current_sum += value_f64;
+                current_count += 1;
+            }
+        }
+    }
+    let mean_value = if current_count > 0 {
+        Some(current_sum / current_count as f64)
+    } else {
+        None
+    };
+    Ok(AggValue::Float64(mean_value))
+}
+
+/// 计算 Decimal 列组内的精确平均值，保持原列的小数位数
+fn calculate_mean_decimal(series_trait: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<AggValue> {
+    let series = series_trait.as_any().downcast_ref::<Series<Decimal>>()
+        .ok_or_else(|| AxionError::InternalError(format!("无法将 Series '{}' 向下转型为 Decimal", series_trait.name())))?;
+    let mean = decimal::mean_raw(indices.iter().filter_map(|&idx| series.get(idx)).map(|v| v.0));
+    Ok(AggValue::Decimal(mean.map(Decimal)))
+}
+
+/// 将聚合值向量转换为指定数据类型的 Series
+///
+/// 与目标类型不匹配的聚合值（包括 `AggValue::None`）视为 null。
+fn series_from_agg_values(name: String, dtype: &DataType, values: Vec<AggValue>) -> AxionResult<Box<dyn SeriesTrait>> {
+    macro_rules! build_series {
+        ($variant:ident, $t:ty) => {{
+            let data: Vec<Option<$t>> = values
+                .into_iter()
+                .map(|value| match value {
+                    AggValue::$variant(opt_val) => opt_val,
+                    _ => None,
+                })
+                .collect();
+            Ok(Box::new(Series::new_from_options(name, data)))
+        }};
+        // 带类型参数（小数位数、时间单位和时区）的类型需要恢复完整的数据类型
+        ($variant:ident, $t:ty, with_dtype) => {{
+            let data: Vec<Option<$t>> = values
+                .into_iter()
+                .map(|value| match value {
+                    AggValue::$variant(opt_val) => opt_val,
+                    _ => None,
+                })
+                .collect();
+            Ok(Box::new(Series::new_from_options(name, data).with_dtype(dtype.clone())?))
+        }};
+    }
+
+    match dtype {
+        DataType::Int8 => build_series!(Int8, i8),
+        DataType::Int16 => build_series!(Int16, i16),
+        DataType::Int32 => build_series!(Int32, i32),
+        DataType::Int64 => build_series!(Int64, i64),
+        DataType::Int128 => build_series!(Int128, i128),
+        DataType::UInt8 => build_series!(UInt8, u8),
+        DataType::UInt16 => build_series!(UInt16, u16),
+        DataType::UInt32 => build_series!(UInt32, u32),
+        DataType::UInt64 => build_series!(UInt64, u64),
+        DataType::UInt128 => build_series!(UInt128, u128),
+        DataType::Float32 => build_series!(Float32, f32),
+        DataType::Float64 => build_series!(Float64, f64),
+        DataType::String => build_series!(String, String),
+        DataType::Bool => build_series!(Bool, bool),
+        DataType::Decimal(_, _) => build_series!(Decimal, Decimal, with_dtype),
+        DataType::Date => build_series!(Date, Date, with_dtype),
+        DataType::Datetime(_, _) => build_series!(Datetime, Datetime, with_dtype),
+        DataType::Duration(_) => build_series!(Duration, Duration, with_dtype),
+        _ => Err(AxionError::UnsupportedOperation(format!("无法从聚合结果创建数据类型为 {:?} 的 Series", dtype))),
+    }
+}
+
+/// 收集组内所有非 null 且非 NaN 的数值（转换为 f64）
+fn collect_valid_f64(series_trait: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<Vec<f64>> {
+    let mut values = Vec::with_capacity(indices.len());
+    for &idx in indices {
+        if let Some(value) = series_trait.get_as_f64(idx)? {
+            if !value.is_nan() {
+                values.push(value);
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// 计算组内数值的方差，`ddof` 为自由度修正（有效值数量不超过 `ddof` 时结果为 null）
+fn calculate_var(series_trait: &dyn SeriesTrait, indices: &[usize], ddof: u8) -> AxionResult<Option<f64>> {
+    let values = collect_valid_f64(series_trait, indices)?;
+    if values.len() <= ddof as usize {
+        return Ok(None);
+    }
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let squared_diff_sum: f64 = values.iter().map(|v| (v - mean).powi(2)).sum();
+    Ok(Some(squared_diff_sum / (values.len() - ddof as usize) as f64))
+}
+
+/// 计算组内数值的分位数，使用线性插值
+fn calculate_quantile(series_trait: &dyn SeriesTrait, indices: &[usize], q: f64) -> AxionResult<AggValue> {
+    let mut values = collect_valid_f64(series_trait, indices)?;
+    if values.is_empty() {
+        return Ok(AggValue::Float64(None));
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let position = q * (values.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let quantile = values[lower] + (values[upper] - values[lower]) * (position - lower as f64);
+    Ok(AggValue::Float64(Some(quantile)))
+}
+
+/// 判断数据类型是否支持 Min/Max 比较
+fn is_comparable_dtype(dtype: &DataType) -> bool {
+    dtype.is_numeric() || dtype.is_temporal() || matches!(dtype, DataType::String | DataType::Bool)
+}
+
+/// 分组聚合函数
+///
+/// 用于 [`GroupBy::agg`] 中为每个输出列指定聚合方式。
+/// 所有聚合都会忽略组内的 null 值。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Agg {
+    /// 非 null 值的数量，结果类型为 UInt32
+    Count,
+    /// 求和，结果类型与原列相同（整数溢出时饱和，浮点数忽略 NaN，Decimal 列的总位数扩展为 38）；
+    /// 8 位和 16 位整数列的结果扩展为 Int64 或 UInt64
+    Sum,
+    /// 平均值，结果类型为 Float64（忽略 NaN）；Decimal 列保持原类型，结果四舍五入到原小数位数
+    Mean,
+    /// 最小值，结果类型与原列相同
+    Min,
+    /// 最大值，结果类型与原列相同
+    Max,
+    /// 标准差，参数为自由度修正 ddof，结果类型为 Float64（忽略 NaN）
+    Std(u8),
+    /// 方差，参数为自由度修正 ddof，结果类型为 Float64（忽略 NaN）
+    Var(u8),
+    /// 中位数，结果类型为 Float64（忽略 NaN）
+    Median,
+    /// 分位数，参数取值范围为 `[0, 1]`，使用线性插值，结果类型为 Float64（忽略 NaN）
+    Quantile(f64),
+    /// 不同值的数量，结果类型为 UInt32
+    NUnique,
+    /// 第一个非 null 值，结果类型与原列相同
+    First,
+    /// 最后一个非 null 值，结果类型与原列相同
+    Last,
+    /// 第 n 个非 null 值（从 0 开始，负数表示从末尾倒数），超出范围时为 null，结果类型与原列相同
+    Nth(i64),
+    /// 将组内所有值（包括 null）按原顺序收集为一个列表，结果类型为 List(原列类型)
+    Implode,
+}
+
+impl Agg {
+    /// 根据输入列的数据类型确定聚合结果的数据类型
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::UnsupportedOperation` - 该聚合不支持此数据类型
+    /// * `AxionError::InvalidArgument` - 分位数参数超出 `[0, 1]` 范围
+    fn output_dtype(&self, col_name: &str, input_dtype: &DataType) -> AxionResult<DataType> {
+        if let Agg::Quantile(q) = self {
+            if !(0.0..=1.0).contains(q) {
+                return Err(AxionError::InvalidArgument(format!("分位数必须在 [0, 1] 范围内，实际为 {}", q)));
+            }
+        }
+        let supported = match self {
+            Agg::Count | Agg::First | Agg::Last | Agg::Nth(_) | Agg::Implode => true,
+            Agg::Sum | Agg::Mean | Agg::Std(_) | Agg::Var(_) | Agg::Median | Agg::Quantile(_) => input_dtype.is_numeric(),
+            Agg::Min | Agg::Max => is_comparable_dtype(input_dtype),
+            Agg::NUnique => !matches!(input_dtype, DataType::List(_)),
+        };
+        if !supported {
+            return Err(AxionError::UnsupportedOperation(format!(
+                "列 '{}' 的数据类型 {:?} 不支持 {:?} 聚合",
+                col_name, input_dtype, self
+            )));
+        }
+        Ok(match (self, input_dtype) {
+            (Agg::Sum, DataType::Decimal(_, scale)) => DataType::Decimal(decimal::MAX_DECIMAL_PRECISION, *scale),
+            (Agg::Sum, DataType::Int8 | DataType::Int16) => DataType::Int64,
+            (Agg::Sum, DataType::UInt8 | DataType::UInt16) => DataType::UInt64,
+            (Agg::Mean, DataType::Decimal(_, _)) => input_dtype.clone(),
+            (Agg::Count | Agg::NUnique, _) => DataType::UInt32,
+            (Agg::Implode, _) => DataType::List(Box::new(input_dtype.clone())),
+            (Agg::Mean | Agg::Std(_) | Agg::Var(_) | Agg::Median | Agg::Quantile(_), _) => DataType::Float64,
+            _ => input_dtype.clone(),
+        })
+    }
+
+    /// 对列中的所有组计算聚合，返回名为 `output_name` 的结果 Series
+    pub(crate) fn aggregate(&self, col: &dyn SeriesTrait, output_name: &str, group_rows: &[&Vec<usize>]) -> AxionResult<Box<dyn SeriesTrait>> {
+        let output_dtype = self.output_dtype(col.name(), &col.dtype())?;
+        let mut result = match self {
+            Agg::First | Agg::Last | Agg::Nth(_) => {
+                // 直接从原列取值，因此支持任意数据类型
+                let selected: Vec<Option<usize>> = group_rows
+                    .iter()
+                    .map(|rows| self.select_row(col, rows))
+                    .collect();
+                col.take_indices_option(&selected)?
+            }
+            Agg::Implode => {
+                let lists = group_rows
+                    .iter()
+                    .map(|rows| col.take_indices(rows).map(Some))
+                    .collect::<AxionResult<Vec<_>>>()?;
+                Box::new(ListSeries::new(output_name.to_string(), lists, col.dtype()))
+            }
+            _ if group_rows.is_empty() => create_empty_series_from_dtype(output_name.to_string(), output_dtype)?,
+            Agg::NUnique => {
+                let key_values = column_group_values(col)?;
+                let counts: Vec<Option<u32>> = group_rows
+                    .iter()
+                    .map(|rows| {
+                        let distinct: HashSet<&GroupKeyValue> = rows
+                            .iter()
+                            .filter_map(|&idx| key_values[idx].as_ref())
+                            .collect();
+                        Some(distinct.len() as u32)
+                    })
+                    .collect();
+                Box::new(Series::new_from_options(output_name.to_string(), counts))
+            }
+            _ => {
+                let values = group_rows
+                    .iter()
+                    .map(|indices| self.evaluate(col, indices))
+                    .collect::<AxionResult<Vec<AggValue>>>()?;
+                series_from_agg_values(output_name.to_string(), &output_dtype, values)?
+            }
+        };
+        result.rename(output_name);
+        Ok(result)
+    }
+
+    /// 为 First/Last/Nth 选出组内对应的行索引，不存在时返回 `None`
+    fn select_row(&self, col: &dyn SeriesTrait, indices: &[usize]) -> Option<usize> {
+        let mut valid = indices.iter().copied().filter(|&idx| !col.is_null_at(idx));
+        match self {
+            Agg::First => valid.next(),
+            Agg::Last => valid.last(),
+            Agg::Nth(n) if *n >= 0 => valid.nth(*n as usize),
+            Agg::Nth(n) => {
+                let valid: Vec<usize> = valid.collect();
+                valid.len().checked_sub(n.unsigned_abs() as usize).map(|pos| valid[pos])
+            }
+            _ => None,
+        }
+    }
+
+    /// 对单个组计算聚合值
+    fn evaluate(&self, col: &dyn SeriesTrait, indices: &[usize]) -> AxionResult<AggValue> {
+        let dtype = col.dtype();
+        match self {
+            Agg::Count => {
+                let count = indices.iter().filter(|&&idx| !col.is_null_at(idx)).count();
+                Ok(AggValue::UInt32(Some(count as u32)))
+            }
+            Agg::Sum => dispatch_sum!(col, &dtype, indices),
+            Agg::Mean if dtype.is_decimal() => calculate_mean_decimal(col, indices),
+            Agg::Mean => calculate_mean(col, indices),
+            Agg::Min => dispatch_min_max!(col, &dtype, indices, true),
+            Agg::Max => dispatch_min_max!(col, &dtype, indices, false),
+            Agg::Var(ddof) => Ok(AggValue::Float64(calculate_var(col, indices, *ddof)?)),
+            Agg::Std(ddof) => Ok(AggValue::Float64(calculate_var(col, indices, *ddof)?.map(f64::sqrt))),
+            Agg::Median => calculate_quantile(col, indices, 0.5),
+            Agg::Quantile(q) => calculate_quantile(col, indices, *q),
+            Agg::NUnique | Agg::First | Agg::Last | Agg::Nth(_) | Agg::Implode => Err(AxionError::InternalError(format!(
+                "{:?} 聚合不应逐组求值",
+                self
+            ))),
+        }
+    }
+}
+
+/// 构建指定行的分组键，`dropna` 为 `true` 且键中包含 null 时返回 `None`
+fn row_group_key(key_values: &[Vec<Option<GroupKeyValue>>], row_idx: usize, dropna: bool) -> Option<GroupKey> {
+    let mut key: GroupKey = Vec::with_capacity(key_values.len());
+    for column_values in key_values {
+        match &column_values[row_idx] {
+            Some(value) => key.push(value.clone()),
+            None if dropna => return None,
+            None => key.push(GroupKeyValue::Null),
+        }
+    }
+    Some(key)
+}
+
+/// 按分组键首次出现的顺序串行构建各组
+fn build_groups_serial(
+    key_values: &[Vec<Option<GroupKeyValue>>],
+    height: usize,
+    dropna: bool,
+) -> Vec<(GroupKey, Vec<usize>)> {
+    let mut group_positions: HashMap<GroupKey, usize> = HashMap::new();
+    let mut groups: Vec<(GroupKey, Vec<usize>)> = Vec::new();
+    for row_idx in 0..height {
+        let Some(current_key) = row_group_key(key_values, row_idx, dropna) else {
+            continue;
+        };
+        match group_positions.get(&current_key) {
+            Some(&position) => groups[position].1.push(row_idx),
+            None => {
+                group_positions.insert(current_key.clone(), groups.len());
+                groups.push((current_key, vec![row_idx]));
+            }
+        }
+    }
+    groups
+}
+
+/// 使用分区哈希并行构建各组，输出顺序与 [`build_groups_serial`] 完全一致
+///
+/// 每行按分组键的哈希值分配到一个分区，同一个键的所有行必然落在同一分区，
+/// 因此各分区可以独立建组。合并后按每组首行索引排序，即恢复首次出现顺序。
+fn build_groups_parallel(
+    key_values: &[Vec<Option<GroupKeyValue>>],
+    height: usize,
+    dropna: bool,
+) -> Vec<(GroupKey, Vec<usize>)> {
+    let partition_count = rayon::current_num_threads().max(1);
+    let hasher = RandomState::new();
+
+    let row_keys: Vec<Option<(usize, GroupKey)>> = (0..height)
+        .into_par_iter()
+        .map(|row_idx| {
+            row_group_key(key_values, row_idx, dropna)
+                .map(|key| ((hasher.hash_one(&key) % partition_count as u64) as usize, key))
+        })
+        .collect();
+
+    let mut partitions: Vec<Vec<usize>> = vec![Vec::new(); partition_count];
+    for (row_idx, row_key) in row_keys.iter().enumerate() {
+        if let Some((partition, _)) = row_key {
+            partitions[*partition].push(row_idx);
+        }
+    }
+
+    let mut groups: Vec<(GroupKey, Vec<usize>)> = partitions
+        .into_par_iter()
+        .flat_map_iter(|rows| {
+            let mut group_positions: HashMap<&GroupKey, usize> = HashMap::new();
+            let mut local_groups: Vec<(GroupKey, Vec<usize>)> = Vec::new();
+            for row_idx in rows {
+                let Some((_, key)) = &row_keys[row_idx] else {
+                    continue;
+                };
+                match group_positions.get(key) {
+                    Some(&position) => local_groups[position].1.push(row_idx),
+                    None => {
+                        group_positions.insert(key, local_groups.len());
+                        local_groups.push((key.clone(), vec![row_idx]));
+                    }
+                }
+            }
+            local_groups
+        })
+        .collect();
+
+    groups.par_sort_unstable_by_key(|(_, rows)| rows[0]);
+    groups
+}
+
+/// 分组操作的配置选项
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::dataframe::GroupByOptions;
+/// # use axion_data::{df, AxionError};
+/// # let df = df!("region" => vec![Some("north"), None, Some("east")])?;
+/// let options = GroupByOptions::builder().dropna(false).sort(true).build();
+/// let counts = df.groupby_with_options(&["region"], options)?.count()?;
+/// # Ok::<(), AxionError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupByOptions {
+    /// 是否丢弃分组键中包含 null 的行，默认为 `true`
+    /// 如果为 `false`，null 键将单独成组
+    pub dropna: bool,
+    /// 是否按分组键升序输出各组（null 排在最后），默认为 `false`
+    /// 未开启时各组按分组键首次出现的顺序输出
+    pub sort: bool,
+    /// 是否使用 Rayon 并行构建分组并并行计算各列的聚合，默认为 `false`
+    /// 结果与串行计算完全一致
+    pub parallel: bool,
+}
+
+impl Default for GroupByOptions {
+    fn default() -> Self {
+        GroupByOptions {
+            dropna: true,
+            sort: false,
+            parallel: false,
+        }
+    }
+}
+
+impl GroupByOptions {
+    /// 创建一个新的 GroupByOptions 构建器，使用默认值
+    pub fn builder() -> GroupByOptionsBuilder {
+        GroupByOptionsBuilder::new()
+    }
+}
+
+/// GroupByOptions 的构建器
+#[derive(Debug, Clone, Default)]
+pub struct GroupByOptionsBuilder {
+    dropna: Option<bool>,
+    sort: Option<bool>,
+    parallel: Option<bool>,
+}
+
+impl GroupByOptionsBuilder {
+    /// 创建一个新的构建器实例
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// 设置是否丢弃包含 null 的分组键
+    pub fn dropna(mut self, dropna: bool) -> Self {
+        self.dropna = Some(dropna);
+        self
+    }
+
+    /// 设置是否按分组键排序输出
+    pub fn sort(mut self, sort: bool) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// 设置是否并行执行分组和聚合
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = Some(parallel);
+        self
+    }
+
+    /// 构建 GroupByOptions 实例
+    pub fn build(self) -> GroupByOptions {
+        let defaults = GroupByOptions::default();
+        GroupByOptions {
+            dropna: self.dropna.unwrap_or(defaults.dropna),
+            sort: self.sort.unwrap_or(defaults.sort),
+            parallel: self.parallel.unwrap_or(defaults.parallel),
+        }
+    }
+}
+
 /// 表示分组操作的中间状态。
 /// 
 /// 持有对原始 DataFrame 的引用和计算出的分组索引。
@@ -174,10 +788,12 @@ macro_rules! dispatch_min_max {
 /// # 示例
 /// 
 /// ```rust
+/// # let df = axion_data::df!("类别" => vec!["A", "B", "A"], "值" => vec![1.0, 2.0, 3.0])?;
 /// let grouped = df.groupby(&["类别"])?;
 /// let count_result = grouped.count()?;
 /// let sum_result = grouped.sum()?;
 /// let mean_result = grouped.mean()?;
+/// # Ok::<(), axion_data::AxionError>(())
 /// ```
 #[derive(Debug)]
 pub struct GroupBy<'a> {
@@ -185,8 +801,12 @@ pub struct GroupBy<'a> {
     df: &'a DataFrame,
     /// 用于分组的列名
     keys: Vec<String>,
-    /// 从分组键值到行索引的映射
-    groups: HashMap<GroupKey, Vec<usize>>,
+    /// 各组的分组键和行索引，按输出顺序排列
+    groups: Vec<(GroupKey, Vec<usize>)>,
+    /// 是否并行计算各列的聚合
+    parallel: bool,
+    /// 动态窗口分组时每个组的键（窗口起点），为 `None` 时从原始键列取值
+    window_keys: Option<Box<dyn SeriesTrait>>,
 }
 
 impl<'a> GroupBy<'a> {
@@ -198,6 +818,7 @@ impl<'a> GroupBy<'a> {
     /// 
     /// * `df` - 要分组的 DataFrame 引用
     /// * `keys` - 用于分组的列名向量
+    /// * `options` - 分组配置
     ///
     /// # 返回值
     /// 
@@ -207,61 +828,139 @@ impl<'a> GroupBy<'a> {
     /// 
     /// * `AxionError::ColumnNotFound` - 指定的分组列不存在
     /// * `AxionError::UnsupportedOperation` - 列的数据类型不支持分组
-    pub(crate) fn new(df: &'a DataFrame, keys: Vec<String>) -> AxionResult<Self> {
-        let mut key_cols: Vec<&dyn SeriesTrait> = Vec::with_capacity(keys.len());
-        for key_name in &keys {
-            let col = df.column(key_name)?;
-            key_cols.push(col);
-            match col.dtype() {
-                DataType::Int32 | DataType::String | DataType::Bool => {},
-                unsupported_dtype => {
-                    return Err(AxionError::UnsupportedOperation(format!(
-                        "列 '{}' 的数据类型 {:?} 不支持分组操作",
-                        key_name, unsupported_dtype
-                    )));
-                }
-            }
+    pub(crate) fn new(df: &'a DataFrame, keys: Vec<String>, options: GroupByOptions) -> AxionResult<Self> {
+        let key_cols: Vec<&dyn SeriesTrait> = keys
+            .iter()
+            .map(|key_name| df.column(key_name))
+            .collect::<AxionResult<_>>()?;
+
+        let mut groups = if options.parallel {
+            let key_values = key_cols
+                .par_iter()
+                .map(|col| column_group_values(*col))
+                .collect::<AxionResult<Vec<_>>>()?;
+            build_groups_parallel(&key_values, df.height(), options.dropna)
+        } else {
+            let key_values = key_cols
+                .iter()
+                .map(|col| column_group_values(*col))
+                .collect::<AxionResult<Vec<_>>>()?;
+            build_groups_serial(&key_values, df.height(), options.dropna)
+        };
+
+        if options.sort {
+            groups.sort_by(|(_, a_rows), (_, b_rows)| {
+                key_cols
+                    .iter()
+                    .map(|col| col.compare_row(a_rows[0], b_rows[0]))
+                    .find(|order| *order != Ordering::Equal)
+                    .unwrap_or(Ordering::Equal)
+            });
         }
 
-        let mut groups: HashMap<GroupKey, Vec<usize>> = HashMap::new();
-        for row_idx in 0..df.height() {
-            let mut current_key: GroupKey = Vec::with_capacity(keys.len());
-            let mut has_null = false;
-
-            for key_col in &key_cols {
-                let key_value = match key_col.dtype() {
-                    DataType::Int32 => {
-                        let series = key_col.as_any().downcast_ref::<Series<i32>>().unwrap();
-                        match series.get(row_idx) {
-                            Some(v) => GroupKeyValue::Int(*v),
-                            None => { has_null = true; break; }
-                        }
-                    }
-                    DataType::String => {
-                        let series = key_col.as_any().downcast_ref::<Series<String>>().unwrap();
-                        match series.get(row_idx) {
-                            Some(v) => GroupKeyValue::Str(v.clone()),
-                            None => { has_null = true; break; }
-                        }
-                    }
-                    DataType::Bool => {
-                        let series = key_col.as_any().downcast_ref::<Series<bool>>().unwrap();
-                        match series.get(row_idx) {
-                            Some(v) => GroupKeyValue::Bool(*v),
-                            None => { has_null = true; break; }
-                        }
-                    }
-                    _ => unreachable!("类型检查后遇到不支持的分组类型"),
-                };
-                current_key.push(key_value);
-            }
+        Ok(Self { df, keys, groups, parallel: options.parallel, window_keys: None })
+    }
+
+    /// 由预先划分好的时间窗口创建 GroupBy（内部使用，由 DataFrame::groupby_dynamic 调用）
+    ///
+    /// # 参数
+    ///
+    /// * `df` - 要分组的 DataFrame 引用
+    /// * `time_col` - 时间列名，聚合时不参与计算
+    /// * `window_keys` - 每个窗口的键值，名称应与时间列相同
+    /// * `windows` - 每个窗口包含的行索引，一行可以属于多个窗口
+    pub(crate) fn from_windows(
+        df: &'a DataFrame,
+        time_col: String,
+        window_keys: Box<dyn SeriesTrait>,
+        windows: Vec<Vec<usize>>,
+    ) -> Self {
+        let groups = windows
+            .into_iter()
+            .enumerate()
+            .map(|(window_idx, rows)| (vec![GroupKeyValue::UInt64(window_idx as u64)], rows))
+            .collect();
+        Self { df, keys: vec![time_col], groups, parallel: false, window_keys: Some(window_keys) }
+    }
+
+    /// 按输出顺序获取各组的行索引
+    pub(crate) fn group_rows(&self) -> Vec<&Vec<usize>> {
+        self.groups.iter().map(|(_, rows)| rows).collect()
+    }
 
-            if !has_null {
-                groups.entry(current_key).or_default().push(row_idx);
+    /// 获取原 DataFrame 每一行所属组在输出顺序中的位置，未归入任何组的行为 `None`
+    ///
+    /// 动态窗口分组中一行属于多个窗口时，取最后一个窗口。
+    pub(crate) fn row_group_ids(&self) -> Vec<Option<usize>> {
+        let mut row_to_group: Vec<Option<usize>> = vec![None; self.df.height()];
+        for (group_idx, (_, rows)) in self.groups.iter().enumerate() {
+            for &row_idx in rows {
+                row_to_group[row_idx] = Some(group_idx);
             }
         }
+        row_to_group
+    }
 
-        Ok(Self { df, keys, groups })
+    /// 根据每个组的首行索引构建输出中的分组键列
+    ///
+    /// 直接从原始键列中取值，因此输出键列的数据类型与原列一致。
+    /// 动态窗口分组时直接返回各窗口的键。
+    pub(crate) fn key_columns(&self, group_rows: &[&Vec<usize>]) -> AxionResult<Vec<Box<dyn SeriesTrait>>> {
+        if let Some(window_keys) = &self.window_keys {
+            return Ok(vec![window_keys.clone_box()]);
+        }
+        let first_rows: Vec<usize> = group_rows.iter().map(|rows| rows[0]).collect();
+        self.keys
+            .iter()
+            .map(|key_name| self.df.column(key_name)?.take_indices(&first_rows))
+            .collect()
+    }
+
+    /// 按列分别指定聚合方式，一次生成自定义的结果 DataFrame。
+    ///
+    /// # 参数
+    /// 
+    /// * `specs` - 聚合规格列表，每项为 `(输入列名, 聚合函数, 输出列名)`
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键列和各聚合结果列的新 DataFrame，结果列按 `specs` 的顺序排列
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 输入列不存在
+    /// * `AxionError::UnsupportedOperation` - 聚合函数不支持输入列的数据类型
+    /// * `AxionError::InvalidArgument` - 聚合参数无效（如分位数超出 `[0, 1]`）
+    /// * `AxionError::DuplicateColumnName` - 输出列名重复或与分组键列重名
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::dataframe::Agg;
+    /// # use axion_data::{df, AxionError};
+    /// # let df = df!("pclass" => vec![1, 3], "fare" => vec![71.3, 7.25], "age" => vec![38.0, 22.0], "name" => vec!["Cumings", "Braund"])?;
+    /// let report = df.groupby(&["pclass"])?.agg(&[
+    ///     ("fare", Agg::Mean, "avg_fare"),
+    ///     ("age", Agg::Max, "oldest"),
+    ///     ("name", Agg::Count, "passengers"),
+    /// ])?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn agg(&self, specs: &[(&str, Agg, &str)]) -> AxionResult<DataFrame> {
+        let group_rows = self.group_rows();
+        let mut final_columns = self.key_columns(&group_rows)?;
+
+        let aggregate_spec = |(col_name, agg, output_name): &(&str, Agg, &str)| {
+            agg.aggregate(self.df.column(col_name)?, output_name, &group_rows)
+        };
+        let aggregated = if self.parallel {
+            specs.par_iter().map(aggregate_spec).collect::<AxionResult<Vec<_>>>()?
+        } else {
+            specs.iter().map(aggregate_spec).collect::<AxionResult<Vec<_>>>()?
+        };
+        final_columns.extend(aggregated);
+
+        DataFrame::new(final_columns)
     }
 
     /// 计算每个组的行数。
@@ -273,106 +972,20 @@ impl<'a> GroupBy<'a> {
     /// # 示例
     /// 
     /// ```rust
+    /// # let df = axion_data::df!("类别" => vec!["A", "B", "A"])?;
     /// let grouped = df.groupby(&["类别"])?;
     /// let count_df = grouped.count()?;
+    /// # Ok::<(), axion_data::AxionError>(())
     /// ```
     pub fn count(&self) -> AxionResult<DataFrame> {
-        let groups = &self.groups;
-
-        if groups.is_empty() {
-            let mut output_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + 1);
-            for key_name in &self.keys {
-                let original_key_col = self.df.column(key_name)?;
-                let dtype = original_key_col.dtype();
-                let empty_key_series = create_empty_series_from_dtype(key_name.clone(), dtype)?;
-                output_columns.push(empty_key_series);
-            }
-            let empty_count_series = Series::<u32>::new_empty("count".into(), DataType::UInt32);
-            output_columns.push(Box::new(empty_count_series));
-            return DataFrame::new(output_columns);
-        }
-
-        let mut key_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(self.keys.len());
-        let mut key_dtypes: Vec<DataType> = Vec::with_capacity(self.keys.len());
-        for key_name in &self.keys {
-             let original_key_col = self.df.column(key_name)?;
-             let dtype = original_key_col.dtype();
-             key_dtypes.push(dtype.clone());
-             match dtype {
-                 DataType::Int32 => key_data_vecs.push(Box::new(Vec::<Option<i32>>::new())),
-                 DataType::String => key_data_vecs.push(Box::new(Vec::<Option<String>>::new())),
-                 DataType::Bool => key_data_vecs.push(Box::new(Vec::<Option<bool>>::new())),
-                 DataType::UInt32 => key_data_vecs.push(Box::new(Vec::<Option<u32>>::new())),
-                 _ => return Err(AxionError::UnsupportedOperation(format!(
-                     "列 '{}' 的数据类型 {:?} 不支持分组操作", key_name, dtype
-                 ))),
-             }
-        }
-        let mut count_data_vec = Vec::<u32>::with_capacity(groups.len());
-
-        for (key, indices) in groups.iter() {
-            let key_values = key.iter();
-
-            for (i, group_key_value) in key_values.enumerate() {
-                match key_dtypes[i] {
-                    DataType::Int32 => {
-                        if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<i32>>>() {
-                            if let GroupKeyValue::Int(val) = group_key_value {
-                                vec.push(Some(*val));
-                            } else { vec.push(None); }
-                        }
-                    }
-                    DataType::String => {
-                         if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<String>>>() {
-                            if let GroupKeyValue::Str(val) = group_key_value {
-                                vec.push(Some(val.clone()));
-                            } else { vec.push(None); }
-                        }
-                    }
-                    DataType::Bool => {
-                         if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<bool>>>() {
-                            if let GroupKeyValue::Bool(val) = group_key_value {
-                                vec.push(Some(*val));
-                            } else { vec.push(None); }
-                        }
-                    }
-                     DataType::UInt32 => {
-                         if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<u32>>>() {
-                             vec.push(None);
-                         }
-                    }
-                    _ => {}
-                }
-            }
-            count_data_vec.push(indices.len() as u32);
-        }
-
-        let mut final_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + 1);
-        for (i, key_name) in self.keys.iter().enumerate() {
-            let boxed_any = &key_data_vecs[i];
+        let group_rows = self.group_rows();
+        let count_data_vec: Vec<u32> = group_rows.iter().map(|rows| rows.len() as u32).collect();
 
-            let final_key_series: Box<dyn SeriesTrait> = match key_dtypes[i] {
-                 DataType::Int32 => {
-                     let data_vec_ref = boxed_any.downcast_ref::<Vec<Option<i32>>>().unwrap();
-                     Box::new(Series::new_from_options(key_name.clone(), data_vec_ref.clone()))
-                 }
-                 DataType::String => {
-                     let data_vec_ref = boxed_any.downcast_ref::<Vec<Option<String>>>().unwrap();
-                     Box::new(Series::new_from_options(key_name.clone(), data_vec_ref.clone()))
-                 }
-                 DataType::Bool => {
-                     let data_vec_ref = boxed_any.downcast_ref::<Vec<Option<bool>>>().unwrap();
-                     Box::new(Series::new_from_options(key_name.clone(), data_vec_ref.clone()))
-                 }
-                 DataType::UInt32 => {
-                     let data_vec_ref = boxed_any.downcast_ref::<Vec<Option<u32>>>().unwrap();
-                     Box::new(Series::new_from_options(key_name.clone(), data_vec_ref.clone()))
-                 }
-                 _ => unreachable!(),
-            };
-            final_columns.push(final_key_series);
-        }
-        final_columns.push(Box::new(Series::new("count".into(), count_data_vec)));
+        let mut final_columns = self.key_columns(&group_rows)?;
+        final_columns.push(Box::new(Series::new_from_options(
+            "count".into(),
+            count_data_vec.into_iter().map(Some).collect(),
+        )));
 
         DataFrame::new(final_columns)
     }
@@ -380,169 +993,14 @@ impl<'a> GroupBy<'a> {
     /// 计算每个组中数值列的和。
     ///
     /// 非数值列（不是分组键的列）将被忽略。
-    /// 组内的 null 值在求和时被忽略（空组或全 null 组的和为 0）。
+    /// 组内的 null 值在求和时被忽略（全 null 组的和为 null）。
+    /// 整数求和溢出时饱和，浮点数求和忽略 NaN，Decimal 列精确求和。
     ///
     /// # 返回值
     /// 
-    /// 返回包含分组键和对应求和结果的新 DataFrame
+    /// 返回包含分组键和对应求和结果的新 DataFrame，结果列的类型规则与 [`Agg::Sum`] 相同
     pub fn sum(&self) -> AxionResult<DataFrame> {
-        let groups = &self.groups;
-
-        let value_col_names: Vec<String> = self.df.columns_names()
-            .into_iter()
-            .filter(|name| !self.keys.iter().any(|k| k == *name))
-            .filter(|name| {
-                if let Ok(col) = self.df.column(name) {
-                    matches!(col.dtype(), DataType::Int32 | DataType::UInt32 | DataType::Float32 | DataType::Float64)
-                } else {
-                    false
-                }
-            })
-            .map(|name| name.to_string())
-            .collect();
-
-        if groups.is_empty() {
-            let mut output_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + value_col_names.len());
-            for key_name in &self.keys {
-                let original_key_col = self.df.column(key_name)?;
-                let dtype = original_key_col.dtype();
-                let empty_key_series = create_empty_series_from_dtype(key_name.clone(), dtype)?;
-                output_columns.push(empty_key_series);
-            }
-            for value_col_name in &value_col_names {
-                let original_value_col = self.df.column(value_col_name)?;
-                let dtype = original_value_col.dtype();
-                let empty_sum_series = create_empty_series_from_dtype(value_col_name.clone(), dtype)?;
-                output_columns.push(empty_sum_series);
-            }
-            return DataFrame::new(output_columns);
-        }
-
-        let mut key_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(self.keys.len());
-        let mut key_dtypes: Vec<DataType> = Vec::with_capacity(self.keys.len());
-        for key_name in &self.keys {
-             let original_key_col = self.df.column(key_name)?;
-             let dtype = original_key_col.dtype();
-             key_dtypes.push(dtype.clone());
-             match dtype {
-                 DataType::Int32 => key_data_vecs.push(Box::new(Vec::<Option<i32>>::new())),
-                 DataType::String => key_data_vecs.push(Box::new(Vec::<Option<String>>::new())),
-                 DataType::Bool => key_data_vecs.push(Box::new(Vec::<Option<bool>>::new())),
-                 _ => return Err(AxionError::UnsupportedOperation(format!(
-                     "列 '{}' 的数据类型 {:?} 不支持分组操作", key_name, dtype
-                 ))),
-             }
-        }
-
-        let mut sum_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(value_col_names.len());
-        let mut sum_dtypes: Vec<DataType> = Vec::with_capacity(value_col_names.len());
-        for value_col_name in &value_col_names {
-            let original_value_col = self.df.column(value_col_name)?;
-            let dtype = original_value_col.dtype();
-            sum_dtypes.push(dtype.clone());
-            match dtype {
-                DataType::Int32 => sum_data_vecs.push(Box::new(Vec::<Option<i32>>::new())),
-                DataType::UInt32 => sum_data_vecs.push(Box::new(Vec::<Option<u32>>::new())),
-                DataType::Float32 => sum_data_vecs.push(Box::new(Vec::<Option<f32>>::new())),
-                DataType::Float64 => sum_data_vecs.push(Box::new(Vec::<Option<f64>>::new())),
-                _ => unreachable!(),
-            }
-        }
-
-        for (key, indices) in groups.iter() {
-            let key_values = key.iter();
-            for (i, group_key_value) in key_values.enumerate() {
-                 match key_dtypes[i] {
-                    DataType::Int32 => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<i32>>>() { if let GroupKeyValue::Int(val) = group_key_value { vec.push(Some(*val)); } else { vec.push(None); } },
-                    DataType::String => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<String>>>() { if let GroupKeyValue::Str(val) = group_key_value { vec.push(Some(val.clone())); } else { vec.push(None); } },
-                    DataType::Bool => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<bool>>>() { if let GroupKeyValue::Bool(val) = group_key_value { vec.push(Some(*val)); } else { vec.push(None); } },
-                    _ => {}
-                }
-            }
-
-            for (j, value_col_name) in value_col_names.iter().enumerate() {
-                let value_col = self.df.column(value_col_name)?;
-
-                match sum_dtypes[j] {
-                    DataType::Int32 => {
-                        let series = value_col.as_any().downcast_ref::<Series<i32>>().unwrap();
-                        let mut current_sum: Option<i32> = None;
-                        for &idx in indices {
-                            if let Some(val) = series.get(idx) {
-                                current_sum = Some(current_sum.unwrap_or(0).saturating_add(*val));
-                            }
-                        }
-                        if let Some(vec) = sum_data_vecs[j].downcast_mut::<Vec<Option<i32>>>() {
-                            vec.push(current_sum);
-                        }
-                    }
-                    DataType::UInt32 => {
-                        let series = value_col.as_any().downcast_ref::<Series<u32>>().unwrap();
-                        let mut current_sum: Option<u32> = None;
-                        for &idx in indices {
-                            if let Some(val) = series.get(idx) {
-                                current_sum = Some(current_sum.unwrap_or(0).saturating_add(*val));
-                            }
-                        }
-                        if let Some(vec) = sum_data_vecs[j].downcast_mut::<Vec<Option<u32>>>() {
-                            vec.push(current_sum);
-                        }
-                    }
-                    DataType::Float32 => {
-                        let series = value_col.as_any().downcast_ref::<Series<f32>>().unwrap();
-                        let mut current_sum: Option<f32> = None;
-                        for &idx in indices {
-                            if let Some(val) = series.get(idx) {
-                                if val.is_nan() { continue; }
-                                current_sum = Some(current_sum.unwrap_or(0.0) + *val);
-                            }
-                        }
-                        if let Some(vec) = sum_data_vecs[j].downcast_mut::<Vec<Option<f32>>>() {
-                            vec.push(current_sum);
-                        }
-                    }
-                    DataType::Float64 => {
-                        let series = value_col.as_any().downcast_ref::<Series<f64>>().unwrap();
-                        let mut current_sum: Option<f64> = None;
-                        for &idx in indices {
-                            if let Some(val) = series.get(idx) {
-                                if val.is_nan() { continue; }
-                                current_sum = Some(current_sum.unwrap_or(0.0) + *val);
-                            }
-                        }
-                        if let Some(vec) = sum_data_vecs[j].downcast_mut::<Vec<Option<f64>>>() {
-                            vec.push(current_sum);
-                        }
-                    }
-                    _ => unreachable!(),
-                }
-            }
-        }
-
-        let mut final_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + value_col_names.len());
-        for (i, key_name) in self.keys.iter().enumerate() {
-            let boxed_any = &key_data_vecs[i];
-            let final_key_series: Box<dyn SeriesTrait> = match key_dtypes[i] {
-                 DataType::Int32 => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<i32>>>().unwrap().clone())),
-                 DataType::String => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<String>>>().unwrap().clone())),
-                 DataType::Bool => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<bool>>>().unwrap().clone())),
-                 _ => unreachable!(),
-            };
-            final_columns.push(final_key_series);
-        }
-        for (j, value_col_name) in value_col_names.iter().enumerate() {
-            let boxed_any = &sum_data_vecs[j];
-            let final_sum_series: Box<dyn SeriesTrait> = match sum_dtypes[j] {
-                 DataType::Int32 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<i32>>>().unwrap().clone())),
-                 DataType::UInt32 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<u32>>>().unwrap().clone())),
-                 DataType::Float32 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<f32>>>().unwrap().clone())),
-                 DataType::Float64 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<f64>>>().unwrap().clone())),
-                 _ => unreachable!(),
-            };
-            final_columns.push(final_sum_series);
-        }
-
-        DataFrame::new(final_columns)
+        self.aggregate_matching_columns(Agg::Sum, |dtype| dtype.is_numeric())
     }
 
     /// 计算每个组中数值列的平均值。
@@ -552,117 +1010,10 @@ impl<'a> GroupBy<'a> {
     ///
     /// # 返回值
     /// 
-    /// 返回包含分组键和对应平均值的新 DataFrame，平均值列的类型为 f64
+    /// 返回包含分组键和对应平均值的新 DataFrame，平均值列的类型为 f64；
+    /// Decimal 列的平均值保持原类型，四舍五入到原小数位数
     pub fn mean(&self) -> AxionResult<DataFrame> {
-        let groups = &self.groups;
-
-        let value_col_names: Vec<String> = self.df.columns_names()
-            .into_iter()
-            .filter(|name| !self.keys.iter().any(|k| k == *name))
-            .filter(|name| {
-                if let Ok(col) = self.df.column(name) {
-                    col.dtype().is_numeric()
-                } else {
-                    false
-                }
-            })
-            .map(|name| name.to_string())
-            .collect();
-
-        if groups.is_empty() {
-            let mut output_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + value_col_names.len());
-            for key_name in &self.keys {
-                let original_key_col = self.df.column(key_name)?;
-                let dtype = original_key_col.dtype();
-                let empty_key_series = create_empty_series_from_dtype(key_name.clone(), dtype)?;
-                output_columns.push(empty_key_series);
-            }
-            for value_col_name in &value_col_names {
-                 let empty_mean_series = Series::<f64>::new_empty(value_col_name.clone(), DataType::Float64);
-                 output_columns.push(Box::new(empty_mean_series));
-            }
-            return DataFrame::new(output_columns);
-        }
-
-        let mut key_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(self.keys.len());
-        let mut key_dtypes: Vec<DataType> = Vec::with_capacity(self.keys.len());
-        for key_name in &self.keys {
-             let original_key_col = self.df.column(key_name)?;
-             let dtype = original_key_col.dtype();
-             key_dtypes.push(dtype.clone());
-             match dtype {
-                 DataType::Int32 => key_data_vecs.push(Box::new(Vec::<Option<i32>>::new())),
-                 DataType::String => key_data_vecs.push(Box::new(Vec::<Option<String>>::new())),
-                 DataType::Bool => key_data_vecs.push(Box::new(Vec::<Option<bool>>::new())),
-                 _ => return Err(AxionError::UnsupportedOperation(format!(
-                     "列 '{}' 的数据类型 {:?} 不支持分组操作", key_name, dtype
-                 ))),
-             }
-        }
-
-        let mut mean_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(value_col_names.len());
-        for _ in &value_col_names {
-            mean_data_vecs.push(Box::new(Vec::<Option<f64>>::new()));
-        }
-
-        for (key, indices) in groups.iter() {
-            let key_values = key.iter();
-            for (i, group_key_value) in key_values.enumerate() {
-                 match key_dtypes[i] {
-                    DataType::Int32 => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<i32>>>() { if let GroupKeyValue::Int(val) = group_key_value { vec.push(Some(*val)); } else { vec.push(None); } },
-                    DataType::String => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<String>>>() { if let GroupKeyValue::Str(val) = group_key_value { vec.push(Some(val.clone())); } else { vec.push(None); } },
-                    DataType::Bool => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<bool>>>() { if let GroupKeyValue::Bool(val) = group_key_value { vec.push(Some(*val)); } else { vec.push(None); } },
-                    _ => {}
-                }
-            }
-
-            for (j, value_col_name) in value_col_names.iter().enumerate() {
-                let value_col = self.df.column(value_col_name)?;
-                let mut current_sum: f64 = 0.0;
-                let mut current_count: u32 = 0;
-
-                for &idx in indices {
-                    if let Some(value_f64) = value_col.get_as_f64(idx)? {
-                        if !value_f64.is_nan() {
-                            current_sum += value_f64;
-                            current_count += 1;
-                        }
-                    }
-                }
-
-                let mean_value = if current_count > 0 {
-                    Some(current_sum / current_count as f64)
-                } else {
-                    None
-                };
-
-                if let Some(vec) = mean_data_vecs[j].downcast_mut::<Vec<Option<f64>>>() {
-                    vec.push(mean_value);
-                }
-            }
-        }
-
-        let mut final_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + value_col_names.len());
-        for (i, key_name) in self.keys.iter().enumerate() {
-            let boxed_any = &key_data_vecs[i];
-            let final_key_series: Box<dyn SeriesTrait> = match key_dtypes[i] {
-                 DataType::Int32 => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<i32>>>().unwrap().clone())),
-                 DataType::String => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<String>>>().unwrap().clone())),
-                 DataType::Bool => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<bool>>>().unwrap().clone())),
-                 _ => unreachable!(),
-            };
-            final_columns.push(final_key_series);
-        }
-        for (j, value_col_name) in value_col_names.iter().enumerate() {
-            let boxed_any = &mean_data_vecs[j];
-            let final_mean_series = Box::new(Series::new_from_options(
-                value_col_name.clone(),
-                boxed_any.downcast_ref::<Vec<Option<f64>>>().unwrap().clone()
-            ));
-            final_columns.push(final_mean_series);
-        }
-
-        DataFrame::new(final_columns)
+        self.aggregate_matching_columns(Agg::Mean, |dtype| dtype.is_numeric())
     }
 
     /// 计算每个组中可比较列的最小值。
@@ -675,7 +1026,7 @@ impl<'a> GroupBy<'a> {
     /// 
     /// 返回包含分组键和对应最小值的新 DataFrame
     pub fn min(&self) -> AxionResult<DataFrame> {
-        self.aggregate_min_max(true)
+        self.aggregate_matching_columns(Agg::Min, is_comparable_dtype)
     }
 
     /// 计算每个组中可比较列的最大值。
@@ -688,159 +1039,215 @@ impl<'a> GroupBy<'a> {
     /// 
     /// 返回包含分组键和对应最大值的新 DataFrame
     pub fn max(&self) -> AxionResult<DataFrame> {
-        self.aggregate_min_max(false)
+        self.aggregate_matching_columns(Agg::Max, is_comparable_dtype)
     }
 
-    /// 内部辅助函数，处理 min 和 max 的通用逻辑
-    fn aggregate_min_max(&self, find_min: bool) -> AxionResult<DataFrame> {
-        let groups = &self.groups;
+    /// 计算每个组中数值列的标准差。
+    ///
+    /// 非数值列（不是分组键的列）将被忽略，组内的 null 值和 NaN 在计算时被忽略。
+    /// 有效值数量不超过 `ddof` 的组结果为 null。
+    ///
+    /// # 参数
+    /// 
+    /// * `ddof` - 自由度修正，样本标准差为 1，总体标准差为 0
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和对应标准差的新 DataFrame，结果列的类型为 f64
+    pub fn std(&self, ddof: u8) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::Std(ddof), |dtype| dtype.is_numeric())
+    }
 
-        let value_col_names: Vec<String> = self.df.columns_names()
-            .into_iter()
-            .filter(|name| !self.keys.iter().any(|k| k == *name))
-            .filter(|name| {
-                if let Ok(col) = self.df.column(name) {
-                    matches!(col.dtype(),
-                        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 |
-                        DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 |
-                        DataType::Float32 | DataType::Float64 |
-                        DataType::String |
-                        DataType::Bool
-                    )
-                } else {
-                    false
-                }
-            })
-            .map(|name| name.to_string())
-            .collect();
+    /// 计算每个组中数值列的方差。
+    ///
+    /// 非数值列（不是分组键的列）将被忽略，组内的 null 值和 NaN 在计算时被忽略。
+    /// 有效值数量不超过 `ddof` 的组结果为 null。
+    ///
+    /// # 参数
+    /// 
+    /// * `ddof` - 自由度修正，样本方差为 1，总体方差为 0
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和对应方差的新 DataFrame，结果列的类型为 f64
+    pub fn var(&self, ddof: u8) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::Var(ddof), |dtype| dtype.is_numeric())
+    }
 
-        if groups.is_empty() {
-            let mut output_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + value_col_names.len());
-            for key_name in &self.keys {
-                let original_key_col = self.df.column(key_name)?;
-                let dtype = original_key_col.dtype();
-                let empty_key_series = create_empty_series_from_dtype(key_name.clone(), dtype)?;
-                output_columns.push(empty_key_series);
-            }
-            for value_col_name in &value_col_names {
-                let original_value_col = self.df.column(value_col_name)?;
-                let dtype = original_value_col.dtype();
-                let empty_agg_series = create_empty_series_from_dtype(value_col_name.clone(), dtype)?;
-                output_columns.push(empty_agg_series);
-            }
-            return DataFrame::new(output_columns);
-        }
+    /// 计算每个组中数值列的中位数。
+    ///
+    /// 非数值列（不是分组键的列）将被忽略，组内的 null 值和 NaN 在计算时被忽略。
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和对应中位数的新 DataFrame，结果列的类型为 f64
+    pub fn median(&self) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::Median, |dtype| dtype.is_numeric())
+    }
 
-        let mut key_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(self.keys.len());
-        let mut key_dtypes: Vec<DataType> = Vec::with_capacity(self.keys.len());
-        for key_name in &self.keys {
-             let original_key_col = self.df.column(key_name)?;
-             let dtype = original_key_col.dtype();
-             key_dtypes.push(dtype.clone());
-             match dtype {
-                 DataType::Int32 => key_data_vecs.push(Box::new(Vec::<Option<i32>>::new())),
-                 DataType::String => key_data_vecs.push(Box::new(Vec::<Option<String>>::new())),
-                 DataType::Bool => key_data_vecs.push(Box::new(Vec::<Option<bool>>::new())),
-                 _ => return Err(AxionError::UnsupportedOperation(format!(
-                     "列 '{}' 的数据类型 {:?} 不支持分组操作", key_name, dtype
-                 ))),
-             }
+    /// 计算每个组中数值列的分位数，相邻值之间使用线性插值。
+    ///
+    /// 非数值列（不是分组键的列）将被忽略，组内的 null 值和 NaN 在计算时被忽略。
+    ///
+    /// # 参数
+    /// 
+    /// * `q` - 分位数，取值范围为 `[0, 1]`
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和对应分位数的新 DataFrame，结果列的类型为 f64
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::InvalidArgument` - `q` 超出 `[0, 1]` 范围
+    pub fn quantile(&self, q: f64) -> AxionResult<DataFrame> {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(AxionError::InvalidArgument(format!("分位数必须在 [0, 1] 范围内，实际为 {}", q)));
         }
+        self.aggregate_matching_columns(Agg::Quantile(q), |dtype| dtype.is_numeric())
+    }
 
-        let mut agg_data_vecs: Vec<Box<dyn std::any::Any>> = Vec::with_capacity(value_col_names.len());
-        let mut agg_dtypes: Vec<DataType> = Vec::with_capacity(value_col_names.len());
-        for value_col_name in &value_col_names {
-            let original_value_col = self.df.column(value_col_name)?;
-            let dtype = original_value_col.dtype();
-            agg_dtypes.push(dtype.clone());
-            match dtype {
-                DataType::Int8 => agg_data_vecs.push(Box::new(Vec::<Option<i8>>::new())),
-                DataType::Int16 => agg_data_vecs.push(Box::new(Vec::<Option<i16>>::new())),
-                DataType::Int32 => agg_data_vecs.push(Box::new(Vec::<Option<i32>>::new())),
-                DataType::Int64 => agg_data_vecs.push(Box::new(Vec::<Option<i64>>::new())),
-                DataType::UInt8 => agg_data_vecs.push(Box::new(Vec::<Option<u8>>::new())),
-                DataType::UInt16 => agg_data_vecs.push(Box::new(Vec::<Option<u16>>::new())),
-                DataType::UInt32 => agg_data_vecs.push(Box::new(Vec::<Option<u32>>::new())),
-                DataType::UInt64 => agg_data_vecs.push(Box::new(Vec::<Option<u64>>::new())),
-                DataType::Float32 => agg_data_vecs.push(Box::new(Vec::<Option<f32>>::new())),
-                DataType::Float64 => agg_data_vecs.push(Box::new(Vec::<Option<f64>>::new())),
-                DataType::String => agg_data_vecs.push(Box::new(Vec::<Option<String>>::new())),
-                DataType::Bool => agg_data_vecs.push(Box::new(Vec::<Option<bool>>::new())),
-                _ => unreachable!("应该只包含之前过滤的可比较类型"),
-            }
-        }
+    /// 计算每个组中各列不同值的数量。
+    ///
+    /// 不支持分组的列（如 List）和分组键列将被忽略，null 值不计入。
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和对应不同值数量的新 DataFrame，结果列的类型为 u32
+    pub fn n_unique(&self) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::NUnique, |dtype| !matches!(dtype, DataType::List(_)))
+    }
 
-        for (key, indices) in groups.iter() {
-            let key_values = key.iter();
-            for (i, group_key_value) in key_values.enumerate() {
-                 match key_dtypes[i] {
-                    DataType::Int32 => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<i32>>>() { if let GroupKeyValue::Int(val) = group_key_value { vec.push(Some(*val)); } else { vec.push(None); } },
-                    DataType::String => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<String>>>() { if let GroupKeyValue::Str(val) = group_key_value { vec.push(Some(val.clone())); } else { vec.push(None); } },
-                    DataType::Bool => if let Some(vec) = key_data_vecs[i].downcast_mut::<Vec<Option<bool>>>() { if let GroupKeyValue::Bool(val) = group_key_value { vec.push(Some(*val)); } else { vec.push(None); } },
-                    _ => {}
-                }
-            }
+    /// 获取每个组中各列的第一个非 null 值。
+    ///
+    /// 适用于所有非分组键列，结果列的类型与原始列相同。
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和各列首个值的新 DataFrame
+    pub fn first(&self) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::First, |_| true)
+    }
 
-            for (j, value_col_name) in value_col_names.iter().enumerate() {
-                let value_col = self.df.column(value_col_name)?;
-
-                let agg_value = dispatch_min_max!(
-                    value_col,
-                    &agg_dtypes[j],
-                    indices,
-                    find_min
-                )?;
-
-                let boxed_any = &mut agg_data_vecs[j];
-                match agg_dtypes[j] {
-                    DataType::Int8 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<i8>>>() { if let AggValue::Int8(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::Int16 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<i16>>>() { if let AggValue::Int16(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::Int32 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<i32>>>() { if let AggValue::Int32(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::Int64 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<i64>>>() { if let AggValue::Int64(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::UInt8 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<u8>>>() { if let AggValue::UInt8(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::UInt16 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<u16>>>() { if let AggValue::UInt16(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::UInt32 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<u32>>>() { if let AggValue::UInt32(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::UInt64 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<u64>>>() { if let AggValue::UInt64(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::Float32 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<f32>>>() { if let AggValue::Float32(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::Float64 => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<f64>>>() { if let AggValue::Float64(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::String => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<String>>>() { if let AggValue::String(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    DataType::Bool => if let Some(vec) = boxed_any.downcast_mut::<Vec<Option<bool>>>() { if let AggValue::Bool(opt_val) = agg_value { vec.push(opt_val); } else { vec.push(None); } },
-                    _ => unreachable!(),
-                }
-            }
-        }
+    /// 获取每个组中各列的最后一个非 null 值。
+    ///
+    /// 适用于所有非分组键列，结果列的类型与原始列相同。
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和各列末个值的新 DataFrame
+    pub fn last(&self) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::Last, |_| true)
+    }
 
-        let mut final_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(self.keys.len() + value_col_names.len());
-        for (i, key_name) in self.keys.iter().enumerate() {
-            let boxed_any = &key_data_vecs[i];
-            let final_key_series: Box<dyn SeriesTrait> = match key_dtypes[i] {
-                 DataType::Int32 => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<i32>>>().unwrap().clone())),
-                 DataType::String => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<String>>>().unwrap().clone())),
-                 DataType::Bool => Box::new(Series::new_from_options(key_name.clone(), boxed_any.downcast_ref::<Vec<Option<bool>>>().unwrap().clone())),
-                 _ => unreachable!(),
-            };
-            final_columns.push(final_key_series);
-        }
-        for (j, value_col_name) in value_col_names.iter().enumerate() {
-            let boxed_any = &agg_data_vecs[j];
-            let final_agg_series: Box<dyn SeriesTrait> = match agg_dtypes[j] {
-                 DataType::Int8 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<i8>>>().unwrap().clone())),
-                 DataType::Int16 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<i16>>>().unwrap().clone())),
-                 DataType::Int32 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<i32>>>().unwrap().clone())),
-                 DataType::Int64 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<i64>>>().unwrap().clone())),
-                 DataType::UInt8 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<u8>>>().unwrap().clone())),
-                 DataType::UInt16 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<u16>>>().unwrap().clone())),
-                 DataType::UInt32 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<u32>>>().unwrap().clone())),
-                 DataType::UInt64 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<u64>>>().unwrap().clone())),
-                 DataType::Float32 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<f32>>>().unwrap().clone())),
-                 DataType::Float64 => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<f64>>>().unwrap().clone())),
-                 DataType::String => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<String>>>().unwrap().clone())),
-                 DataType::Bool => Box::new(Series::new_from_options(value_col_name.clone(), boxed_any.downcast_ref::<Vec<Option<bool>>>().unwrap().clone())),
-                 _ => unreachable!(),
-            };
-            final_columns.push(final_agg_series);
+    /// 获取每个组中各列的第 n 个非 null 值。
+    ///
+    /// 适用于所有非分组键列，结果列的类型与原始列相同。组内不存在该位置时结果为 null。
+    ///
+    /// # 参数
+    /// 
+    /// * `n` - 从 0 开始的位置，负数表示从末尾倒数（-1 为最后一个）
+    ///
+    /// # 返回值
+    /// 
+    /// 返回包含分组键和各列第 n 个值的新 DataFrame
+    pub fn nth(&self, n: i64) -> AxionResult<DataFrame> {
+        self.aggregate_matching_columns(Agg::Nth(n), |_| true)
+    }
+
+    /// 按组计算聚合值，并将结果广播回原始行位置。
+    ///
+    /// 返回的 Series 与原 DataFrame 等长，每行的值为其所在组的聚合结果，
+    /// 因此可以直接与原列进行逐行运算（例如计算每行相对组均值的偏差）。
+    /// 因分组键为 null 而被丢弃的行（`dropna` 为 `true` 时）结果为 null。
+    ///
+    /// # 参数
+    /// 
+    /// * `column` - 要聚合的列名
+    /// * `agg` - 聚合函数
+    ///
+    /// # 返回值
+    /// 
+    /// 返回与原列同名、长度等于 DataFrame 行数的新 Series
+    ///
+    /// # 错误
+    /// 
+    /// * `AxionError::ColumnNotFound` - 列不存在
+    /// * `AxionError::UnsupportedOperation` - 聚合函数不支持该列的数据类型
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # use axion_data::dataframe::Agg;
+    /// # use axion_data::{df, AxionError, Series, SeriesArithSeries};
+    /// # let df = df!("pclass" => vec![1, 3, 3], "fare" => vec![71.3, 7.25, 8.05])?;
+    /// let group_mean = df.groupby(&["pclass"])?.transform("fare", Agg::Mean)?;
+    /// let fare: &Series<f64> = df.downcast_column("fare")?;
+    /// let group_mean: &Series<f64> = group_mean.as_any().downcast_ref().unwrap();
+    /// let deviation = fare.sub_series(group_mean)?;
+    /// # Ok::<(), AxionError>(())
+    /// ```
+    pub fn transform(&self, column: &str, agg: Agg) -> AxionResult<Box<dyn SeriesTrait>> {
+        let col = self.df.column(column)?;
+        let aggregated = agg.aggregate(col, column, &self.group_rows())?;
+        aggregated.take_indices_option(&self.row_group_ids())
+    }
+
+    /// 对每个组的子 DataFrame 调用闭包，并将所有结果按组顺序纵向拼接。
+    ///
+    /// 子 DataFrame 包含该组的所有行（含分组键列），行顺序与原 DataFrame 一致。
+    /// 闭包返回的 DataFrame 行数可以任意，但所有结果的列名和数据类型必须一致，
+    /// 拼接方式与 [`concat`] 的 [`ConcatType::Vertical`] 相同。
+    ///
+    /// # 参数
+    /// 
+    /// * `func` - 作用于每个组的闭包
+    ///
+    /// # 返回值
+    /// 
+    /// 返回拼接后的 DataFrame；没有任何组时返回空 DataFrame
+    ///
+    /// # 错误
+    /// 
+    /// * 闭包返回的任何错误
+    /// * `AxionError::InvalidArgument` - 各组结果的列名不一致
+    /// * `AxionError::TypeMismatch` - 各组结果中同名列的数据类型不一致
+    ///
+    /// # 示例
+    /// 
+    /// ```rust
+    /// # let df = axion_data::df!("pclass" => vec![1, 3, 3, 3], "fare" => vec![71.3, 7.25, 8.05, 7.9])?;
+    /// // 取每个组票价最高的两名乘客
+    /// let top_two = df.groupby(&["pclass"])?.apply(|group| {
+    ///     Ok(group.sort(&["fare"], &[true])?.head(2))
+    /// })?;
+    /// # assert_eq!(top_two.height(), 3);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn apply<F>(&self, mut func: F) -> AxionResult<DataFrame>
+    where
+        F: FnMut(&DataFrame) -> AxionResult<DataFrame>,
+    {
+        let mut results = Vec::with_capacity(self.groups.len());
+        for rows in self.group_rows() {
+            let group_columns = self.df.columns
+                .iter()
+                .map(|col| col.take_indices(rows))
+                .collect::<AxionResult<Vec<_>>>()?;
+            let group_df = DataFrame::new(group_columns)?;
+            results.push(func(&group_df)?);
         }
+        concat(&results, ConcatType::Vertical)
+    }
 
-        DataFrame::new(final_columns)
+    /// 内部辅助函数，对所有满足条件的非键列应用同一聚合，输出列名与原列名相同
+    fn aggregate_matching_columns(&self, agg: Agg, include: impl Fn(&DataType) -> bool) -> AxionResult<DataFrame> {
+        let specs: Vec<(&str, Agg, &str)> = self.df.columns
+            .iter()
+            .filter(|col| !self.keys.iter().any(|k| k == col.name()))
+            .filter(|col| include(&col.dtype()))
+            .map(|col| (col.name(), agg, col.name()))
+            .collect();
+        self.agg(&specs)
     }
 }
\ No newline at end of file
@@ -0,0 +1,844 @@
+//! 连接操作模块
+//!
+//! 提供 DataFrame 连接所需的键哈希和索引计算逻辑。连接键可以是任意可哈希的
+//! 非 List 类型列，并支持由多列组成的复合键。
+
+use crate::dataframe::DataFrame;
+use crate::decimal::Decimal;
+use crate::dtype::DataType;
+use crate::error::{AxionError, AxionResult};
+use crate::series::{CategoricalSeries, Series, SeriesTrait};
+use crate::temporal::{Date, Datetime, Duration};
+use num_traits::ToPrimitive;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// 连接键中的单个值
+///
+/// 借用原始列中的数据，避免在构建哈希表时复制字符串。
+/// 所有有符号整数统一提升为 `i128`，无符号整数提升为 `u128`，
+/// 浮点数按规范化后的位模式参与哈希和比较。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum JoinKeyValue<'a> {
+    Int(i128),
+    UInt(u128),
+    Float(u64),
+    Str(&'a str),
+    Bool(bool),
+}
+
+/// 单行的复合连接键，`None` 表示该键列在此行为 null
+pub(crate) type RowKey<'a> = Vec<Option<JoinKeyValue<'a>>>;
+
+/// 单个键列逐行的连接键值，`None` 表示 null
+type KeyColumn<'a> = Vec<Option<JoinKeyValue<'a>>>;
+
+/// 连接类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// 内连接，只保留两侧都匹配的行
+    Inner,
+    /// 左连接，保留左侧所有行
+    Left,
+    /// 右连接，保留右侧所有行
+    Right,
+    /// 外连接，保留两侧所有行
+    Outer,
+    /// 半连接，只保留在右侧有匹配的左侧行，不输出右侧列
+    Semi,
+    /// 反连接，只保留在右侧没有匹配的左侧行，不输出右侧列
+    Anti,
+}
+
+/// 连接键基数校验模式
+///
+/// 在连接前检查连接键的唯一性，不满足时返回 `AxionError::JoinValidationError`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinValidation {
+    /// 不做校验（默认）
+    #[default]
+    ManyToMany,
+    /// 左右两侧的连接键都必须唯一
+    OneToOne,
+    /// 左侧的连接键必须唯一
+    OneToMany,
+    /// 右侧的连接键必须唯一
+    ManyToOne,
+}
+
+impl JoinValidation {
+    /// 获取校验模式的名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JoinValidation::ManyToMany => "many_to_many",
+            JoinValidation::OneToOne => "one_to_one",
+            JoinValidation::OneToMany => "one_to_many",
+            JoinValidation::ManyToOne => "many_to_one",
+        }
+    }
+}
+
+/// 连接操作的配置选项
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::dataframe::{JoinOptions, JoinType, JoinValidation};
+/// # use axion_data::{df, AxionError};
+/// # let sales = df!("store_id" => vec![1, 1, 2], "amount" => vec![10.0, 20.0, 5.0])?;
+/// # let stores = df!("id" => vec![1, 2], "amount" => vec![100.0, 200.0])?;
+/// let options = JoinOptions::builder()
+///     .with_right_suffix("_store")
+///     .nulls_equal(true)
+///     .validate(JoinValidation::ManyToOne)
+///     .build();
+/// let joined = sales.join(&stores, &["store_id"], &["id"], JoinType::Left, Some(options))?;
+/// # assert!(joined.column("amount_store").is_ok());
+/// # Ok::<(), AxionError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinOptions {
+    /// 右连接时左表重名列的后缀，默认为 `_left`
+    pub left_suffix: String,
+    /// 其余连接类型下右表重名列的后缀，默认为 `_right`
+    pub right_suffix: String,
+    /// null 连接键之间是否互相匹配，默认为 `false`（SQL 语义，null 永不匹配）
+    pub nulls_equal: bool,
+    /// 连接键基数校验模式，默认为 `JoinValidation::ManyToMany`
+    pub validate: JoinValidation,
+}
+
+impl Default for JoinOptions {
+    fn default() -> Self {
+        JoinOptions {
+            left_suffix: "_left".to_string(),
+            right_suffix: "_right".to_string(),
+            nulls_equal: false,
+            validate: JoinValidation::ManyToMany,
+        }
+    }
+}
+
+impl JoinOptions {
+    /// 创建一个新的 JoinOptions 构建器，使用默认值
+    pub fn builder() -> JoinOptionsBuilder {
+        JoinOptionsBuilder::new()
+    }
+}
+
+/// JoinOptions 的构建器
+#[derive(Debug, Clone, Default)]
+pub struct JoinOptionsBuilder {
+    left_suffix: Option<String>,
+    right_suffix: Option<String>,
+    nulls_equal: Option<bool>,
+    validate: Option<JoinValidation>,
+}
+
+impl JoinOptionsBuilder {
+    /// 创建一个新的构建器实例
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// 设置左表重名列的后缀
+    pub fn with_left_suffix(mut self, suffix: &str) -> Self {
+        self.left_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// 设置右表重名列的后缀
+    pub fn with_right_suffix(mut self, suffix: &str) -> Self {
+        self.right_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// 设置 null 连接键之间是否互相匹配
+    pub fn nulls_equal(mut self, nulls_equal: bool) -> Self {
+        self.nulls_equal = Some(nulls_equal);
+        self
+    }
+
+    /// 设置连接键基数校验模式
+    pub fn validate(mut self, validate: JoinValidation) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// 构建 JoinOptions 实例
+    pub fn build(self) -> JoinOptions {
+        let defaults = JoinOptions::default();
+        JoinOptions {
+            left_suffix: self.left_suffix.unwrap_or(defaults.left_suffix),
+            right_suffix: self.right_suffix.unwrap_or(defaults.right_suffix),
+            nulls_equal: self.nulls_equal.unwrap_or(defaults.nulls_equal),
+            validate: self.validate.unwrap_or(defaults.validate),
+        }
+    }
+}
+
+/// as-of 连接的配置选项
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::dataframe::AsofJoinOptions;
+/// # use axion_data::{df, AxionError};
+/// # let readings = df!("sensor_id" => vec![1, 2], "ts" => vec![7200_i64, 7200])?;
+/// # let calibrations = df!("sensor_id" => vec![1, 2], "ts" => vec![3600_i64, 0])?;
+/// let options = AsofJoinOptions::builder()
+///     .with_by(&["sensor_id"])
+///     .with_tolerance(3600.0)
+///     .build();
+/// let joined = readings.join_asof(&calibrations, "ts", "ts", Some(options))?;
+/// # Ok::<(), AxionError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsofJoinOptions {
+    /// 分组列，左右两侧必须同名；只在分组键相同的行之间匹配，默认为空
+    pub by: Vec<String>,
+    /// 允许的最大键差（左键 - 右键），默认为 `None`，即不限制
+    /// 时间类型的键按底层整数计算差值，即 Date 为天数，Datetime 和 Duration 为列的时间单位数
+    pub tolerance: Option<f64>,
+    /// 右表重名列的后缀，默认为 `_right`
+    pub right_suffix: String,
+}
+
+impl Default for AsofJoinOptions {
+    fn default() -> Self {
+        AsofJoinOptions {
+            by: Vec::new(),
+            tolerance: None,
+            right_suffix: "_right".to_string(),
+        }
+    }
+}
+
+impl AsofJoinOptions {
+    /// 创建一个新的 AsofJoinOptions 构建器，使用默认值
+    pub fn builder() -> AsofJoinOptionsBuilder {
+        AsofJoinOptionsBuilder::new()
+    }
+}
+
+/// AsofJoinOptions 的构建器
+#[derive(Debug, Clone, Default)]
+pub struct AsofJoinOptionsBuilder {
+    by: Option<Vec<String>>,
+    tolerance: Option<f64>,
+    right_suffix: Option<String>,
+}
+
+impl AsofJoinOptionsBuilder {
+    /// 创建一个新的构建器实例
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// 设置分组列
+    pub fn with_by(mut self, by: &[&str]) -> Self {
+        self.by = Some(by.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// 设置允许的最大键差
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// 设置右表重名列的后缀
+    pub fn with_right_suffix(mut self, suffix: &str) -> Self {
+        self.right_suffix = Some(suffix.to_string());
+        self
+    }
+
+    /// 构建 AsofJoinOptions 实例
+    pub fn build(self) -> AsofJoinOptions {
+        let defaults = AsofJoinOptions::default();
+        AsofJoinOptions {
+            by: self.by.unwrap_or(defaults.by),
+            tolerance: self.tolerance.or(defaults.tolerance),
+            right_suffix: self.right_suffix.unwrap_or(defaults.right_suffix),
+        }
+    }
+}
+
+/// 将浮点数规范化为用于哈希的位模式
+///
+/// `0.0` 与 `-0.0` 视为相同，所有 NaN 视为相同。
+fn float_key_bits(value: f64) -> u64 {
+    if value == 0.0 {
+        0.0f64.to_bits()
+    } else if value.is_nan() {
+        f64::NAN.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
+/// 将单列转换为连接键值向量
+fn column_key_values(col: &dyn SeriesTrait) -> AxionResult<Vec<Option<JoinKeyValue<'_>>>> {
+    macro_rules! collect_keys {
+        ($t:ty, $v:ident => $conv:expr) => {{
+            let series = downcast_key::<$t>(col)?;
+            Ok(series.iter().map(|opt| opt.map(|$v| $conv)).collect())
+        }};
+    }
+
+    match col.dtype() {
+        DataType::Int8 => collect_keys!(i8, v => JoinKeyValue::Int(*v as i128)),
+        DataType::Int16 => collect_keys!(i16, v => JoinKeyValue::Int(*v as i128)),
+        DataType::Int32 => collect_keys!(i32, v => JoinKeyValue::Int(*v as i128)),
+        DataType::Int64 => collect_keys!(i64, v => JoinKeyValue::Int(*v as i128)),
+        DataType::Int128 => collect_keys!(i128, v => JoinKeyValue::Int(*v)),
+        DataType::UInt8 => collect_keys!(u8, v => JoinKeyValue::UInt(*v as u128)),
+        DataType::UInt16 => collect_keys!(u16, v => JoinKeyValue::UInt(*v as u128)),
+        DataType::UInt32 => collect_keys!(u32, v => JoinKeyValue::UInt(*v as u128)),
+        DataType::UInt64 => collect_keys!(u64, v => JoinKeyValue::UInt(*v as u128)),
+        DataType::UInt128 => collect_keys!(u128, v => JoinKeyValue::UInt(*v)),
+        DataType::Float32 => collect_keys!(f32, v => JoinKeyValue::Float(float_key_bits(*v as f64))),
+        DataType::Float64 => collect_keys!(f64, v => JoinKeyValue::Float(float_key_bits(*v))),
+        DataType::String => collect_keys!(String, v => JoinKeyValue::Str(v.as_str())),
+        DataType::Bool => collect_keys!(bool, v => JoinKeyValue::Bool(*v)),
+        // 两侧数据类型已确认一致，时间类型直接按底层整数比较
+        DataType::Date => collect_keys!(Date, v => JoinKeyValue::Int(v.0 as i128)),
+        DataType::Datetime(_, _) => collect_keys!(Datetime, v => JoinKeyValue::Int(v.0 as i128)),
+        DataType::Duration(_) => collect_keys!(Duration, v => JoinKeyValue::Int(v.0 as i128)),
+        // 两侧小数位数已确认一致，直接比较缩放后的整数
+        DataType::Decimal(_, _) => collect_keys!(Decimal, v => JoinKeyValue::Int(v.0)),
+        DataType::Categorical => {
+            let series = downcast_categorical_key(col)?;
+            Ok(series.iter().map(|opt| opt.map(JoinKeyValue::Str)).collect())
+        }
+        DataType::Null => Ok(vec![None; col.len()]),
+        other => Err(AxionError::UnsupportedOperation(format!(
+            "列 '{}' 的数据类型 {:?} 不能作为连接键",
+            col.name(),
+            other
+        ))),
+    }
+}
+
+/// 判断两侧键列能否直接按键值比较
+///
+/// 数据类型必须相同；Decimal 列只要求小数位数相同，总位数可以不同。
+fn key_dtypes_compatible(left: &DataType, right: &DataType) -> bool {
+    match (left, right) {
+        (DataType::Decimal(_, left_scale), DataType::Decimal(_, right_scale)) => left_scale == right_scale,
+        _ => left == right,
+    }
+}
+
+/// 两侧分类列共享同一个字典时，直接以编码作为连接键
+///
+/// 字典不同时返回 `None`，此时按类别字符串连接。
+fn categorical_code_keys<'a, 'b>(
+    left_col: &'a dyn SeriesTrait,
+    right_col: &'b dyn SeriesTrait,
+) -> AxionResult<Option<(KeyColumn<'a>, KeyColumn<'b>)>> {
+    let left = downcast_categorical_key(left_col)?;
+    let right = downcast_categorical_key(right_col)?;
+    if !left.same_dictionary(right) {
+        return Ok(None);
+    }
+    let code_keys = |series: &CategoricalSeries| {
+        series.codes().iter().map(|opt| opt.map(|code| JoinKeyValue::UInt(*code as u128))).collect()
+    };
+    Ok(Some((code_keys(left), code_keys(right))))
+}
+
+/// 校验两侧连接键并逐行构建复合键
+///
+/// # 错误
+///
+/// * `AxionError::InvalidArgument` - 连接键为空或两侧数量不一致
+/// * `AxionError::ColumnNotFound` - 连接键列不存在
+/// * `AxionError::JoinKeyTypeError` - 两侧对应键列的数据类型不一致（Decimal 列的小数位数不同）
+pub(crate) fn build_join_keys<'a, 'b>(
+    left: &'a DataFrame,
+    right: &'b DataFrame,
+    left_on: &[&str],
+    right_on: &[&str],
+) -> AxionResult<(Vec<RowKey<'a>>, Vec<RowKey<'b>>)> {
+    if left_on.is_empty() {
+        return Err(AxionError::InvalidArgument("连接键不能为空".to_string()));
+    }
+    if left_on.len() != right_on.len() {
+        return Err(AxionError::InvalidArgument(format!(
+            "左右两侧连接键数量必须一致: 左侧 {} 个，右侧 {} 个",
+            left_on.len(),
+            right_on.len()
+        )));
+    }
+
+    let mut left_key_cols = Vec::with_capacity(left_on.len());
+    let mut right_key_cols = Vec::with_capacity(right_on.len());
+    for (&left_name, &right_name) in left_on.iter().zip(right_on.iter()) {
+        let left_col = left.column(left_name).map_err(|_| {
+            AxionError::ColumnNotFound(format!("左侧连接键列 '{}'", left_name))
+        })?;
+        let right_col = right.column(right_name).map_err(|_| {
+            AxionError::ColumnNotFound(format!("右侧连接键列 '{}'", right_name))
+        })?;
+        if !key_dtypes_compatible(&left_col.dtype(), &right_col.dtype()) {
+            return Err(AxionError::JoinKeyTypeError {
+                side: "右侧".to_string(),
+                name: right_name.to_string(),
+                expected: left_col.dtype(),
+                found: right_col.dtype(),
+            });
+        }
+        if left_col.dtype() == DataType::Categorical {
+            if let Some((left_keys, right_keys)) = categorical_code_keys(left_col, right_col)? {
+                left_key_cols.push(left_keys);
+                right_key_cols.push(right_keys);
+                continue;
+            }
+        }
+        left_key_cols.push(column_key_values(left_col)?);
+        right_key_cols.push(column_key_values(right_col)?);
+    }
+
+    Ok((
+        transpose_keys(left_key_cols, left.height()),
+        transpose_keys(right_key_cols, right.height()),
+    ))
+}
+
+/// 将按列存储的键值转换为按行存储的复合键
+fn transpose_keys(key_cols: Vec<Vec<Option<JoinKeyValue<'_>>>>, height: usize) -> Vec<RowKey<'_>> {
+    (0..height)
+        .map(|row| key_cols.iter().map(|col| col[row]).collect())
+        .collect()
+}
+
+/// 判断复合键能否参与匹配
+///
+/// 当 `nulls_equal` 为 `false` 时，包含 null 的键不与任何键匹配。
+#[inline]
+fn is_matchable(key: &RowKey<'_>, nulls_equal: bool) -> bool {
+    nulls_equal || key.iter().all(Option::is_some)
+}
+
+/// 为一侧的复合键构建从键到行索引的哈希表
+///
+/// 不能参与匹配的键（见 `nulls_equal`）不会进入哈希表，因此查找时自然不会命中。
+pub(crate) fn build_key_index<'k, 'a>(
+    keys: &'k [RowKey<'a>],
+    nulls_equal: bool,
+) -> HashMap<&'k RowKey<'a>, Vec<usize>> {
+    let mut map: HashMap<&RowKey<'a>, Vec<usize>> = HashMap::with_capacity(keys.len());
+    for (idx, key) in keys.iter().enumerate() {
+        if is_matchable(key, nulls_equal) {
+            map.entry(key).or_default().push(idx);
+        }
+    }
+    map
+}
+
+/// 检查一侧可参与匹配的连接键中是否存在重复值
+fn has_duplicate_keys(keys: &[RowKey<'_>], nulls_equal: bool) -> bool {
+    let mut seen: HashSet<&RowKey<'_>> = HashSet::with_capacity(keys.len());
+    keys.iter()
+        .filter(|key| is_matchable(key, nulls_equal))
+        .any(|key| !seen.insert(key))
+}
+
+/// 按校验模式检查两侧连接键的唯一性
+fn validate_join_keys(
+    left_keys: &[RowKey<'_>],
+    right_keys: &[RowKey<'_>],
+    options: &JoinOptions,
+) -> AxionResult<()> {
+    let (check_left, check_right) = match options.validate {
+        JoinValidation::ManyToMany => (false, false),
+        JoinValidation::OneToOne => (true, true),
+        JoinValidation::OneToMany => (true, false),
+        JoinValidation::ManyToOne => (false, true),
+    };
+    let error = |side: &str| AxionError::JoinValidationError {
+        validate: options.validate.as_str().to_string(),
+        side: side.to_string(),
+    };
+    if check_left && has_duplicate_keys(left_keys, options.nulls_equal) {
+        return Err(error("左侧"));
+    }
+    if check_right && has_duplicate_keys(right_keys, options.nulls_equal) {
+        return Err(error("右侧"));
+    }
+    Ok(())
+}
+
+/// 根据连接类型计算左右两侧的行索引
+///
+/// 返回的两个向量长度相同，`None` 表示该侧在此行没有匹配，输出时填充 null。
+fn compute_join_indices(
+    left_keys: &[RowKey<'_>],
+    right_keys: &[RowKey<'_>],
+    how: JoinType,
+    nulls_equal: bool,
+) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+    let mut left_indices = Vec::new();
+    let mut right_indices = Vec::new();
+
+    if how == JoinType::Right {
+        let left_map = build_key_index(left_keys, nulls_equal);
+        for (right_idx, key) in right_keys.iter().enumerate() {
+            match left_map.get(key) {
+                Some(matches) => {
+                    for &left_idx in matches {
+                        left_indices.push(Some(left_idx));
+                        right_indices.push(Some(right_idx));
+                    }
+                }
+                None => {
+                    left_indices.push(None);
+                    right_indices.push(Some(right_idx));
+                }
+            }
+        }
+        return (left_indices, right_indices);
+    }
+
+    let right_map = build_key_index(right_keys, nulls_equal);
+    let mut used_right_indices: HashSet<usize> = HashSet::new();
+    for (left_idx, key) in left_keys.iter().enumerate() {
+        match right_map.get(key) {
+            Some(matches) => {
+                for &right_idx in matches {
+                    left_indices.push(Some(left_idx));
+                    right_indices.push(Some(right_idx));
+                    if how == JoinType::Outer {
+                        used_right_indices.insert(right_idx);
+                    }
+                }
+            }
+            None => {
+                if how != JoinType::Inner {
+                    left_indices.push(Some(left_idx));
+                    right_indices.push(None);
+                }
+            }
+        }
+    }
+
+    if how == JoinType::Outer {
+        for right_idx in 0..right_keys.len() {
+            if !used_right_indices.contains(&right_idx) {
+                left_indices.push(None);
+                right_indices.push(Some(right_idx));
+            }
+        }
+    }
+
+    (left_indices, right_indices)
+}
+
+/// 计算半连接/反连接需要保留的左侧行索引
+///
+/// 每个左侧行最多保留一次，保持左侧原有顺序。
+fn compute_filter_indices(
+    left_keys: &[RowKey<'_>],
+    right_keys: &[RowKey<'_>],
+    keep_matched: bool,
+    nulls_equal: bool,
+) -> Vec<usize> {
+    let right_map = build_key_index(right_keys, nulls_equal);
+    left_keys
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| right_map.contains_key(key) == keep_matched)
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// 执行哈希连接
+///
+/// 半连接和反连接只输出左表的列；右连接时以右表列在前，左表的非键列追加在后，
+/// 并使用 `left_suffix` 后缀解决重名；其余连接类型以左表列在前，右表的非键列追加在后并使用 `right_suffix` 后缀。
+pub(crate) fn hash_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_on: &[&str],
+    right_on: &[&str],
+    how: JoinType,
+    options: &JoinOptions,
+) -> AxionResult<DataFrame> {
+    let (left_keys, right_keys) = build_join_keys(left, right, left_on, right_on)?;
+    validate_join_keys(&left_keys, &right_keys, options)?;
+
+    if matches!(how, JoinType::Semi | JoinType::Anti) {
+        let indices = compute_filter_indices(
+            &left_keys,
+            &right_keys,
+            how == JoinType::Semi,
+            options.nulls_equal,
+        );
+        let columns = left
+            .columns
+            .iter()
+            .map(|col| col.take_indices(&indices))
+            .collect::<AxionResult<Vec<_>>>()?;
+        return DataFrame::new(columns);
+    }
+
+    let (left_indices, right_indices) =
+        compute_join_indices(&left_keys, &right_keys, how, options.nulls_equal);
+
+    let (primary, primary_indices, secondary, secondary_indices, secondary_on, suffix) =
+        if how == JoinType::Right {
+            (right, &right_indices, left, &left_indices, left_on, options.left_suffix.as_str())
+        } else {
+            (left, &left_indices, right, &right_indices, right_on, options.right_suffix.as_str())
+        };
+
+    let mut result_columns: Vec<Box<dyn SeriesTrait>> =
+        Vec::with_capacity(primary.width() + secondary.width());
+    let mut primary_column_names: HashSet<String> = HashSet::with_capacity(primary.width());
+
+    for col in &primary.columns {
+        let taken_col = col.take_indices_option(primary_indices)?;
+        primary_column_names.insert(taken_col.name().to_string());
+        result_columns.push(taken_col);
+    }
+
+    for col in &secondary.columns {
+        if secondary_on.contains(&col.name()) {
+            continue;
+        }
+        let mut taken_col = col.take_indices_option(secondary_indices)?;
+        if primary_column_names.contains(col.name()) {
+            let new_name = format!("{}{}", col.name(), suffix);
+            taken_col.rename(&new_name);
+        }
+        result_columns.push(taken_col);
+    }
+
+    DataFrame::new(result_columns)
+}
+
+/// 执行交叉连接（笛卡尔积）
+///
+/// 输出行按左表顺序排列，每个左侧行依次与所有右侧行组合。
+/// 右表中与左表重名的列使用 `options.right_suffix` 后缀。
+pub(crate) fn cross_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: &JoinOptions,
+) -> AxionResult<DataFrame> {
+    let left_height = left.height();
+    let right_height = right.height();
+    let total = left_height.checked_mul(right_height).ok_or_else(|| {
+        AxionError::InvalidArgument(format!(
+            "交叉连接结果行数溢出: {} x {}",
+            left_height, right_height
+        ))
+    })?;
+
+    let mut left_indices = Vec::with_capacity(total);
+    let mut right_indices = Vec::with_capacity(total);
+    for left_idx in 0..left_height {
+        for right_idx in 0..right_height {
+            left_indices.push(left_idx);
+            right_indices.push(right_idx);
+        }
+    }
+
+    let mut result_columns: Vec<Box<dyn SeriesTrait>> =
+        Vec::with_capacity(left.width() + right.width());
+    let mut left_column_names: HashSet<String> = HashSet::with_capacity(left.width());
+
+    for col in &left.columns {
+        let taken_col = col.take_indices(&left_indices)?;
+        left_column_names.insert(taken_col.name().to_string());
+        result_columns.push(taken_col);
+    }
+
+    for col in &right.columns {
+        let mut taken_col = col.take_indices(&right_indices)?;
+        if left_column_names.contains(col.name()) {
+            let new_name = format!("{}{}", col.name(), options.right_suffix);
+            taken_col.rename(&new_name);
+        }
+        result_columns.push(taken_col);
+    }
+
+    DataFrame::new(result_columns)
+}
+
+/// 将键列向下转型为具体类型的 Series
+fn downcast_key<T: 'static>(col: &dyn SeriesTrait) -> AxionResult<&Series<T>> {
+    col.as_any().downcast_ref::<Series<T>>().ok_or_else(|| {
+        AxionError::InternalError(format!(
+            "无法将连接键列 '{}' 向下转型为 {}",
+            col.name(),
+            std::any::type_name::<T>()
+        ))
+    })
+}
+
+/// 将分类连接键列向下转型为 CategoricalSeries
+fn downcast_categorical_key(col: &dyn SeriesTrait) -> AxionResult<&CategoricalSeries> {
+    col.as_any().downcast_ref::<CategoricalSeries>().ok_or_else(|| {
+        AxionError::InternalError(format!("无法将连接键列 '{}' 向下转型为 CategoricalSeries", col.name()))
+    })
+}
+
+/// 按键升序排列的有效行索引，跳过 null 和 NaN 键
+///
+/// 如果 Series 已标记为升序排序则直接沿用原有顺序，否则进行稳定排序。
+fn sorted_valid_indices<T: PartialOrd>(series: &Series<T>) -> Vec<usize> {
+    let mut indices: Vec<usize> = series
+        .iter()
+        .enumerate()
+        .filter(|(_, opt)| opt.is_some_and(|v| v.partial_cmp(v).is_some()))
+        .map(|(idx, _)| idx)
+        .collect();
+    if !series.get_flags().is_sorted_ascending() {
+        let values = series.values();
+        indices.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal));
+    }
+    indices
+}
+
+/// 为每个左侧行查找分组内键不大于左键的最后一个右侧行
+fn asof_match<T>(
+    left: &Series<T>,
+    right: &Series<T>,
+    left_groups: &[RowKey<'_>],
+    right_groups: &[RowKey<'_>],
+    tolerance: Option<f64>,
+) -> Vec<Option<usize>>
+where
+    T: PartialOrd + ToPrimitive,
+{
+    let left_values = left.values();
+    let right_values = right.values();
+
+    // 右表按分组拆分，组内保持键的升序
+    let mut right_by_group: HashMap<&RowKey<'_>, Vec<usize>> = HashMap::new();
+    for right_idx in sorted_valid_indices(right) {
+        let group = &right_groups[right_idx];
+        if is_matchable(group, false) {
+            right_by_group.entry(group).or_default().push(right_idx);
+        }
+    }
+
+    // 左表按键升序遍历，每个分组维护一个单调前进的游标
+    let mut result = vec![None; left.len()];
+    let mut cursors: HashMap<&RowKey<'_>, usize> = HashMap::new();
+    for left_idx in sorted_valid_indices(left) {
+        let group = &left_groups[left_idx];
+        let Some(candidates) = right_by_group.get(group) else {
+            continue;
+        };
+        let cursor = cursors.entry(group).or_insert(0);
+        let left_value = &left_values[left_idx];
+        while *cursor < candidates.len() && right_values[candidates[*cursor]] <= *left_value {
+            *cursor += 1;
+        }
+        if *cursor == 0 {
+            continue;
+        }
+        let right_idx = candidates[*cursor - 1];
+        if let Some(tol) = tolerance {
+            let diff = match (left_value.to_f64(), right_values[right_idx].to_f64()) {
+                (Some(l), Some(r)) => l - r,
+                _ => continue,
+            };
+            if diff > tol {
+                continue;
+            }
+        }
+        result[left_idx] = Some(right_idx);
+    }
+    result
+}
+
+/// 执行 as-of 连接
+///
+/// 对每个左侧行，匹配同一分组内键不大于左键的最后一个右侧行（向后查找）。
+/// 输出包含左表所有列以及右表除分组列外的列；右键列与左键列同名时不输出。
+pub(crate) fn asof_join(
+    left: &DataFrame,
+    right: &DataFrame,
+    left_on: &str,
+    right_on: &str,
+    options: &AsofJoinOptions,
+) -> AxionResult<DataFrame> {
+    let left_key = left.column(left_on).map_err(|_| {
+        AxionError::ColumnNotFound(format!("左侧连接键列 '{}'", left_on))
+    })?;
+    let right_key = right.column(right_on).map_err(|_| {
+        AxionError::ColumnNotFound(format!("右侧连接键列 '{}'", right_on))
+    })?;
+    if left_key.dtype() != right_key.dtype() {
+        return Err(AxionError::JoinKeyTypeError {
+            side: "右侧".to_string(),
+            name: right_on.to_string(),
+            expected: left_key.dtype(),
+            found: right_key.dtype(),
+        });
+    }
+
+    let (left_groups, right_groups) = if options.by.is_empty() {
+        (vec![Vec::new(); left.height()], vec![Vec::new(); right.height()])
+    } else {
+        let by: Vec<&str> = options.by.iter().map(|s| s.as_str()).collect();
+        build_join_keys(left, right, &by, &by)?
+    };
+
+    macro_rules! dispatch_asof {
+        ($t:ty) => {{
+            asof_match(
+                downcast_key::<$t>(left_key)?,
+                downcast_key::<$t>(right_key)?,
+                &left_groups,
+                &right_groups,
+                options.tolerance,
+            )
+        }};
+    }
+
+    let right_indices = match left_key.dtype() {
+        DataType::Int8 => dispatch_asof!(i8),
+        DataType::Int16 => dispatch_asof!(i16),
+        DataType::Int32 => dispatch_asof!(i32),
+        DataType::Int64 => dispatch_asof!(i64),
+        DataType::Int128 => dispatch_asof!(i128),
+        DataType::UInt8 => dispatch_asof!(u8),
+        DataType::UInt16 => dispatch_asof!(u16),
+        DataType::UInt32 => dispatch_asof!(u32),
+        DataType::UInt64 => dispatch_asof!(u64),
+        DataType::UInt128 => dispatch_asof!(u128),
+        DataType::Float32 => dispatch_asof!(f32),
+        DataType::Float64 => dispatch_asof!(f64),
+        DataType::Date => dispatch_asof!(Date),
+        DataType::Datetime(_, _) => dispatch_asof!(Datetime),
+        DataType::Duration(_) => dispatch_asof!(Duration),
+        other => {
+            return Err(AxionError::UnsupportedOperation(format!(
+                "as-of 连接键列 '{}' 的数据类型 {:?} 不支持，需要数值或时间类型",
+                left_on, other
+            )))
+        }
+    };
+
+    let mut result_columns: Vec<Box<dyn SeriesTrait>> = left.columns.clone();
+    let left_column_names: HashSet<&str> = left.columns.iter().map(|col| col.name()).collect();
+    for col in &right.columns {
+        let name = col.name();
+        if options.by.iter().any(|b| b == name) || (name == right_on && right_on == left_on) {
+            continue;
+        }
+        let mut taken_col = col.take_indices_option(&right_indices)?;
+        if left_column_names.contains(name) {
+            let new_name = format!("{}{}", name, options.right_suffix);
+            taken_col.rename(&new_name);
+        }
+        result_columns.push(taken_col);
+    }
+
+    DataFrame::new(result_columns)
+}
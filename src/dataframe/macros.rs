@@ -11,7 +11,7 @@
 /// # 示例
 /// 
 /// ```rust
-/// use axion::df;
+/// use axion_data::df;
 /// 
 /// // 类型推断
 /// let df1 = df! {
@@ -27,6 +27,7 @@
 /// 
 /// // 空 DataFrame
 /// let empty = df!()?;
+/// # Ok::<(), axion_data::AxionError>(())
 /// ```
 /// 
 /// # 返回值
@@ -1,10 +1,17 @@
+pub mod concat;
 pub mod core;
+pub mod dynamic;
 pub mod groupby;
+pub mod join;
+pub mod reshape;
 pub mod types;
 pub mod macros;
 
+pub use concat::{concat, ConcatType};
 pub use core::DataFrame;
+pub use dynamic::ClosedWindow;
 pub use groupby::*;
+pub use join::{AsofJoinOptions, AsofJoinOptionsBuilder, JoinOptions, JoinOptionsBuilder, JoinType, JoinValidation};
 pub use types::*;
 #[allow(unused_imports)]
 pub use macros::*;
\ No newline at end of file
@@ -0,0 +1,188 @@
+//! 重塑操作模块
+//!
+//! 提供宽表与长表之间的转换：`pivot` 将长表按某列的不同取值展开为多列，
+//! `melt` 则将多列折叠为 "变量名-值" 两列；`unnest` 将结构体列展开为各字段列；
+//! `explode` 将列表列的每个元素展开为一行，`implode` 则按分组将各列收集回列表。
+
+use crate::dataframe::{Agg, DataFrame, GroupBy, GroupByOptions};
+use crate::dtype::DataType;
+use crate::error::{AxionError, AxionResult};
+use crate::series::{ListSeries, Series, SeriesTrait, StructSeries};
+
+/// 透视表中保留 null 键的分组配置，各组按首次出现的顺序输出
+fn pivot_group_options() -> GroupByOptions {
+    GroupByOptions::builder().dropna(false).build()
+}
+
+/// 透视表实现
+///
+/// 分别按 `index`、`columns` 以及二者的组合分组：组合分组上的聚合结果
+/// 通过每组首行所属的行分组和列分组定位到输出表格中的单元格。
+pub(crate) fn pivot(
+    df: &DataFrame,
+    index: &[&str],
+    columns: &str,
+    values: &str,
+    agg: Agg,
+) -> AxionResult<DataFrame> {
+    if index.contains(&columns) || index.contains(&values) || columns == values {
+        return Err(AxionError::InvalidArgument(format!(
+            "透视表的 index {:?}、columns '{}' 和 values '{}' 不能包含相同的列",
+            index, columns, values
+        )));
+    }
+
+    let to_keys = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+    let mut cell_keys = index.to_vec();
+    cell_keys.push(columns);
+
+    let row_groups = GroupBy::new(df, to_keys(index), pivot_group_options())?;
+    let column_groups = GroupBy::new(df, to_keys(&[columns]), pivot_group_options())?;
+    let cell_groups = GroupBy::new(df, to_keys(&cell_keys), pivot_group_options())?;
+
+    let cell_rows = cell_groups.group_rows();
+    let cell_values = agg.aggregate(df.column(values)?, values, &cell_rows)?;
+
+    let row_ids = row_groups.row_group_ids();
+    let column_ids = column_groups.row_group_ids();
+    let row_group_rows = row_groups.group_rows();
+    let column_group_rows = column_groups.group_rows();
+
+    // cell_positions[列位置][行位置] = 组合分组的位置
+    let mut cell_positions: Vec<Vec<Option<usize>>> = vec![vec![None; row_group_rows.len()]; column_group_rows.len()];
+    for (cell_idx, rows) in cell_rows.iter().enumerate() {
+        let first_row = rows[0];
+        if let (Some(row_pos), Some(col_pos)) = (row_ids[first_row], column_ids[first_row]) {
+            cell_positions[col_pos][row_pos] = Some(cell_idx);
+        }
+    }
+
+    let column_source = df.column(columns)?;
+    let mut output_columns = row_groups.key_columns(&row_group_rows)?;
+    for (rows, positions) in column_group_rows.iter().zip(&cell_positions) {
+        let label = column_source.get_str(rows[0]).unwrap_or_else(|| "null".to_string());
+        let mut output = cell_values.take_indices_option(positions)?;
+        output.rename(&label);
+        output_columns.push(output);
+    }
+
+    if output_columns.is_empty() {
+        return Ok(DataFrame::new_empty());
+    }
+    DataFrame::new(output_columns)
+}
+
+/// 逆透视实现
+///
+/// 输出按 `value_vars` 的顺序依次堆叠：先是第一个值列对应的所有行，再是第二个，以此类推。
+pub(crate) fn melt(df: &DataFrame, id_vars: &[&str], value_vars: &[&str]) -> AxionResult<DataFrame> {
+    for name in id_vars.iter().chain(value_vars) {
+        df.column(name)?;
+    }
+    let value_vars: Vec<&str> = if value_vars.is_empty() {
+        df.columns_names().into_iter().filter(|name| !id_vars.contains(name)).collect()
+    } else {
+        value_vars.to_vec()
+    };
+    if value_vars.is_empty() {
+        return Err(AxionError::InvalidArgument("melt 至少需要一个值列".to_string()));
+    }
+
+    let height = df.height();
+    let repeated_rows: Vec<usize> = (0..value_vars.len()).flat_map(|_| 0..height).collect();
+    let mut output_columns = id_vars
+        .iter()
+        .map(|name| df.column(name)?.take_indices(&repeated_rows))
+        .collect::<AxionResult<Vec<_>>>()?;
+
+    let variable: Vec<String> = value_vars
+        .iter()
+        .flat_map(|name| std::iter::repeat_n(name.to_string(), height))
+        .collect();
+    output_columns.push(Box::new(Series::new("variable".to_string(), variable)));
+
+    let mut value = df.column(value_vars[0])?.clone_box();
+    value.rename("value");
+    for name in &value_vars[1..] {
+        value.append_series(df.column(name)?)?;
+    }
+    output_columns.push(value);
+
+    DataFrame::new(output_columns)
+}
+
+/// 结构体列展开实现
+///
+/// 每个结构体列在原位置被替换为其字段列，整行为 null 的位置在字段列中也为 null。
+pub(crate) fn unnest(df: &DataFrame, columns: &[&str]) -> AxionResult<DataFrame> {
+    for name in columns {
+        let col = df.column(name)?;
+        if col.as_any().downcast_ref::<StructSeries>().is_none() {
+            return Err(AxionError::TypeMismatch {
+                expected: DataType::Struct(Vec::new()),
+                found: col.dtype(),
+                name: name.to_string(),
+            });
+        }
+    }
+
+    let mut output_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(df.width());
+    for col in &df.columns {
+        match col.as_any().downcast_ref::<StructSeries>() {
+            Some(struct_col) if columns.contains(&col.name()) => output_columns.extend(struct_col.fields()?),
+            _ => output_columns.push(col.clone_box()),
+        }
+    }
+    DataFrame::new(output_columns)
+}
+
+/// 列表列展开实现
+///
+/// 每个列表元素生成一行，其他列的值通过 `take_indices` 重复；
+/// 空列表和 null 行各保留一行，展开列的值为 null。
+pub(crate) fn explode(df: &DataFrame, column: &str) -> AxionResult<DataFrame> {
+    let col = df.column(column)?;
+    let list = col.as_any().downcast_ref::<ListSeries>().ok_or_else(|| AxionError::TypeMismatch {
+        expected: DataType::List(Box::new(DataType::Null)),
+        found: col.dtype(),
+        name: column.to_string(),
+    })?;
+    let (flat, rows) = list.flatten()?;
+
+    let mut row_indices = Vec::with_capacity(flat.len());
+    let mut element_indices = Vec::with_capacity(flat.len());
+    for (row, positions) in rows.iter().enumerate() {
+        if positions.is_empty() {
+            row_indices.push(row);
+            element_indices.push(None);
+        }
+        for &pos in positions {
+            row_indices.push(row);
+            element_indices.push(Some(pos));
+        }
+    }
+
+    let mut output_columns: Vec<Box<dyn SeriesTrait>> = Vec::with_capacity(df.width());
+    for col in &df.columns {
+        if col.name() == column {
+            output_columns.push(flat.take_indices_option(&element_indices)?);
+        } else {
+            output_columns.push(col.take_indices(&row_indices)?);
+        }
+    }
+    DataFrame::new(output_columns)
+}
+
+/// 列表列收集实现
+///
+/// 按分组键首次出现的顺序输出各组（null 键单独成组），其余每列收集为同名的列表列。
+pub(crate) fn implode(df: &DataFrame, by: &[&str]) -> AxionResult<DataFrame> {
+    let value_columns: Vec<&str> = df
+        .columns_names()
+        .into_iter()
+        .filter(|name| !by.contains(name))
+        .collect();
+    let specs: Vec<(&str, Agg, &str)> = value_columns.iter().map(|name| (*name, Agg::Implode, *name)).collect();
+    let options = GroupByOptions::builder().dropna(false).build();
+    df.groupby_with_options(by, options)?.agg(&specs)
+}
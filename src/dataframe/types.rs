@@ -3,37 +3,90 @@ use std::fmt::Debug;
 
 /// 表示分组键中的单个值
 /// 
-/// 在 GroupBy 操作中用于构建分组键，支持常用的可哈希和可比较的数据类型。
+/// 在 GroupBy 操作中用于构建分组键，覆盖所有可哈希的非 List 数据类型。
 /// 
 /// # 支持的类型
 /// 
-/// - `Int` - 32位整数
+/// - `Int8` ~ `Int128` - 各宽度的有符号整数
+/// - `UInt8` ~ `UInt128` - 各宽度的无符号整数
+/// - `Float32` / `Float64` - 浮点数，按位模式存储（`-0.0` 归一为 `0.0`，所有 NaN 视为同一个值）
 /// - `Str` - 字符串
 /// - `Bool` - 布尔值
+/// - `Null` - 空值，仅在分组时保留 null 键（`dropna = false`）时使用
 /// 
 /// # 示例
 /// 
 /// ```rust
-/// use axion::dataframe::types::GroupKeyValue;
+/// use axion_data::dataframe::types::GroupKeyValue;
 /// 
-/// let key1 = GroupKeyValue::Int(42);
+/// let key1 = GroupKeyValue::Int64(42);
 /// let key2 = GroupKeyValue::Str("category".to_string());
-/// let key3 = GroupKeyValue::Bool(true);
+/// let key3 = GroupKeyValue::from_f64(1.5);
 /// ```
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum GroupKeyValue {
-    Int(i32),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Int128(i128),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+    UInt128(u128),
+    Float32(u32),
+    Float64(u64),
     Str(String),
     Bool(bool),
+    Null,
+}
+
+impl GroupKeyValue {
+    /// 从 f32 创建分组键值
+    pub fn from_f32(value: f32) -> Self {
+        let normalized = if value == 0.0 {
+            0.0
+        } else if value.is_nan() {
+            f32::NAN
+        } else {
+            value
+        };
+        GroupKeyValue::Float32(normalized.to_bits())
+    }
+
+    /// 从 f64 创建分组键值
+    pub fn from_f64(value: f64) -> Self {
+        let normalized = if value == 0.0 {
+            0.0
+        } else if value.is_nan() {
+            f64::NAN
+        } else {
+            value
+        };
+        GroupKeyValue::Float64(normalized.to_bits())
+    }
 }
 
 impl Hash for GroupKeyValue {
     fn hash<H: Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
-            GroupKeyValue::Int(i) => i.hash(state),
+            GroupKeyValue::Int8(v) => v.hash(state),
+            GroupKeyValue::Int16(v) => v.hash(state),
+            GroupKeyValue::Int32(v) => v.hash(state),
+            GroupKeyValue::Int64(v) => v.hash(state),
+            GroupKeyValue::Int128(v) => v.hash(state),
+            GroupKeyValue::UInt8(v) => v.hash(state),
+            GroupKeyValue::UInt16(v) => v.hash(state),
+            GroupKeyValue::UInt32(v) => v.hash(state),
+            GroupKeyValue::UInt64(v) => v.hash(state),
+            GroupKeyValue::UInt128(v) => v.hash(state),
+            GroupKeyValue::Float32(bits) => bits.hash(state),
+            GroupKeyValue::Float64(bits) => bits.hash(state),
             GroupKeyValue::Str(s) => s.hash(state),
             GroupKeyValue::Bool(b) => b.hash(state),
+            GroupKeyValue::Null => {}
         }
     }
 }
@@ -47,7 +100,7 @@ impl Hash for GroupKeyValue {
 /// # 示例
 /// 
 /// ```rust
-/// use axion::dataframe::types::{GroupKey, GroupKeyValue};
+/// use axion_data::dataframe::types::{GroupKey, GroupKeyValue};
 /// 
 /// // 按 "类别" 和 "状态" 两列分组的键
 /// let group_key: GroupKey = vec![
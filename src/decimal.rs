@@ -0,0 +1,528 @@
+//! 定点小数模块
+//!
+//! 提供以 `i128` 存储的 `Decimal` 值类型，用于金额等不能容忍浮点误差的列。
+//! 值的小数位数（scale）和总位数（precision）由所在 Series 的 `DataType::Decimal` 决定，
+//! 例如 `Decimal(12345)` 在 `Decimal(10, 2)` 列中表示 `123.45`。
+
+use crate::dtype::{DataType, DataTypeTrait};
+use crate::error::{AxionError, AxionResult, CastError};
+use crate::series::{Series, SeriesArithScalar, SeriesArithSeries, SeriesTrait};
+use num_traits::{SaturatingAdd, Zero};
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::iter::Sum;
+use std::ops::Add;
+
+/// Decimal 类型支持的最大总位数
+pub const MAX_DECIMAL_PRECISION: u8 = 38;
+
+/// 除法结果的最小小数位数
+const MIN_DIV_SCALE: u8 = 6;
+
+/// 定点小数值，存储放大 `10^scale` 倍后的整数
+///
+/// 单独使用时视为 scale 为 0 的整数。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal(pub i128);
+
+impl Decimal {
+    /// 按指定小数位数解析十进制字符串，如 `"-12.50"`
+    ///
+    /// 小数部分多于 `scale` 位（末尾的 0 除外）或超出 38 位总精度时返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::Decimal;
+    /// assert_eq!(Decimal::parse("12.5", 2), Some(Decimal(1250)));
+    /// assert_eq!(Decimal::parse("0.125", 2), None);
+    /// ```
+    pub fn parse(s: &str, scale: u8) -> Option<Self> {
+        parse_decimal(s, MAX_DECIMAL_PRECISION, scale).map(Decimal)
+    }
+
+    /// 按指定小数位数格式化，如 scale 为 2 时 `Decimal(-5)` 格式化为 `"-0.05"`
+    pub fn to_string_with_scale(&self, scale: u8) -> String {
+        format_decimal(self.0, scale)
+    }
+
+    /// 按指定小数位数转换为 f64（可能损失精度）
+    pub fn to_f64(&self, scale: u8) -> f64 {
+        format_decimal(self.0, scale).parse().unwrap_or(f64::NAN)
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl DataTypeTrait for Decimal {
+    const DTYPE: DataType = DataType::Decimal(MAX_DECIMAL_PRECISION, 0);
+
+    fn as_dtype(&self) -> DataType {
+        Self::DTYPE
+    }
+
+    fn format_with_dtype(&self, dtype: &DataType) -> Option<String> {
+        match dtype {
+            DataType::Decimal(_, scale) => Some(format_decimal(self.0, *scale)),
+            _ => None,
+        }
+    }
+}
+
+// === 同 scale 值之间的运算 ===
+
+/// 溢出时饱和
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, rhs: Decimal) -> Decimal {
+        Decimal(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl SaturatingAdd for Decimal {
+    fn saturating_add(&self, v: &Self) -> Self {
+        *self + *v
+    }
+}
+
+impl Zero for Decimal {
+    fn zero() -> Self {
+        Decimal(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal(0), |acc, v| acc + v)
+    }
+}
+
+// === 精度校验与换算 ===
+
+/// 检查 Decimal 的总位数和小数位数是否有效（`0 <= scale <= precision <= 38` 且 `precision >= 1`）
+pub(crate) fn check_decimal_dtype(precision: u8, scale: u8) -> AxionResult<()> {
+    if precision == 0 || precision > MAX_DECIMAL_PRECISION || scale > precision {
+        return Err(AxionError::InvalidArgument(format!(
+            "无效的 Decimal 类型参数: precision = {}, scale = {}（要求 1 <= precision <= {} 且 scale <= precision）",
+            precision, scale, MAX_DECIMAL_PRECISION
+        )));
+    }
+    Ok(())
+}
+
+/// 10 的 `exp` 次方，溢出时返回 `None`
+fn pow10(exp: u32) -> Option<i128> {
+    10i128.checked_pow(exp)
+}
+
+/// 检查整数值的位数是否不超过 `precision`
+fn fits_precision(raw: i128, precision: u8) -> bool {
+    raw.unsigned_abs() < 10u128.pow(precision as u32)
+}
+
+/// 整数除法，结果四舍五入（远离零），除数为 0 或溢出时返回 `None`
+fn div_round(numerator: i128, denominator: i128) -> Option<i128> {
+    let quotient = numerator.checked_div(denominator)?;
+    let remainder = numerator.checked_rem(denominator)?;
+    if remainder.unsigned_abs() * 2 >= denominator.unsigned_abs() {
+        let away = if (numerator < 0) == (denominator < 0) { 1 } else { -1 };
+        quotient.checked_add(away)
+    } else {
+        Some(quotient)
+    }
+}
+
+/// 将值从一个小数位数换算到另一个小数位数，缩小时四舍五入（远离零）
+fn rescale(raw: i128, from: u8, to: u8) -> Option<i128> {
+    if to >= from {
+        raw.checked_mul(pow10((to - from) as u32)?)
+    } else {
+        div_round(raw, pow10((from - to) as u32)?)
+    }
+}
+
+/// 计算一组值的平均值，保持原小数位数并四舍五入（远离零），无值或溢出时返回 `None`
+pub(crate) fn mean_raw(values: impl Iterator<Item = i128>) -> Option<i128> {
+    let mut sum: i128 = 0;
+    let mut count: i128 = 0;
+    for value in values {
+        sum = sum.checked_add(value)?;
+        count += 1;
+    }
+    if count == 0 {
+        return None;
+    }
+    div_round(sum, count)
+}
+
+// === 解析与格式化 ===
+
+/// 解析十进制字符串，返回去掉符号后的整数值和小数位数（末尾的 0 不计入）
+fn parse_digits(s: &str) -> Option<(i128, u8)> {
+    let s = s.trim();
+    let (negative, unsigned) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    if int_part.is_empty() && frac_part.is_empty() {
+        return None;
+    }
+    if !int_part.bytes().chain(frac_part.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let frac_part = frac_part.trim_end_matches('0');
+    let frac_digits = u8::try_from(frac_part.len()).ok()?;
+    let mut value: i128 = 0;
+    for digit in int_part.bytes().chain(frac_part.bytes()) {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as i128)?;
+    }
+    Some((if negative { -value } else { value }, frac_digits))
+}
+
+/// 按指定精度精确解析十进制字符串
+///
+/// 小数部分多于 `scale` 位（末尾的 0 除外）或整数值超出 `precision` 位时返回 `None`。
+pub(crate) fn parse_decimal(s: &str, precision: u8, scale: u8) -> Option<i128> {
+    let (value, frac_digits) = parse_digits(s)?;
+    if frac_digits > scale {
+        return None;
+    }
+    rescale(value, frac_digits, scale).filter(|raw| fits_precision(*raw, precision))
+}
+
+/// 按指定精度解析十进制字符串，多余的小数位四舍五入（远离零）
+fn parse_decimal_rounded(s: &str, precision: u8, scale: u8) -> Option<i128> {
+    let (value, frac_digits) = parse_digits(s)?;
+    rescale(value, frac_digits, scale).filter(|raw| fits_precision(*raw, precision))
+}
+
+/// 按小数位数格式化整数值，不丢失任何位数
+pub(crate) fn format_decimal(raw: i128, scale: u8) -> String {
+    let sign = if raw < 0 { "-" } else { "" };
+    let digits = raw.unsigned_abs().to_string();
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let scale = scale as usize;
+    let padded = format!("{:0>width$}", digits, width = scale + 1);
+    let (int_part, frac_part) = padded.split_at(padded.len() - scale);
+    format!("{}{}.{}", sign, int_part, frac_part)
+}
+
+// === 类型转换 ===
+
+/// 将 Series 转换为指定精度的 Decimal（由 `SeriesTrait::cast` 调用）
+///
+/// Decimal 之间按小数位数换算，其他类型先格式化为字符串再解析，多余的小数位四舍五入（远离零）。
+pub(crate) fn cast_to_decimal<T: DataTypeTrait>(
+    series: &Series<T>,
+    precision: u8,
+    scale: u8,
+    strict: bool,
+) -> AxionResult<Box<dyn SeriesTrait>> {
+    check_decimal_dtype(precision, scale)?;
+    let data: Vec<Option<Decimal>> = match (series as &dyn Any).downcast_ref::<Series<Decimal>>() {
+        Some(decimals) => {
+            let source_scale = decimals.scale();
+            decimals
+                .iter()
+                .map(|opt| {
+                    opt.and_then(|v| rescale(v.0, source_scale, scale))
+                        .filter(|raw| fits_precision(*raw, precision))
+                        .map(Decimal)
+                })
+                .collect()
+        }
+        None => {
+            let strings: Series<String> = series.cast_lenient();
+            strings
+                .iter()
+                .map(|opt| opt.and_then(|s| parse_decimal_rounded(s, precision, scale)).map(Decimal))
+                .collect()
+        }
+    };
+    let converted = Series::new_from_options(series.name().to_string(), data)
+        .with_dtype(DataType::Decimal(precision, scale))?;
+    if strict {
+        let failed = (0..series.len()).find(|&idx| series.is_valid(idx) && !converted.is_valid(idx));
+        if let Some((idx, value)) = failed.and_then(|idx| series.get(idx).map(|value| (idx, value))) {
+            return Err(AxionError::CastError(CastError(format!(
+                "无法将 Series '{}' 第 {} 行的值 {:?} 从 {:?} 转换为 {:?}",
+                series.name(),
+                idx,
+                value,
+                series.dtype(),
+                converted.dtype()
+            ))));
+        }
+    }
+    Ok(Box::new(converted))
+}
+
+/// 将 Decimal Series 格式化为字符串 Series，用于转换为其他类型
+///
+/// 目标为整数类型时小数部分向零截断。
+pub(crate) fn decimal_to_strings(series: &Series<Decimal>, truncate: bool) -> Series<String> {
+    let scale = series.scale();
+    let data: Vec<Option<String>> = series
+        .iter()
+        .map(|opt| {
+            opt.map(|v| match (truncate, pow10(scale as u32)) {
+                (true, Some(factor)) => (v.0 / factor).to_string(),
+                _ => format_decimal(v.0, scale),
+            })
+        })
+        .collect();
+    Series::new_from_options(series.name().to_string(), data)
+}
+
+// === Series 级别的定点运算 ===
+
+/// 定点小数的算术运算
+#[derive(Debug, Clone, Copy)]
+enum DecimalOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+impl DecimalOp {
+    /// 根据两侧的 (precision, scale) 计算结果类型
+    ///
+    /// 加减法和取余的 scale 取两侧较大者，乘法为两侧之和，除法至少保留 6 位小数。
+    fn result_dtype(&self, (p1, s1): (u8, u8), (p2, s2): (u8, u8)) -> AxionResult<(u8, u8)> {
+        let max_scale = s1.max(s2);
+        let int_digits = (p1 - s1).max(p2 - s2);
+        let (precision, scale) = match self {
+            DecimalOp::Add | DecimalOp::Sub => (int_digits as u32 + max_scale as u32 + 1, max_scale),
+            DecimalOp::Mul => {
+                let scale = s1 as u32 + s2 as u32;
+                if scale > MAX_DECIMAL_PRECISION as u32 {
+                    return Err(AxionError::InvalidArgument(format!(
+                        "乘法结果的小数位数 {} 超过最大精度 {}",
+                        scale, MAX_DECIMAL_PRECISION
+                    )));
+                }
+                (p1 as u32 + p2 as u32 + 1, scale as u8)
+            }
+            DecimalOp::Div => (MAX_DECIMAL_PRECISION as u32, max_scale.max(MIN_DIV_SCALE)),
+            DecimalOp::Rem => (int_digits as u32 + max_scale as u32, max_scale),
+        };
+        Ok(((precision.min(MAX_DECIMAL_PRECISION as u32)) as u8, scale))
+    }
+
+    /// 计算单个结果，溢出或除数为 0 时返回 `None`
+    fn apply(&self, a: i128, s1: u8, b: i128, s2: u8, scale: u8) -> Option<i128> {
+        match self {
+            DecimalOp::Add => rescale(a, s1, scale)?.checked_add(rescale(b, s2, scale)?),
+            DecimalOp::Sub => rescale(a, s1, scale)?.checked_sub(rescale(b, s2, scale)?),
+            DecimalOp::Mul => a.checked_mul(b),
+            DecimalOp::Div => {
+                // a / 10^s1 ÷ (b / 10^s2) = a * 10^(scale - s1 + s2) / b / 10^scale
+                let exp = scale as u32 + s2 as u32 - s1 as u32;
+                div_round(a.checked_mul(pow10(exp)?)?, b)
+            }
+            DecimalOp::Rem => rescale(a, s1, scale)?.checked_rem(rescale(b, s2, scale)?),
+        }
+    }
+}
+
+/// 逐元素计算两组 Decimal 值，任一侧为 null、溢出、除数为 0 或超出结果精度时为 null
+fn zip_decimal<'a>(
+    left: &Series<Decimal>,
+    right: impl Iterator<Item = Option<&'a Decimal>>,
+    right_dtype: (u8, u8),
+    op: DecimalOp,
+    name: String,
+) -> AxionResult<Series<Decimal>> {
+    let (s1, s2) = (left.scale(), right_dtype.1);
+    let (precision, scale) = op.result_dtype((left.precision(), s1), right_dtype)?;
+    let data: Vec<Option<Decimal>> = left
+        .iter()
+        .zip(right)
+        .map(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => op
+                .apply(a.0, s1, b.0, s2, scale)
+                .filter(|raw| fits_precision(*raw, precision))
+                .map(Decimal),
+            _ => None,
+        })
+        .collect();
+    Series::new_from_options(name, data).with_dtype(DataType::Decimal(precision, scale))
+}
+
+impl Series<Decimal> {
+    /// 获取 Decimal 列的总位数
+    pub fn precision(&self) -> u8 {
+        match self.dtype() {
+            DataType::Decimal(precision, _) => precision,
+            _ => MAX_DECIMAL_PRECISION,
+        }
+    }
+
+    /// 获取 Decimal 列的小数位数
+    pub fn scale(&self) -> u8 {
+        match self.dtype() {
+            DataType::Decimal(_, scale) => scale,
+            _ => 0,
+        }
+    }
+
+    /// 由十进制字符串创建指定精度的 Decimal Series
+    ///
+    /// # 参数
+    ///
+    /// * `name` - Series 名称
+    /// * `values` - 十进制字符串，`None` 表示 null
+    /// * `precision` - 总位数，取值范围为 `[1, 38]`
+    /// * `scale` - 小数位数，不能大于 `precision`
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::InvalidArgument` - 精度参数无效
+    /// * `AxionError::CastError` - 存在无法按该精度精确表示的值
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::{Decimal, Series, SeriesTrait};
+    /// let fare = Series::<Decimal>::from_strs("Fare".into(), vec![Some("7.25"), None, Some("71.2833")], 10, 4)?;
+    /// assert_eq!(fare.get_str(0), Some("7.2500".to_string()));
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn from_strs<I, S>(name: String, values: I, precision: u8, scale: u8) -> AxionResult<Self>
+    where
+        I: IntoIterator<Item = Option<S>>,
+        S: AsRef<str>,
+    {
+        check_decimal_dtype(precision, scale)?;
+        let mut data = Vec::new();
+        for (idx, opt) in values.into_iter().enumerate() {
+            let parsed = match opt {
+                Some(s) => match parse_decimal(s.as_ref(), precision, scale) {
+                    Some(raw) => Some(Decimal(raw)),
+                    None => {
+                        return Err(AxionError::CastError(CastError(format!(
+                            "无法将 Series '{}' 第 {} 行的值 '{}' 解析为 Decimal({}, {})",
+                            name,
+                            idx,
+                            s.as_ref(),
+                            precision,
+                            scale
+                        ))));
+                    }
+                },
+                None => None,
+            };
+            data.push(parsed);
+        }
+        Series::new_from_options(name, data).with_dtype(DataType::Decimal(precision, scale))
+    }
+
+    /// 计算所有有效值的精确平均值
+    ///
+    /// 与返回 f64 的 `mean` 不同，结果保持列的小数位数，并四舍五入（远离零）。
+    /// 没有有效值或求和溢出时返回 `None`。
+    pub fn mean_decimal(&self) -> Option<Decimal> {
+        mean_raw(self.iter_valid().map(|v| v.0)).map(Decimal)
+    }
+}
+
+/// 两侧的小数位数可以不同，结果类型见各运算的说明
+///
+/// * 加减法：scale 取两侧较大者
+/// * 乘法：scale 为两侧之和
+/// * 除法：scale 取两侧较大者且至少为 6，四舍五入（远离零）
+/// * 取余：scale 取两侧较大者
+///
+/// 溢出、除数为 0 或结果超出精度的位置为 null。
+impl SeriesArithSeries<&Series<Decimal>> for Series<Decimal> {
+    type AddOutput = Decimal;
+    type SubOutput = Decimal;
+    type MulOutput = Decimal;
+    type DivOutput = Decimal;
+    type RemOutput = Decimal;
+
+    fn add_series(&self, rhs: &Series<Decimal>) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_series(rhs, DecimalOp::Add, "add_series")
+    }
+
+    fn sub_series(&self, rhs: &Series<Decimal>) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_series(rhs, DecimalOp::Sub, "sub_series")
+    }
+
+    fn mul_series(&self, rhs: &Series<Decimal>) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_series(rhs, DecimalOp::Mul, "mul_series")
+    }
+
+    fn div_series(&self, rhs: &Series<Decimal>) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_series(rhs, DecimalOp::Div, "div_series")
+    }
+
+    fn rem_series(&self, rhs: &Series<Decimal>) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_series(rhs, DecimalOp::Rem, "rem_series")
+    }
+}
+
+/// 标量按本 Series 的精度解释，例如在 `Decimal(10, 2)` 列上 `Decimal(150)` 表示 `1.50`，
+/// 结果类型规则与 Series 间运算相同
+impl SeriesArithScalar<Decimal> for Series<Decimal> {
+    type AddOutput = Decimal;
+    type SubOutput = Decimal;
+    type MulOutput = Decimal;
+    type DivOutput = Decimal;
+    type RemOutput = Decimal;
+
+    fn add_scalar(&self, rhs: Decimal) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_scalar(rhs, DecimalOp::Add, "add_scalar")
+    }
+
+    fn sub_scalar(&self, rhs: Decimal) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_scalar(rhs, DecimalOp::Sub, "sub_scalar")
+    }
+
+    fn mul_scalar(&self, rhs: Decimal) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_scalar(rhs, DecimalOp::Mul, "mul_scalar")
+    }
+
+    fn div_scalar(&self, rhs: Decimal) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_scalar(rhs, DecimalOp::Div, "div_scalar")
+    }
+
+    fn rem_scalar(&self, rhs: Decimal) -> AxionResult<Series<Decimal>> {
+        self.zip_decimal_scalar(rhs, DecimalOp::Rem, "rem_scalar")
+    }
+}
+
+impl Series<Decimal> {
+    fn zip_decimal_series(&self, rhs: &Series<Decimal>, op: DecimalOp, method: &str) -> AxionResult<Series<Decimal>> {
+        if self.len() != rhs.len() {
+            return Err(AxionError::MismatchedLengths {
+                expected: self.len(),
+                found: rhs.len(),
+                name: rhs.name().to_string(),
+            });
+        }
+        let name = format!("{}_{}_{}", self.name(), method, rhs.name());
+        zip_decimal(self, rhs.iter(), (rhs.precision(), rhs.scale()), op, name)
+    }
+
+    fn zip_decimal_scalar(&self, rhs: Decimal, op: DecimalOp, method: &str) -> AxionResult<Series<Decimal>> {
+        let name = format!("{}_{}", self.name(), method);
+        let right = std::iter::repeat(Some(&rhs));
+        zip_decimal(self, right, (self.precision(), self.scale()), op, name)
+    }
+}
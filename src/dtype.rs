@@ -14,8 +14,11 @@ use serde::{Deserialize, Serialize};
 /// - **整数类型** - Int8, Int16, Int32, Int64, Int128
 /// - **无符号整数类型** - UInt8, UInt16, UInt32, UInt64, UInt128
 /// - **浮点类型** - Float32, Float64
+/// - **定点小数类型** - Decimal(总位数, 小数位数)
 /// - **字符串类型** - String
-/// - **复合类型** - List(内部类型)
+/// - **分类类型** - Categorical
+/// - **时间类型** - Date, Datetime(时间单位, 时区), Duration(时间单位)
+/// - **复合类型** - List(内部类型), Struct(字段列表)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DataType {
     /// 空值类型
@@ -46,13 +49,64 @@ pub enum DataType {
     Float32,
     /// 64位浮点数
     Float64,
+    /// 定点小数类型，参数为总位数（precision，最大 38）和小数位数（scale），以 i128 存储
+    Decimal(u8, u8),
     /// 字符串类型
     String,
+    /// 分类类型，以 u32 编码和共享的类别字典存储字符串
+    Categorical,
+    /// 日期类型，以自 1970-01-01 起的天数（i32）存储
+    Date,
+    /// 日期时间类型，以自 Unix 纪元起的指定单位数（i64）存储
+    /// 时区为 `None` 表示不带时区的本地时间，否则值按 UTC 存储，显示时按时区偏移
+    Datetime(TimeUnit, Option<String>),
+    /// 时间间隔类型，以指定单位数（i64）存储
+    Duration(TimeUnit),
     /// 列表类型，包含内部元素的数据类型
     List(Box<DataType>),
+    /// 结构体类型，包含按顺序排列的字段名和字段数据类型
+    Struct(Vec<(String, DataType)>),
+}
+
+/// 时间单位
+///
+/// 决定 `Datetime` 和 `Duration` 类型中整数值所代表的精度。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum TimeUnit {
+    /// 纳秒
+    Nanoseconds,
+    /// 微秒
+    Microseconds,
+    /// 毫秒
+    Milliseconds,
+}
+
+impl TimeUnit {
+    /// 每个单位包含的纳秒数
+    pub fn nanos_per_unit(&self) -> i64 {
+        match self {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => 1_000,
+            TimeUnit::Milliseconds => 1_000_000,
+        }
+    }
+
+    /// 格式化时小数秒部分的位数
+    pub fn fraction_digits(&self) -> usize {
+        match self {
+            TimeUnit::Nanoseconds => 9,
+            TimeUnit::Microseconds => 6,
+            TimeUnit::Milliseconds => 3,
+        }
+    }
 }
 
 impl DataType {
+    /// 检查数据类型是否为时间类型（Date、Datetime 或 Duration）
+    pub fn is_temporal(&self) -> bool {
+        matches!(self, DataType::Date | DataType::Datetime(_, _) | DataType::Duration(_))
+    }
+
     /// 检查数据类型是否为浮点类型
     pub fn is_float(&self) -> bool {
         matches!(self, DataType::Float32 | DataType::Float64)
@@ -67,13 +121,18 @@ impl DataType {
         )
     }
 
-    /// 检查数据类型是否为数值类型（整数或浮点）
+    /// 检查数据类型是否为定点小数类型
+    pub fn is_decimal(&self) -> bool {
+        matches!(self, DataType::Decimal(_, _))
+    }
+
+    /// 检查数据类型是否为数值类型（整数、浮点或定点小数）
     pub fn is_numeric(&self) -> bool {
         matches!(
             self,
-            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 |
-            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 |
-            DataType::Float32 | DataType::Float64
+            DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::Int64 | DataType::Int128 |
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 | DataType::UInt128 |
+            DataType::Float32 | DataType::Float64 | DataType::Decimal(_, _)
         )
     }
 }
@@ -96,8 +155,14 @@ impl Ord for DataType {
                 DataType::UInt128 => 24,
                 DataType::Float32 => 30,
                 DataType::Float64 => 31,
+                DataType::Decimal(_, _) => 32,
                 DataType::String => 40,
+                DataType::Categorical => 41,
+                DataType::Date => 50,
+                DataType::Datetime(_, _) => 51,
+                DataType::Duration(_) => 52,
                 DataType::List(_) => 100,
+                DataType::Struct(_) => 101,
             }
         }
 
@@ -105,6 +170,14 @@ impl Ord for DataType {
             Ordering::Equal => {
                 match (self, other) {
                     (DataType::List(a), DataType::List(b)) => a.cmp(b),
+                    (DataType::Struct(a), DataType::Struct(b)) => a.cmp(b),
+                    (DataType::Datetime(unit_a, tz_a), DataType::Datetime(unit_b, tz_b)) => {
+                        unit_a.cmp(unit_b).then_with(|| tz_a.cmp(tz_b))
+                    }
+                    (DataType::Duration(a), DataType::Duration(b)) => a.cmp(b),
+                    (DataType::Decimal(p_a, s_a), DataType::Decimal(p_b, s_b)) => {
+                        p_a.cmp(p_b).then_with(|| s_a.cmp(s_b))
+                    }
                     _ => Ordering::Equal,
                 }
             }
@@ -119,6 +192,37 @@ impl PartialOrd for DataType {
     }
 }
 
+/// 类型转换的中间值
+///
+/// `Series::cast` 先将源值转换为此中间表示，再由目标类型从中构造，
+/// 从而避免为每一对源类型和目标类型单独实现转换。
+#[derive(Debug, Clone, PartialEq)]
+pub enum CastValue {
+    /// 任意宽度的整数，统一提升为 `i128`
+    Int(i128),
+    /// 超出 `i128` 范围的无符号整数（仅来自 `u128`）
+    UInt(u128),
+    /// 32位浮点数，单独保留以便无损格式化为字符串
+    Float32(f32),
+    /// 64位浮点数
+    Float64(f64),
+    /// 布尔值
+    Bool(bool),
+    /// 字符串
+    Str(String),
+}
+
+impl CastValue {
+    /// 以 f64 形式获取浮点值，非浮点值返回 `None`
+    fn as_float(&self) -> Option<f64> {
+        match self {
+            CastValue::Float32(v) => Some(*v as f64),
+            CastValue::Float64(v) => Some(*v),
+            _ => None,
+        }
+    }
+}
+
 /// 数据类型特征
 /// 
 /// 为 Rust 原生类型与 Axion DataType 枚举之间提供映射关系。
@@ -128,9 +232,10 @@ impl PartialOrd for DataType {
 /// 
 /// 实现此特征的类型必须满足：
 /// - `Debug` + `Clone` - 用于调试和克隆
+/// - `Default` - 作为 null 位置在值缓冲区中的占位值
 /// - `Send` + `Sync` - 用于多线程安全
 /// - `'static` - 确保类型生命周期足够长
-pub trait DataTypeTrait: Debug + Clone + Send + Sync + 'static {
+pub trait DataTypeTrait: Debug + Clone + Default + Send + Sync + 'static {
     /// 与此类型关联的 DataType 枚举变体
     const DTYPE: DataType;
 
@@ -138,43 +243,158 @@ pub trait DataTypeTrait: Debug + Clone + Send + Sync + 'static {
     /// 
     /// 通常直接返回 `DTYPE` 常量
     fn as_dtype(&self) -> DataType;
+
+    /// 将值转换为类型转换的中间值
+    ///
+    /// 默认返回 `None`，表示该类型不支持作为类型转换的源类型
+    fn to_cast_value(&self) -> Option<CastValue> {
+        None
+    }
+
+    /// 从类型转换的中间值构造此类型的值
+    ///
+    /// 当值无法用此类型表示（溢出、解析失败等）或不支持该转换时返回 `None`
+    fn from_cast_value(_value: &CastValue) -> Option<Self> {
+        None
+    }
+
+    /// 按 Series 的实际数据类型格式化值
+    ///
+    /// 用于值的含义依赖于数据类型参数的类型（如带时间单位和时区的 Datetime）。
+    /// 默认返回 `None`，表示直接使用 `Display` 格式化。
+    fn format_with_dtype(&self, _dtype: &DataType) -> Option<String> {
+        None
+    }
 }
 
 /// 为基础类型实现 DataTypeTrait 的宏
 macro_rules! impl_datatype_trait {
-    ($prim_type: ty, $dtype_variant: ident) => {
+    ($prim_type: ty, $dtype_variant: ident, $to_cast:expr, $from_cast:expr) => {
         impl DataTypeTrait for $prim_type where $prim_type: Debug + Clone {
             const DTYPE: DataType = DataType::$dtype_variant;
 
             fn as_dtype(&self) -> DataType {
                 Self::DTYPE
             }
+
+            fn to_cast_value(&self) -> Option<CastValue> {
+                let to_cast: fn(&$prim_type) -> CastValue = $to_cast;
+                Some(to_cast(self))
+            }
+
+            fn from_cast_value(value: &CastValue) -> Option<Self> {
+                let from_cast: fn(&CastValue) -> Option<$prim_type> = $from_cast;
+                from_cast(value)
+            }
         }
     };
 }
 
+/// 为整数类型实现 DataTypeTrait 的宏
+///
+/// 浮点数向零截断，超出范围、NaN 或无法解析的字符串视为转换失败。
+macro_rules! impl_integer_datatype_trait {
+    ($($prim_type: ty => $dtype_variant: ident),* $(,)?) => {
+        $(
+            impl_datatype_trait!(
+                $prim_type,
+                $dtype_variant,
+                |v| match num_traits::ToPrimitive::to_i128(v) {
+                    Some(v) => CastValue::Int(v),
+                    None => CastValue::UInt(num_traits::ToPrimitive::to_u128(v).unwrap_or_default()),
+                },
+                |value| match value {
+                    CastValue::Int(v) => <$prim_type>::try_from(*v).ok(),
+                    CastValue::UInt(v) => <$prim_type>::try_from(*v).ok(),
+                    CastValue::Float32(_) | CastValue::Float64(_) => {
+                        value.as_float().and_then(<$prim_type as num_traits::NumCast>::from)
+                    }
+                    CastValue::Bool(b) => Some(*b as $prim_type),
+                    CastValue::Str(s) => s.trim().parse().ok(),
+                }
+            );
+        )*
+    };
+}
+
+/// 为浮点类型实现 DataTypeTrait 的宏
+///
+/// 有限值在目标类型中溢出为无穷大时视为转换失败。
+macro_rules! impl_float_datatype_trait {
+    ($($prim_type: ty => $dtype_variant: ident, $cast_variant: ident),* $(,)?) => {
+        $(
+            impl_datatype_trait!(
+                $prim_type,
+                $dtype_variant,
+                |v| CastValue::$cast_variant(*v),
+                |value| match value {
+                    CastValue::Int(v) => Some(*v as $prim_type),
+                    CastValue::UInt(v) => Some(*v as $prim_type),
+                    CastValue::Float32(_) | CastValue::Float64(_) => {
+                        let v = value.as_float()?;
+                        let converted = v as $prim_type;
+                        (!(v.is_finite() && converted.is_infinite())).then_some(converted)
+                    }
+                    CastValue::Bool(b) => Some(if *b { 1.0 } else { 0.0 }),
+                    CastValue::Str(s) => s.trim().parse().ok(),
+                }
+            );
+        )*
+    };
+}
+
 // 为整数类型实现 DataTypeTrait
-impl_datatype_trait!(i8, Int8);
-impl_datatype_trait!(i16, Int16);
-impl_datatype_trait!(i32, Int32);
-impl_datatype_trait!(i64, Int64);
-impl_datatype_trait!(u8, UInt8);
-impl_datatype_trait!(u16, UInt16);
-impl_datatype_trait!(u32, UInt32);
-impl_datatype_trait!(u64, UInt64);
+impl_integer_datatype_trait!(
+    i8 => Int8,
+    i16 => Int16,
+    i32 => Int32,
+    i64 => Int64,
+    i128 => Int128,
+    u8 => UInt8,
+    u16 => UInt16,
+    u32 => UInt32,
+    u64 => UInt64,
+    u128 => UInt128,
+);
 
 // 为浮点类型实现 DataTypeTrait
-impl_datatype_trait!(f32, Float32);
-impl_datatype_trait!(f64, Float64);
+impl_float_datatype_trait!(
+    f32 => Float32, Float32,
+    f64 => Float64, Float64,
+);
 
 // 为布尔类型实现 DataTypeTrait
-impl_datatype_trait!(bool, Bool);
-
-// 为字符串类型手动实现 DataTypeTrait
-impl DataTypeTrait for String {
-    const DTYPE: DataType = DataType::String;
-
-    fn as_dtype(&self) -> DataType {
-        Self::DTYPE
+// 非零数值视为 true，字符串仅接受不区分大小写的 "true" 和 "false"
+impl_datatype_trait!(
+    bool,
+    Bool,
+    |v| CastValue::Bool(*v),
+    |value| match value {
+        CastValue::Int(v) => Some(*v != 0),
+        CastValue::UInt(v) => Some(*v != 0),
+        CastValue::Float32(_) | CastValue::Float64(_) => {
+            value.as_float().filter(|v| !v.is_nan()).map(|v| v != 0.0)
+        }
+        CastValue::Bool(b) => Some(*b),
+        CastValue::Str(s) => match s.trim().to_ascii_lowercase().as_str() {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None,
+        },
     }
-}
+);
+
+// 为字符串类型实现 DataTypeTrait，任何值都可以格式化为字符串
+impl_datatype_trait!(
+    String,
+    String,
+    |v| CastValue::Str(v.clone()),
+    |value| Some(match value {
+        CastValue::Int(v) => v.to_string(),
+        CastValue::UInt(v) => v.to_string(),
+        CastValue::Float32(v) => v.to_string(),
+        CastValue::Float64(v) => v.to_string(),
+        CastValue::Bool(b) => b.to_string(),
+        CastValue::Str(s) => s.clone(),
+    })
+);
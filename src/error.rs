@@ -73,6 +73,16 @@ pub enum AxionError {
         /// 实际发现的数据类型
         found: DataType,
     },
+
+    /// Join 操作基数校验错误
+    ///
+    /// 当连接键不满足 `JoinValidation` 指定的一对一/一对多/多对一约束时触发
+    JoinValidationError {
+        /// 校验模式名称，如 "one_to_one"
+        validate: String,
+        /// 连接键存在重复值的一侧（"左侧" 或 "右侧"）
+        side: String,
+    },
     
     /// 索引越界错误
     /// 
@@ -159,6 +169,11 @@ impl Display for AxionError {
                 "{} 表的连接键列 '{}' 类型无效: 期望 {:?}，发现 {:?}",
                 side, name, expected, found
             ),
+            AxionError::JoinValidationError { validate, side } => write!(
+                f,
+                "连接校验 '{}' 失败: {} 表的连接键存在重复值",
+                validate, side
+            ),
             AxionError::IndexOutOfBounds(index, len) => write!(
                 f,
                 "索引越界: 集合长度为 {}，但访问索引为 {}",
@@ -196,8 +211,10 @@ impl Error for AxionError {
 /// # 示例
 /// 
 /// ```rust
+/// # use axion_data::{AxionResult, DataFrame};
 /// fn process_data() -> AxionResult<DataFrame> {
 ///     // 处理逻辑
+///     let dataframe = DataFrame::new_empty();
 ///     Ok(dataframe)
 /// }
 /// ```
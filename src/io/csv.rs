@@ -1,10 +1,12 @@
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 use crate::dataframe::DataFrame;
-use crate::series::{Series, SeriesTrait};
+use crate::series::{CategoricalSeries, Series, SeriesTrait};
 use crate::AxionResult;
 use crate::AxionError;
-use crate::dtype::DataType;
+use crate::dtype::{DataType, TimeUnit};
+use crate::temporal::{parse_date, parse_datetime, parse_duration, Date, Datetime, Duration};
+use crate::decimal::{parse_decimal, Decimal};
 use std::fs::File;
 use std::path::Path;
 use std::collections::{HashMap, HashSet};
@@ -17,9 +19,9 @@ use std::io::{BufReader, BufRead};
 /// 
 /// # 示例
 /// 
-/// ```rust
-/// use axion::io::csv::{ReadCsvOptions, read_csv};
-/// use axion::dtype::DataType;
+/// ```rust,no_run
+/// use axion_data::io::csv::{ReadCsvOptions, read_csv};
+/// use axion_data::dtype::DataType;
 /// use std::collections::HashMap;
 /// 
 /// // 使用默认配置
@@ -32,6 +34,7 @@ use std::io::{BufReader, BufRead};
 ///     .infer_schema(true)
 ///     .build();
 /// let df2 = read_csv("data.csv", Some(options))?;
+/// # Ok::<(), axion_data::AxionError>(())
 /// ```
 #[derive(Debug, Clone)]
 pub struct ReadCsvOptions {
@@ -47,7 +50,9 @@ pub struct ReadCsvOptions {
     /// 如果为 `None`，则使用所有行进行推断
     pub infer_schema_length: Option<usize>,
     /// 可选的 HashMap，用于手动指定某些列的数据类型
-    /// 手动指定的类型将覆盖类型推断的结果
+    /// 手动指定的类型将覆盖类型推断的结果，指定为 `DataType::Categorical` 可直接读取为分类列
+    /// 指定为 `DataType::Decimal(precision, scale)` 可按定点小数精确读取金额列，
+    /// 小数位数多于 scale 或超出总位数的值读取为 null
     pub dtypes: Option<HashMap<String, DataType>>,
     /// 跳过文件开头的 N 行，默认为 `0`
     pub skip_rows: usize,
@@ -90,6 +95,7 @@ impl ReadCsvOptions {
 /// # 示例
 /// 
 /// ```rust
+/// # use axion_data::ReadCsvOptions;
 /// let options = ReadCsvOptions::builder()
 ///     .with_delimiter(b';')
 ///     .with_header(true)
@@ -254,6 +260,26 @@ fn infer_column_type(
     if sample.iter().all(|s| try_parse_bool(s).is_some()) {
         return DataType::Bool;
     }
+    if sample.iter().all(|s| parse_date(s).is_some()) {
+        return DataType::Date;
+    }
+    let parsed_datetimes: Option<Vec<(i64, bool)>> = sample
+        .iter()
+        .map(|s| parse_datetime(s, TimeUnit::Microseconds))
+        .collect();
+    if let Some(parsed) = parsed_datetimes {
+        // 全部带偏移时视为 UTC，全部不带时视为无时区，混合时保留为字符串
+        if parsed.iter().all(|(_, has_offset)| *has_offset) {
+            return DataType::Datetime(TimeUnit::Microseconds, Some("UTC".to_string()));
+        }
+        if parsed.iter().all(|(_, has_offset)| !*has_offset) {
+            return DataType::Datetime(TimeUnit::Microseconds, None);
+        }
+        return DataType::String;
+    }
+    if sample.iter().all(|s| parse_duration(s, TimeUnit::Microseconds).is_some()) {
+        return DataType::Duration(TimeUnit::Microseconds);
+    }
     DataType::String
 }
 
@@ -271,6 +297,20 @@ fn parse_column_as_type(
                 .collect();
             Ok(Box::new(Series::<i64>::new_from_options(column_name, parsed_data)))
         }
+        DataType::Int128 => {
+            let parsed_data: Vec<Option<i128>> = string_data
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(|s| s.parse::<i128>().ok()))
+                .collect();
+            Ok(Box::new(Series::<i128>::new_from_options(column_name, parsed_data)))
+        }
+        DataType::UInt128 => {
+            let parsed_data: Vec<Option<u128>> = string_data
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(|s| s.parse::<u128>().ok()))
+                .collect();
+            Ok(Box::new(Series::<u128>::new_from_options(column_name, parsed_data)))
+        }
         DataType::Float64 => {
             let parsed_data: Vec<Option<f64>> = string_data
                 .into_iter()
@@ -288,49 +328,54 @@ fn parse_column_as_type(
         DataType::String => {
             Ok(Box::new(Series::<String>::new_from_options(column_name, string_data)))
         }
+        DataType::Categorical => {
+            Ok(Box::new(CategoricalSeries::from_strings(column_name, string_data)))
+        }
+        DataType::Date => {
+            let parsed_data: Vec<Option<Date>> = string_data
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(|s| parse_date(&s)).map(Date))
+                .collect();
+            Ok(Box::new(Series::<Date>::new_from_options(column_name, parsed_data)))
+        }
+        DataType::Datetime(unit, _) => {
+            let parsed_data: Vec<Option<Datetime>> = string_data
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(|s| parse_datetime(&s, *unit)).map(|(value, _)| Datetime(value)))
+                .collect();
+            let series = Series::<Datetime>::new_from_options(column_name, parsed_data)
+                .with_dtype(target_type.clone())?;
+            Ok(Box::new(series))
+        }
+        DataType::Duration(unit) => {
+            let parsed_data: Vec<Option<Duration>> = string_data
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(|s| parse_duration(&s, *unit)).map(Duration))
+                .collect();
+            let series = Series::<Duration>::new_from_options(column_name, parsed_data)
+                .with_dtype(target_type.clone())?;
+            Ok(Box::new(series))
+        }
+        DataType::Decimal(precision, scale) => {
+            let parsed_data: Vec<Option<Decimal>> = string_data
+                .into_iter()
+                .map(|opt_s| opt_s.and_then(|s| parse_decimal(&s, *precision, *scale)).map(Decimal))
+                .collect();
+            let series = Series::<Decimal>::new_from_options(column_name, parsed_data)
+                .with_dtype(target_type.clone())?;
+            Ok(Box::new(series))
+        }
         dt => Err(AxionError::UnsupportedOperation(format!(
-            "无法将 CSV 列 '{}' 解析为类型 {:?}。CSV 解析仅支持 Int64、Float64、Bool、String 类型。",
+            "无法将 CSV 列 '{}' 解析为类型 {:?}。CSV 解析仅支持 Int64、Int128、UInt128、Float64、Bool、String、Categorical、Date、Datetime、Duration、Decimal 类型。",
             column_name, dt
         ))),
     }
 }
 
-/// 从 CSV 文件读取数据到 DataFrame
-/// 
-/// 支持自动类型推断、列选择、注释行处理等高级功能。
-/// 
-/// # 参数
-/// 
-/// * `filepath` - CSV 文件路径
-/// * `options` - 可选的读取配置，如果为 None 则使用默认配置
-/// 
-/// # 返回值
-/// 
-/// 成功时返回包含 CSV 数据的 DataFrame
-/// 
-/// # 错误
-/// 
-/// * `AxionError::IoError` - 文件读取失败
-/// * `AxionError::CsvError` - CSV 格式错误或解析失败
-/// 
-/// # 示例
-/// 
-/// ```rust
-/// // 使用默认配置读取
-/// let df = read_csv("data.csv", None)?;
-/// 
-/// // 使用自定义配置读取
-/// let options = ReadCsvOptions::builder()
-///     .with_delimiter(b';')
-///     .infer_schema(true)
-///     .build();
-/// let df = read_csv("data.csv", Some(options))?;
-/// ```
-pub fn read_csv(filepath: impl AsRef<Path>, options: Option<ReadCsvOptions>) -> AxionResult<DataFrame> {
-    let opts = options.unwrap_or_default();
-
-    let file = File::open(filepath.as_ref())
-        .map_err(|e| AxionError::IoError(format!("无法打开文件 {:?}: {}", filepath.as_ref(), e)))?;
+/// 打开 CSV 文件并跳过 `skip_rows` 行，返回逐条读取原始记录的迭代器（不解析表头）
+fn open_csv_records(filepath: &Path, opts: &ReadCsvOptions) -> AxionResult<csv::StringRecordsIntoIter<BufReader<File>>> {
+    let file = File::open(filepath)
+        .map_err(|e| AxionError::IoError(format!("无法打开文件 {:?}: {}", filepath, e)))?;
     
     let mut buf_reader = BufReader::new(file);
 
@@ -363,7 +408,59 @@ pub fn read_csv(filepath: impl AsRef<Path>, options: Option<ReadCsvOptions>) ->
     }
 
     let rdr = rdr_builder.from_reader(buf_reader); 
-    let mut records_iter = rdr.into_records();
+    Ok(rdr.into_records())
+}
+
+/// 读取 CSV 文件的列名，不读取数据行
+///
+/// 列名规则与 [`read_csv`] 一致：没有表头时生成 "column_0", "column_1", ...；
+/// 文件为空时返回空列表。`use_columns` 选项会被忽略。
+pub(crate) fn read_csv_header(filepath: &Path, opts: &ReadCsvOptions) -> AxionResult<Vec<String>> {
+    let mut records_iter = open_csv_records(filepath, opts)?;
+    match records_iter.next() {
+        Some(Ok(record)) if opts.has_header => Ok(record.iter().map(|s| s.to_string()).collect()),
+        Some(Ok(record)) => Ok((0..record.len()).map(|i| format!("column_{}", i)).collect()),
+        Some(Err(e)) => Err(AxionError::CsvError(format!("读取 CSV 表头失败: {}", e))),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// 从 CSV 文件读取数据到 DataFrame
+/// 
+/// 支持自动类型推断、列选择、注释行处理等高级功能。
+/// 
+/// # 参数
+/// 
+/// * `filepath` - CSV 文件路径
+/// * `options` - 可选的读取配置，如果为 None 则使用默认配置
+/// 
+/// # 返回值
+/// 
+/// 成功时返回包含 CSV 数据的 DataFrame
+/// 
+/// # 错误
+/// 
+/// * `AxionError::IoError` - 文件读取失败
+/// * `AxionError::CsvError` - CSV 格式错误或解析失败
+/// 
+/// # 示例
+/// 
+/// ```rust,no_run
+/// # use axion_data::{read_csv, ReadCsvOptions};
+/// // 使用默认配置读取
+/// let df = read_csv("data.csv", None)?;
+/// 
+/// // 使用自定义配置读取
+/// let options = ReadCsvOptions::builder()
+///     .with_delimiter(b';')
+///     .infer_schema(true)
+///     .build();
+/// let df = read_csv("data.csv", Some(options))?;
+/// # Ok::<(), axion_data::AxionError>(())
+/// ```
+pub fn read_csv(filepath: impl AsRef<Path>, options: Option<ReadCsvOptions>) -> AxionResult<DataFrame> {
+    let opts = options.unwrap_or_default();
+    let mut records_iter = open_csv_records(filepath.as_ref(), &opts)?;
 
     // 确定文件表头和第一行数据
     let original_file_headers: Vec<String>;
@@ -547,7 +644,7 @@ pub enum QuoteStyle {
 /// # 示例
 /// 
 /// ```rust
-/// use axion::io::csv::{WriteCsvOptions, QuoteStyle};
+/// use axion_data::io::csv::{WriteCsvOptions, QuoteStyle};
 /// 
 /// let options = WriteCsvOptions::builder()
 ///     .with_header(true)
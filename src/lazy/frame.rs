@@ -0,0 +1,301 @@
+use super::optimizer::optimize;
+use super::plan::{LogicalPlan, Predicate};
+use crate::dataframe::{Agg, DataFrame, GroupByOptions, JoinOptions, JoinType};
+use crate::error::{AxionError, AxionResult};
+use crate::io::{read_csv_header, ReadCsvOptions};
+use crate::series::{Series, SeriesTrait};
+use std::path::Path;
+use std::sync::Arc;
+
+/// 将 `&[&str]` 转换为列名列表
+fn to_strings(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+/// 惰性 DataFrame
+///
+/// 只记录 `select`、`filter`、`with_column`、分组聚合、`join`、`sort` 和 `limit` 等操作，
+/// 组成逻辑计划，直到调用 [`LazyFrame::collect`] 时才优化并执行。优化包括：
+///
+/// - **谓词下推** - 过滤被移到尽量靠近数据源的位置，减少后续步骤处理的行数
+/// - **投影下推** - 数据源只读取最终用到的列，CSV 文件通过 `use_columns` 只解析这些列
+///
+/// 由于过滤条件和新列以函数的形式给出，调用时需要同时声明函数读取的列，
+/// 优化器据此判断能否移动这些步骤；声明不完整时优化后的结果可能出错。
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// # use axion_data::dataframe::Agg;
+/// # use axion_data::{LazyFrame, SeriesCompareScalar};
+/// let result = LazyFrame::scan_csv("titanic.csv", None)?
+///     .filter(&["Age"], |df| df.downcast_column::<f64>("Age")?.gt_scalar(30.0))
+///     .groupby(&["Pclass"])
+///     .agg(&[("Fare", Agg::Mean, "avg_fare")])
+///     .sort(&["Pclass"], &[false])
+///     .collect()?;
+/// # Ok::<(), axion_data::AxionError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazyFrame {
+    plan: LogicalPlan,
+    predicate_pushdown: bool,
+    projection_pushdown: bool,
+}
+
+impl LazyFrame {
+    /// 由逻辑计划创建 LazyFrame，默认开启所有优化
+    fn from_plan(plan: LogicalPlan) -> Self {
+        LazyFrame { plan, predicate_pushdown: true, projection_pushdown: true }
+    }
+
+    /// 以当前计划为输入构建新的根节点，保留优化设置
+    fn map_plan<F>(self, f: F) -> Self
+    where
+        F: FnOnce(Box<LogicalPlan>) -> LogicalPlan,
+    {
+        LazyFrame { plan: f(Box::new(self.plan)), ..self }
+    }
+
+    /// 惰性读取 CSV 文件。
+    ///
+    /// 创建时只读取表头以确定列名，数据在 `collect` 时才读取，
+    /// 并且只解析查询用到的列。
+    ///
+    /// # 参数
+    ///
+    /// * `filepath` - CSV 文件路径
+    /// * `options` - 可选的 CSV 读取配置，其中的 `use_columns` 会限定可用的列
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::IoError` - 文件无法打开
+    /// * `AxionError::CsvError` - 表头读取失败，或 `use_columns` 中的列不存在
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// # use axion_data::LazyFrame;
+    /// let adults = LazyFrame::scan_csv("titanic.csv", None)?
+    ///     .select(&["Name", "Age"])
+    ///     .collect()?;
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn scan_csv(filepath: impl AsRef<Path>, options: Option<ReadCsvOptions>) -> AxionResult<LazyFrame> {
+        let options = options.unwrap_or_default();
+        let header = read_csv_header(filepath.as_ref(), &options)?;
+        let schema = match &options.use_columns {
+            Some(columns) => {
+                if let Some(missing) = columns.iter().find(|c| !header.contains(c)) {
+                    return Err(AxionError::CsvError(format!(
+                        "use_columns 中指定的列 '{}' 在 CSV 表头中未找到: {:?}",
+                        missing, header
+                    )));
+                }
+                columns.clone()
+            }
+            None => header,
+        };
+        Ok(LazyFrame::from_plan(LogicalPlan::CsvScan {
+            path: filepath.as_ref().to_path_buf(),
+            options,
+            schema,
+            projection: None,
+        }))
+    }
+
+    /// 设置是否启用谓词下推，默认启用
+    pub fn with_predicate_pushdown(mut self, enabled: bool) -> Self {
+        self.predicate_pushdown = enabled;
+        self
+    }
+
+    /// 设置是否启用投影下推，默认启用
+    pub fn with_projection_pushdown(mut self, enabled: bool) -> Self {
+        self.projection_pushdown = enabled;
+        self
+    }
+
+    /// 获取查询结果的列名，不执行查询
+    pub fn columns_names(&self) -> Vec<String> {
+        self.plan.schema()
+    }
+
+    /// 选择指定的列。
+    ///
+    /// # 参数
+    ///
+    /// * `columns` - 要选择的列名，结果按此顺序排列
+    pub fn select(self, columns: &[&str]) -> Self {
+        self.map_plan(|input| LogicalPlan::Select { input, columns: to_strings(columns) })
+    }
+
+    /// 根据布尔掩码过滤行。
+    ///
+    /// # 参数
+    ///
+    /// * `columns` - `predicate` 读取的列
+    /// * `predicate` - 根据当前 DataFrame 计算布尔掩码的函数，true 表示保留该行
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::SeriesCompareScalar;
+    /// # let lf = axion_data::df!("Survived" => vec![1_i64, 0])?.lazy();
+    /// let survivors = lf.filter(&["Survived"], |df| df.downcast_column::<i64>("Survived")?.eq_scalar(1));
+    /// # assert_eq!(survivors.collect()?.height(), 1);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn filter<F>(self, columns: &[&str], predicate: F) -> Self
+    where
+        F: Fn(&DataFrame) -> AxionResult<Series<bool>> + Send + Sync + 'static,
+    {
+        let predicate = Predicate { columns: to_strings(columns), func: Arc::new(predicate) };
+        self.map_plan(|input| LogicalPlan::Filter { input, predicate })
+    }
+
+    /// 添加新列，已存在同名列时替换该列。
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 新列名，`func` 返回的 Series 会被重命名为该名称
+    /// * `columns` - `func` 读取的列
+    /// * `func` - 根据当前 DataFrame 计算新列的函数，结果长度必须与行数一致
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::{SeriesArithScalar, SeriesTrait};
+    /// # let lf = axion_data::df!("Fare" => vec![7.25, 71.3])?.lazy();
+    /// let lf = lf.with_column("fare_x2", &["Fare"], |df| {
+    ///     Ok(Box::new(df.downcast_column::<f64>("Fare")?.mul_scalar(2.0)?) as Box<dyn SeriesTrait>)
+    /// });
+    /// # assert_eq!(lf.collect()?.width(), 2);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn with_column<F>(self, name: &str, columns: &[&str], func: F) -> Self
+    where
+        F: Fn(&DataFrame) -> AxionResult<Box<dyn SeriesTrait>> + Send + Sync + 'static,
+    {
+        self.map_plan(|input| LogicalPlan::WithColumn {
+            input,
+            name: name.to_string(),
+            columns: to_strings(columns),
+            func: Arc::new(func),
+        })
+    }
+
+    /// 按指定列分组，随后通过 [`LazyGroupBy::agg`] 指定聚合方式。
+    pub fn groupby(self, keys: &[&str]) -> LazyGroupBy {
+        self.groupby_with_options(keys, GroupByOptions::default())
+    }
+
+    /// 按指定列和分组选项分组，随后通过 [`LazyGroupBy::agg`] 指定聚合方式。
+    pub fn groupby_with_options(self, keys: &[&str], options: GroupByOptions) -> LazyGroupBy {
+        LazyGroupBy { frame: self, keys: to_strings(keys), options }
+    }
+
+    /// 与另一个 LazyFrame 连接，参数含义与 [`DataFrame::join`] 相同。
+    ///
+    /// 结果沿用本 LazyFrame 的优化设置。
+    pub fn join(
+        self,
+        other: LazyFrame,
+        left_on: &[&str],
+        right_on: &[&str],
+        how: JoinType,
+        options: Option<JoinOptions>,
+    ) -> Self {
+        self.map_plan(|left| LogicalPlan::Join {
+            left,
+            right: Box::new(other.plan),
+            left_on: to_strings(left_on),
+            right_on: to_strings(right_on),
+            how,
+            options: options.unwrap_or_default(),
+        })
+    }
+
+    /// 按指定列排序，参数含义与 [`DataFrame::sort`] 相同。
+    pub fn sort(self, by: &[&str], descending: &[bool]) -> Self {
+        self.map_plan(|input| LogicalPlan::Sort { input, by: to_strings(by), descending: descending.to_vec() })
+    }
+
+    /// 只保留前 `n` 行。
+    pub fn limit(self, n: usize) -> Self {
+        self.map_plan(|input| LogicalPlan::Limit { input, n })
+    }
+
+    /// 以缩进树的形式描述查询计划，根节点在最上方。
+    ///
+    /// # 参数
+    ///
+    /// * `optimized` - 为 `true` 时描述按当前设置优化后的计划
+    pub fn explain(&self, optimized: bool) -> String {
+        if optimized {
+            optimize(self.plan.clone(), self.predicate_pushdown, self.projection_pushdown).to_string()
+        } else {
+            self.plan.to_string()
+        }
+    }
+
+    /// 优化并执行查询。
+    ///
+    /// # 错误
+    ///
+    /// 返回执行过程中任一步骤产生的错误，与对应的 DataFrame 即时操作相同
+    pub fn collect(self) -> AxionResult<DataFrame> {
+        optimize(self.plan, self.predicate_pushdown, self.projection_pushdown).execute()
+    }
+}
+
+/// 惰性分组，由 [`LazyFrame::groupby`] 创建
+#[derive(Debug, Clone)]
+pub struct LazyGroupBy {
+    frame: LazyFrame,
+    keys: Vec<String>,
+    options: GroupByOptions,
+}
+
+impl LazyGroupBy {
+    /// 按列分别指定聚合方式，参数含义与 `GroupBy::agg` 相同。
+    ///
+    /// # 参数
+    ///
+    /// * `specs` - 聚合规格列表，每项为 `(输入列名, 聚合函数, 输出列名)`
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::dataframe::Agg;
+    /// # let lf = axion_data::df!("Pclass" => vec![1, 3, 3], "Fare" => vec![71.3, 7.25, 8.05])?.lazy();
+    /// let report = lf.groupby(&["Pclass"]).agg(&[("Fare", Agg::Mean, "avg_fare")]).collect()?;
+    /// # assert_eq!(report.height(), 2);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn agg(self, specs: &[(&str, Agg, &str)]) -> LazyFrame {
+        let aggs = specs
+            .iter()
+            .map(|(column, agg, output)| (column.to_string(), *agg, output.to_string()))
+            .collect();
+        let LazyGroupBy { frame, keys, options } = self;
+        frame.map_plan(|input| LogicalPlan::GroupByAgg { input, keys, aggs, options })
+    }
+}
+
+impl DataFrame {
+    /// 将 DataFrame 转换为 [`LazyFrame`]，后续操作在 `collect` 时才执行。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # let df = axion_data::df!("score" => vec![90, 75, 88])?;
+    /// let top = df.lazy().sort(&["score"], &[true]).limit(10).collect()?;
+    /// # assert_eq!(top.height(), 3);
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn lazy(self) -> LazyFrame {
+        let schema = self.columns_names().into_iter().map(str::to_string).collect();
+        LazyFrame::from_plan(LogicalPlan::DataFrameScan { df: Arc::new(self), schema, projection: None })
+    }
+}
@@ -0,0 +1,15 @@
+//! 惰性查询模块
+//!
+//! 提供 `LazyFrame`：先把查询记录为逻辑计划，优化后再一次性执行。
+//!
+//! # 模块组织
+//!
+//! - `frame` - LazyFrame 与 LazyGroupBy 的公开接口
+//! - `optimizer` - 谓词下推与投影下推
+//! - `plan` - 逻辑计划节点及其执行
+
+pub mod frame;
+mod optimizer;
+mod plan;
+
+pub use self::frame::{LazyFrame, LazyGroupBy};
@@ -0,0 +1,205 @@
+//! 逻辑计划优化器
+//!
+//! - 谓词下推：把过滤尽量移到靠近数据源的位置，让后续的排序、连接和分组处理更少的行；
+//!   `with_column` 的函数可能依赖整列，过滤不会越过它
+//! - 投影下推：自顶向下收集真正用到的列，数据源只读取这些列（CSV 通过 `use_columns`），
+//!   结果未用到的 `with_column` 节点会被移除
+
+use super::plan::{join_output_columns, JoinColumn, JoinSide, LogicalPlan, Predicate};
+use crate::dataframe::JoinType;
+use std::collections::HashSet;
+
+/// 依次应用谓词下推和投影下推
+pub(crate) fn optimize(plan: LogicalPlan, predicate_pushdown: bool, projection_pushdown: bool) -> LogicalPlan {
+    let plan = if predicate_pushdown { push_down_predicates(plan, Vec::new()) } else { plan };
+    if projection_pushdown { push_down_projection(plan, None) } else { plan }
+}
+
+/// 在节点之上重新放置无法继续下推的过滤
+///
+/// `predicates` 按自顶向下的收集顺序排列，因此逆序应用以保持原有的执行顺序。
+fn apply_predicates(plan: LogicalPlan, predicates: Vec<Predicate>) -> LogicalPlan {
+    predicates.into_iter().rev().fold(plan, |input, predicate| LogicalPlan::Filter {
+        input: Box::new(input),
+        predicate,
+    })
+}
+
+/// 按条件把过滤分为可以下推的和必须留在当前节点之上的两组
+fn partition_predicates<F>(predicates: Vec<Predicate>, can_push: F) -> (Vec<Predicate>, Vec<Predicate>)
+where
+    F: Fn(&Predicate) -> bool,
+{
+    predicates.into_iter().partition(|predicate| can_push(predicate))
+}
+
+/// 判断过滤读取的列是否全部来自连接的同一侧且未被重命名，是则返回该侧
+fn predicate_join_side(predicate: &Predicate, outputs: &[JoinColumn]) -> Option<JoinSide> {
+    let mut side = None;
+    for column in &predicate.columns {
+        let (_, column_side, _) = outputs.iter().find(|(name, _, source)| name == column && source == column)?;
+        if side.is_some_and(|side| side != *column_side) {
+            return None;
+        }
+        side = Some(*column_side);
+    }
+    side
+}
+
+fn push_down_predicates(plan: LogicalPlan, mut predicates: Vec<Predicate>) -> LogicalPlan {
+    match plan {
+        LogicalPlan::Filter { input, predicate } => {
+            predicates.push(predicate);
+            push_down_predicates(*input, predicates)
+        }
+        LogicalPlan::Select { input, columns } => {
+            // 读取了未选择列的过滤留在原处，执行时与即时计算一样报告列不存在
+            let (pushable, blocked) =
+                partition_predicates(predicates, |predicate| predicate.columns.iter().all(|c| columns.contains(c)));
+            let input = Box::new(push_down_predicates(*input, pushable));
+            apply_predicates(LogicalPlan::Select { input, columns }, blocked)
+        }
+        LogicalPlan::WithColumn { input, name, columns, func } => {
+            // 新列的函数可能依赖整列（如减去均值），先过滤会改变它看到的行，过滤不能越过 WithColumn
+            let input = Box::new(push_down_predicates(*input, Vec::new()));
+            apply_predicates(LogicalPlan::WithColumn { input, name, columns, func }, predicates)
+        }
+        LogicalPlan::GroupByAgg { input, keys, aggs, options } => {
+            // 只读取分组键的过滤等价于先过滤行再分组
+            let (pushable, blocked) = partition_predicates(predicates, |predicate| {
+                !predicate.columns.is_empty() && predicate.columns.iter().all(|c| keys.contains(c))
+            });
+            let input = Box::new(push_down_predicates(*input, pushable));
+            apply_predicates(LogicalPlan::GroupByAgg { input, keys, aggs, options }, blocked)
+        }
+        LogicalPlan::Join { left, right, left_on, right_on, how, options } => {
+            let outputs = join_output_columns(&left.schema(), &right.schema(), &left_on, &right_on, how, &options);
+            let mut left_predicates = Vec::new();
+            let mut right_predicates = Vec::new();
+            let mut blocked = Vec::new();
+            for predicate in predicates {
+                // 只有不会因为未匹配而补 null 的一侧才能先过滤
+                match predicate_join_side(&predicate, &outputs) {
+                    Some(JoinSide::Left) if matches!(how, JoinType::Inner | JoinType::Left | JoinType::Semi | JoinType::Anti) => {
+                        left_predicates.push(predicate)
+                    }
+                    Some(JoinSide::Right) if matches!(how, JoinType::Inner | JoinType::Right) => right_predicates.push(predicate),
+                    _ => blocked.push(predicate),
+                }
+            }
+            let left = Box::new(push_down_predicates(*left, left_predicates));
+            let right = Box::new(push_down_predicates(*right, right_predicates));
+            apply_predicates(LogicalPlan::Join { left, right, left_on, right_on, how, options }, blocked)
+        }
+        LogicalPlan::Sort { input, by, descending } => {
+            let input = Box::new(push_down_predicates(*input, predicates));
+            LogicalPlan::Sort { input, by, descending }
+        }
+        LogicalPlan::Limit { input, n } => {
+            // 先取前 n 行再过滤与先过滤再取前 n 行结果不同，过滤不能越过 Limit
+            let input = Box::new(push_down_predicates(*input, Vec::new()));
+            apply_predicates(LogicalPlan::Limit { input, n }, predicates)
+        }
+        scan @ (LogicalPlan::DataFrameScan { .. } | LogicalPlan::CsvScan { .. }) => apply_predicates(scan, predicates),
+    }
+}
+
+/// 将列名加入需要的列集合，`None` 表示需要全部列
+fn require(required: &Option<HashSet<String>>, columns: &[String]) -> Option<HashSet<String>> {
+    required.as_ref().map(|required| required.iter().chain(columns).cloned().collect())
+}
+
+/// 按数据源的列顺序保留需要的列，全部需要时返回 `None`
+///
+/// 没有任何列被用到时仍保留第一列，以保证结果的行数不变。
+fn scan_projection(schema: &[String], projection: Option<Vec<String>>, required: Option<HashSet<String>>) -> Option<Vec<String>> {
+    let available = projection.unwrap_or_else(|| schema.to_vec());
+    let columns = match required {
+        Some(required) => {
+            let columns: Vec<String> = available.iter().filter(|c| required.contains(*c)).cloned().collect();
+            if columns.is_empty() { available.into_iter().take(1).collect() } else { columns }
+        }
+        None => available,
+    };
+    (columns.len() < schema.len()).then_some(columns)
+}
+
+fn push_down_projection(plan: LogicalPlan, required: Option<HashSet<String>>) -> LogicalPlan {
+    match plan {
+        LogicalPlan::DataFrameScan { df, schema, projection } => {
+            let projection = scan_projection(&schema, projection, required);
+            LogicalPlan::DataFrameScan { df, schema, projection }
+        }
+        LogicalPlan::CsvScan { path, options, schema, projection } => {
+            let projection = scan_projection(&schema, projection, required);
+            LogicalPlan::CsvScan { path, options, schema, projection }
+        }
+        LogicalPlan::Select { input, columns } => {
+            let columns: Vec<String> = match required {
+                Some(required) => columns.into_iter().filter(|c| required.contains(c)).collect(),
+                None => columns,
+            };
+            let input = Box::new(push_down_projection(*input, Some(columns.iter().cloned().collect())));
+            LogicalPlan::Select { input, columns }
+        }
+        LogicalPlan::Filter { input, predicate } => {
+            let input_required = require(&required, &predicate.columns);
+            let input = Box::new(push_down_projection(*input, input_required));
+            LogicalPlan::Filter { input, predicate }
+        }
+        LogicalPlan::WithColumn { input, name, columns, func } => {
+            if required.as_ref().is_some_and(|required| !required.contains(&name)) {
+                // 新列没有被用到，整个节点可以省略
+                return push_down_projection(*input, required);
+            }
+            let input_required = required.map(|mut required| {
+                required.remove(&name);
+                required.extend(columns.iter().cloned());
+                required
+            });
+            let input = Box::new(push_down_projection(*input, input_required));
+            LogicalPlan::WithColumn { input, name, columns, func }
+        }
+        LogicalPlan::GroupByAgg { input, keys, aggs, options } => {
+            let input_required = keys.iter().chain(aggs.iter().map(|(column, _, _)| column)).cloned().collect();
+            let input = Box::new(push_down_projection(*input, Some(input_required)));
+            LogicalPlan::GroupByAgg { input, keys, aggs, options }
+        }
+        LogicalPlan::Join { left, right, left_on, right_on, how, options } => {
+            let (left_required, right_required) = match required {
+                Some(required) => {
+                    let outputs = join_output_columns(&left.schema(), &right.schema(), &left_on, &right_on, how, &options);
+                    let mut left_required: HashSet<String> = left_on.iter().cloned().collect();
+                    let mut right_required: HashSet<String> = right_on.iter().cloned().collect();
+                    for (name, side, source) in outputs {
+                        if !required.contains(&name) {
+                            continue;
+                        }
+                        // 被加了后缀的列需要同时保留另一侧的同名列，否则输出列名会改变
+                        let renamed = name != source;
+                        if side == JoinSide::Left || renamed {
+                            left_required.insert(source.clone());
+                        }
+                        if side == JoinSide::Right || renamed {
+                            right_required.insert(source);
+                        }
+                    }
+                    (Some(left_required), Some(right_required))
+                }
+                None => (None, None),
+            };
+            let left = Box::new(push_down_projection(*left, left_required));
+            let right = Box::new(push_down_projection(*right, right_required));
+            LogicalPlan::Join { left, right, left_on, right_on, how, options }
+        }
+        LogicalPlan::Sort { input, by, descending } => {
+            let input_required = require(&required, &by);
+            let input = Box::new(push_down_projection(*input, input_required));
+            LogicalPlan::Sort { input, by, descending }
+        }
+        LogicalPlan::Limit { input, n } => {
+            let input = Box::new(push_down_projection(*input, required));
+            LogicalPlan::Limit { input, n }
+        }
+    }
+}
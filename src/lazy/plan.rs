@@ -0,0 +1,279 @@
+//! 逻辑计划模块
+//!
+//! 以树的形式记录惰性查询的各个步骤，叶子节点为数据源（内存中的 DataFrame 或 CSV 文件），
+//! 执行时自底向上调用对应的 DataFrame 即时操作。
+
+use crate::dataframe::{Agg, DataFrame, GroupByOptions, JoinOptions, JoinType};
+use crate::error::AxionResult;
+use crate::io::{read_csv, ReadCsvOptions};
+use crate::series::{Series, SeriesTrait};
+use std::fmt::{self, Debug, Display};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// 计算过滤掩码的函数
+pub(crate) type PredicateFn = dyn Fn(&DataFrame) -> AxionResult<Series<bool>> + Send + Sync;
+
+/// 计算新列的函数
+pub(crate) type ColumnFn = dyn Fn(&DataFrame) -> AxionResult<Box<dyn SeriesTrait>> + Send + Sync;
+
+/// 过滤条件
+///
+/// 除计算掩码的函数外还记录了它读取的列，优化器据此判断过滤能否移到其他节点之下。
+#[derive(Clone)]
+pub(crate) struct Predicate {
+    /// 计算掩码时读取的列
+    pub(crate) columns: Vec<String>,
+    pub(crate) func: Arc<PredicateFn>,
+}
+
+/// 连接结果中某一列的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JoinSide {
+    Left,
+    Right,
+}
+
+/// 连接结果中的一列：`(输出列名, 来源一侧, 来源列名)`
+pub(crate) type JoinColumn = (String, JoinSide, String);
+
+/// 逻辑计划节点
+#[derive(Clone)]
+pub(crate) enum LogicalPlan {
+    /// 读取内存中的 DataFrame，`projection` 为需要保留的列（`None` 表示全部）
+    DataFrameScan {
+        df: Arc<DataFrame>,
+        schema: Vec<String>,
+        projection: Option<Vec<String>>,
+    },
+    /// 读取 CSV 文件，`projection` 会作为 `use_columns` 传给 `read_csv`
+    CsvScan {
+        path: PathBuf,
+        options: ReadCsvOptions,
+        schema: Vec<String>,
+        projection: Option<Vec<String>>,
+    },
+    Select {
+        input: Box<LogicalPlan>,
+        columns: Vec<String>,
+    },
+    Filter {
+        input: Box<LogicalPlan>,
+        predicate: Predicate,
+    },
+    /// 添加或替换一列，`columns` 为计算新列时读取的列
+    WithColumn {
+        input: Box<LogicalPlan>,
+        name: String,
+        columns: Vec<String>,
+        func: Arc<ColumnFn>,
+    },
+    /// 分组聚合，`aggs` 的每项为 `(输入列名, 聚合函数, 输出列名)`
+    GroupByAgg {
+        input: Box<LogicalPlan>,
+        keys: Vec<String>,
+        aggs: Vec<(String, Agg, String)>,
+        options: GroupByOptions,
+    },
+    Join {
+        left: Box<LogicalPlan>,
+        right: Box<LogicalPlan>,
+        left_on: Vec<String>,
+        right_on: Vec<String>,
+        how: JoinType,
+        options: JoinOptions,
+    },
+    Sort {
+        input: Box<LogicalPlan>,
+        by: Vec<String>,
+        descending: Vec<bool>,
+    },
+    Limit {
+        input: Box<LogicalPlan>,
+        n: usize,
+    },
+}
+
+/// 将列名列表转换为 `&str` 切片所需的形式
+fn as_strs(names: &[String]) -> Vec<&str> {
+    names.iter().map(String::as_str).collect()
+}
+
+/// 按 `hash_join` 的规则计算连接结果的列及其来源
+///
+/// 半连接和反连接只输出左侧列；右连接时右侧列在前，左侧非键列在后并在重名时添加 `left_suffix`；
+/// 其余连接类型左侧列在前，右侧非键列在后并在重名时添加 `right_suffix`。
+pub(crate) fn join_output_columns(
+    left: &[String],
+    right: &[String],
+    left_on: &[String],
+    right_on: &[String],
+    how: JoinType,
+    options: &JoinOptions,
+) -> Vec<JoinColumn> {
+    if matches!(how, JoinType::Semi | JoinType::Anti) {
+        return left.iter().map(|name| (name.clone(), JoinSide::Left, name.clone())).collect();
+    }
+    let (primary, primary_side, secondary, secondary_side, secondary_on, suffix) = if how == JoinType::Right {
+        (right, JoinSide::Right, left, JoinSide::Left, left_on, options.left_suffix.as_str())
+    } else {
+        (left, JoinSide::Left, right, JoinSide::Right, right_on, options.right_suffix.as_str())
+    };
+
+    let mut output: Vec<JoinColumn> = primary.iter().map(|name| (name.clone(), primary_side, name.clone())).collect();
+    for name in secondary {
+        if secondary_on.contains(name) {
+            continue;
+        }
+        let output_name = if primary.contains(name) { format!("{}{}", name, suffix) } else { name.clone() };
+        output.push((output_name, secondary_side, name.clone()));
+    }
+    output
+}
+
+impl LogicalPlan {
+    /// 获取该节点输出的列名
+    pub(crate) fn schema(&self) -> Vec<String> {
+        match self {
+            LogicalPlan::DataFrameScan { schema, projection, .. } | LogicalPlan::CsvScan { schema, projection, .. } => {
+                projection.clone().unwrap_or_else(|| schema.clone())
+            }
+            LogicalPlan::Select { columns, .. } => columns.clone(),
+            LogicalPlan::Filter { input, .. } | LogicalPlan::Sort { input, .. } | LogicalPlan::Limit { input, .. } => {
+                input.schema()
+            }
+            LogicalPlan::WithColumn { input, name, .. } => {
+                let mut schema = input.schema();
+                if !schema.contains(name) {
+                    schema.push(name.clone());
+                }
+                schema
+            }
+            LogicalPlan::GroupByAgg { keys, aggs, .. } => {
+                keys.iter().cloned().chain(aggs.iter().map(|(_, _, output)| output.clone())).collect()
+            }
+            LogicalPlan::Join { left, right, left_on, right_on, how, options } => {
+                join_output_columns(&left.schema(), &right.schema(), left_on, right_on, *how, options)
+                    .into_iter()
+                    .map(|(name, _, _)| name)
+                    .collect()
+            }
+        }
+    }
+
+    /// 自底向上执行计划，返回结果 DataFrame
+    pub(crate) fn execute(self) -> AxionResult<DataFrame> {
+        match self {
+            LogicalPlan::DataFrameScan { df, projection, .. } => match projection {
+                Some(columns) => df.select(&as_strs(&columns)),
+                // 计划独占该 DataFrame 时直接取出，避免复制
+                None => Ok(Arc::try_unwrap(df).unwrap_or_else(|df| df.as_ref().clone())),
+            },
+            LogicalPlan::CsvScan { path, mut options, projection, .. } => {
+                if projection.is_some() {
+                    options.use_columns = projection;
+                }
+                read_csv(&path, Some(options))
+            }
+            LogicalPlan::Select { input, columns } => input.execute()?.select(&as_strs(&columns)),
+            LogicalPlan::Filter { input, predicate } => {
+                let df = input.execute()?;
+                let mask = (predicate.func)(&df)?;
+                df.filter(&mask)
+            }
+            LogicalPlan::WithColumn { input, name, func, .. } => {
+                let mut df = input.execute()?;
+                let mut series = func(&df)?;
+                series.rename(&name);
+                df.with_column(series)?;
+                Ok(df)
+            }
+            LogicalPlan::GroupByAgg { input, keys, aggs, options } => {
+                let df = input.execute()?;
+                let specs: Vec<(&str, Agg, &str)> = aggs
+                    .iter()
+                    .map(|(column, agg, output)| (column.as_str(), *agg, output.as_str()))
+                    .collect();
+                df.groupby_with_options(&as_strs(&keys), options)?.agg(&specs)
+            }
+            LogicalPlan::Join { left, right, left_on, right_on, how, options } => {
+                let left = left.execute()?;
+                let right = right.execute()?;
+                left.join(&right, &as_strs(&left_on), &as_strs(&right_on), how, Some(options))
+            }
+            LogicalPlan::Sort { input, by, descending } => input.execute()?.sort(&as_strs(&by), &descending),
+            LogicalPlan::Limit { input, n } => Ok(input.execute()?.head(n)),
+        }
+    }
+
+    /// 以缩进树的形式输出计划，子节点比父节点多缩进两个空格
+    fn fmt_indent(&self, f: &mut fmt::Formatter<'_>, indent: usize) -> fmt::Result {
+        write!(f, "{:indent$}", "", indent = indent)?;
+        match self {
+            LogicalPlan::DataFrameScan { schema, projection, .. } => {
+                write!(f, "DATAFRAME SCAN [{}]", schema.join(", "))?;
+                if let Some(columns) = projection {
+                    write!(f, "; PROJECT [{}]", columns.join(", "))?;
+                }
+                writeln!(f)
+            }
+            LogicalPlan::CsvScan { path, projection, .. } => {
+                write!(f, "CSV SCAN {}", path.display())?;
+                if let Some(columns) = projection {
+                    write!(f, "; PROJECT [{}]", columns.join(", "))?;
+                }
+                writeln!(f)
+            }
+            LogicalPlan::Select { input, columns } => {
+                writeln!(f, "SELECT [{}]", columns.join(", "))?;
+                input.fmt_indent(f, indent + 2)
+            }
+            LogicalPlan::Filter { input, predicate } => {
+                writeln!(f, "FILTER ON [{}]", predicate.columns.join(", "))?;
+                input.fmt_indent(f, indent + 2)
+            }
+            LogicalPlan::WithColumn { input, name, columns, .. } => {
+                writeln!(f, "WITH COLUMN {} FROM [{}]", name, columns.join(", "))?;
+                input.fmt_indent(f, indent + 2)
+            }
+            LogicalPlan::GroupByAgg { input, keys, aggs, .. } => {
+                let aggs: Vec<String> = aggs
+                    .iter()
+                    .map(|(column, agg, output)| format!("{:?}({}) AS {}", agg, column, output))
+                    .collect();
+                writeln!(f, "GROUP BY [{}] AGG [{}]", keys.join(", "), aggs.join(", "))?;
+                input.fmt_indent(f, indent + 2)
+            }
+            LogicalPlan::Join { left, right, left_on, right_on, how, .. } => {
+                writeln!(f, "{:?} JOIN ON [{}] = [{}]", how, left_on.join(", "), right_on.join(", "))?;
+                left.fmt_indent(f, indent + 2)?;
+                right.fmt_indent(f, indent + 2)
+            }
+            LogicalPlan::Sort { input, by, descending } => {
+                let keys: Vec<String> = by
+                    .iter()
+                    .zip(descending)
+                    .map(|(name, desc)| if *desc { format!("{} DESC", name) } else { name.clone() })
+                    .collect();
+                writeln!(f, "SORT BY [{}]", keys.join(", "))?;
+                input.fmt_indent(f, indent + 2)
+            }
+            LogicalPlan::Limit { input, n } => {
+                writeln!(f, "LIMIT {}", n)?;
+                input.fmt_indent(f, indent + 2)
+            }
+        }
+    }
+}
+
+impl Display for LogicalPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indent(f, 0)
+    }
+}
+
+impl Debug for LogicalPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
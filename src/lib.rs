@@ -3,9 +3,15 @@ pub mod series;
 pub mod dataframe;
 pub mod error;
 pub mod io;
+pub mod temporal;
+pub mod decimal;
+pub mod lazy;
 
 pub use crate::dtype::*;
 pub use crate::error::*;
-pub use crate::dataframe::DataFrame;
+pub use crate::dataframe::{concat, ConcatType, DataFrame};
 pub use crate::series::*;
-pub use crate::io::*;
\ No newline at end of file
+pub use crate::io::*;
+pub use crate::temporal::{Date, Datetime, Duration};
+pub use crate::decimal::Decimal;
+pub use crate::lazy::{LazyFrame, LazyGroupBy};
\ No newline at end of file
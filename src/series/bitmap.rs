@@ -0,0 +1,222 @@
+//! 有效性位图模块
+//!
+//! 提供紧凑的位图结构，用于标记 Series 中每个位置的值是否有效（非 null）。
+//! 每个元素只占用 1 位，相比 `Vec<Option<T>>` 可以显著降低内存占用。
+
+use std::fmt::{self, Debug};
+use std::iter::FromIterator;
+
+/// 紧凑存储的位图
+///
+/// 按位记录布尔值，第 `i` 位存储在第 `i / 8` 个字节的第 `i % 8` 位上。
+/// 在 Series 中，置位（`true`）表示该位置的值有效，未置位表示 null。
+///
+/// # 特性
+///
+/// - 每个元素仅占用 1 位
+/// - 缓存未置位数量，`unset_count` 为 O(1) 操作
+/// - 支持追加、随机读写和切片
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Bitmap {
+    /// 按字节打包的位数据
+    bytes: Vec<u8>,
+    /// 位图的逻辑长度（位数）
+    len: usize,
+    /// 未置位（null）的数量
+    unset_count: usize,
+}
+
+impl Bitmap {
+    /// 创建一个空的位图
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 创建一个预分配容量的空位图
+    ///
+    /// # 参数
+    ///
+    /// * `capacity` - 预计容纳的位数
+    pub fn with_capacity(capacity: usize) -> Self {
+        Bitmap {
+            bytes: Vec::with_capacity(capacity.div_ceil(8)),
+            len: 0,
+            unset_count: 0,
+        }
+    }
+
+    /// 创建一个所有位都为指定值的位图
+    ///
+    /// # 参数
+    ///
+    /// * `len` - 位图长度
+    /// * `value` - 所有位的初始值
+    pub fn new_with_value(len: usize, value: bool) -> Self {
+        let fill = if value { 0xFF } else { 0x00 };
+        let mut bytes = vec![fill; len.div_ceil(8)];
+        // 保证末尾多余的位始终为 0，便于逐字节比较
+        if value && !len.is_multiple_of(8) {
+            if let Some(last) = bytes.last_mut() {
+                *last = (1u8 << (len % 8)) - 1;
+            }
+        }
+        Bitmap {
+            bytes,
+            len,
+            unset_count: if value { 0 } else { len },
+        }
+    }
+
+    /// 获取位图长度
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 检查位图是否为空
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 获取未置位的数量
+    pub fn unset_count(&self) -> usize {
+        self.unset_count
+    }
+
+    /// 获取置位的数量
+    pub fn set_count(&self) -> usize {
+        self.len - self.unset_count
+    }
+
+    /// 获取指定位置的值
+    ///
+    /// # Panics
+    ///
+    /// 如果索引超出范围会 panic
+    #[inline]
+    pub fn get(&self, index: usize) -> bool {
+        assert!(index < self.len, "Bitmap index {} out of bounds (len {})", index, self.len);
+        (self.bytes[index >> 3] >> (index & 7)) & 1 == 1
+    }
+
+    /// 设置指定位置的值
+    ///
+    /// # Panics
+    ///
+    /// 如果索引超出范围会 panic
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        let old = self.get(index);
+        if old == value {
+            return;
+        }
+        let mask = 1u8 << (index & 7);
+        if value {
+            self.bytes[index >> 3] |= mask;
+            self.unset_count -= 1;
+        } else {
+            self.bytes[index >> 3] &= !mask;
+            self.unset_count += 1;
+        }
+    }
+
+    /// 在位图末尾追加一位
+    #[inline]
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if value {
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= 1u8 << (self.len & 7);
+        } else {
+            self.unset_count += 1;
+        }
+        self.len += 1;
+    }
+
+    /// 将另一个位图的所有位追加到末尾
+    pub fn extend_from_bitmap(&mut self, other: &Bitmap) {
+        self.bytes.reserve(other.len.div_ceil(8));
+        for value in other.iter() {
+            self.push(value);
+        }
+    }
+
+    /// 截取 `[start, end)` 范围内的位，返回新的位图
+    ///
+    /// # Panics
+    ///
+    /// 如果 `start > end` 或 `end` 超出范围会 panic
+    pub fn slice(&self, start: usize, end: usize) -> Bitmap {
+        assert!(start <= end && end <= self.len, "Bitmap slice [{}, {}) out of bounds (len {})", start, end, self.len);
+        if start.is_multiple_of(8) {
+            let mut bytes = self.bytes[start / 8..(end.div_ceil(8))].to_vec();
+            let len = end - start;
+            if !len.is_multiple_of(8) {
+                if let Some(last) = bytes.last_mut() {
+                    *last &= (1u8 << (len % 8)) - 1;
+                }
+            }
+            let unset_count = len - bytes.iter().map(|b| b.count_ones() as usize).sum::<usize>();
+            return Bitmap { bytes, len, unset_count };
+        }
+        (start..end).map(|i| self.get(i)).collect()
+    }
+
+    /// 返回遍历所有位的迭代器
+    pub fn iter(&self) -> BitmapIter<'_> {
+        BitmapIter { bitmap: self, index: 0 }
+    }
+
+    /// 获取底层字节数据
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Debug for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bitmap")
+            .field("len", &self.len)
+            .field("unset_count", &self.unset_count)
+            .finish()
+    }
+}
+
+impl FromIterator<bool> for Bitmap {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut bitmap = Bitmap::with_capacity(iter.size_hint().0);
+        for value in iter {
+            bitmap.push(value);
+        }
+        bitmap
+    }
+}
+
+/// 位图迭代器
+pub struct BitmapIter<'a> {
+    bitmap: &'a Bitmap,
+    index: usize,
+}
+
+impl Iterator for BitmapIter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.index >= self.bitmap.len {
+            return None;
+        }
+        let value = self.bitmap.get(self.index);
+        self.index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.bitmap.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitmapIter<'_> {}
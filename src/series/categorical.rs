@@ -0,0 +1,297 @@
+use crate::dtype::DataType;
+use crate::error::{AxionError, AxionResult};
+use super::core::Series;
+use super::interface::SeriesTrait;
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::sync::Arc;
+
+/// 分类类型的字典，保存所有类别及其编码
+///
+/// 编码即类别在 `categories` 中的位置，按首次出现的顺序分配。
+#[derive(Debug, Clone, Default)]
+pub struct CategoricalDictionary {
+    categories: Vec<String>,
+    lookup: HashMap<String, u32>,
+}
+
+impl CategoricalDictionary {
+    /// 获取所有类别，下标即编码
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    /// 获取类别对应的编码
+    pub fn code_of(&self, category: &str) -> Option<u32> {
+        self.lookup.get(category).copied()
+    }
+
+    /// 获取编码对应的类别
+    pub fn category(&self, code: u32) -> Option<&str> {
+        self.categories.get(code as usize).map(|s| s.as_str())
+    }
+
+    /// 获取类别的编码，不存在时追加到字典末尾
+    fn get_or_insert(&mut self, category: &str) -> u32 {
+        if let Some(&code) = self.lookup.get(category) {
+            return code;
+        }
+        let code = self.categories.len() as u32;
+        self.categories.push(category.to_string());
+        self.lookup.insert(category.to_string(), code);
+        code
+    }
+}
+
+/// 分类（字典编码）类型的 Series
+///
+/// 每行只存储一个 `u32` 编码，类别字符串保存在共享的字典中。
+/// 由同一个 Series 切片、过滤或选取得到的 Series 共享同一个字典，
+/// 适合 `Sex`、`Embarked` 这类取值很少的字符串列，可以显著减少内存并加速分组和连接。
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::{CategoricalSeries, DataType, SeriesTrait};
+/// let sex = CategoricalSeries::from_strings("Sex".to_string(), vec![Some("male"), Some("female"), None]);
+/// assert_eq!(sex.categories(), &["male".to_string(), "female".to_string()]);
+/// let as_strings = sex.cast(DataType::String)?;
+/// # Ok::<(), axion_data::AxionError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct CategoricalSeries {
+    /// 每行的编码，null 表示缺失值，名称即 Series 名称
+    codes: Series<u32>,
+    /// 共享的类别字典
+    dictionary: Arc<CategoricalDictionary>,
+}
+
+impl CategoricalSeries {
+    /// 由字符串值创建分类 Series，类别按首次出现的顺序编码
+    ///
+    /// # 参数
+    ///
+    /// * `name` - Series 名称
+    /// * `values` - 字符串值，`None` 表示 null
+    pub fn from_strings<I, S>(name: String, values: I) -> Self
+    where
+        I: IntoIterator<Item = Option<S>>,
+        S: AsRef<str>,
+    {
+        let mut dictionary = CategoricalDictionary::default();
+        let codes: Vec<Option<u32>> = values
+            .into_iter()
+            .map(|opt| opt.map(|s| dictionary.get_or_insert(s.as_ref())))
+            .collect();
+        CategoricalSeries {
+            codes: Series::new_from_options(name, codes),
+            dictionary: Arc::new(dictionary),
+        }
+    }
+
+    /// 由字符串 Series 创建分类 Series
+    pub fn from_string_series(series: &Series<String>) -> Self {
+        Self::from_strings(series.name().to_string(), series.iter())
+    }
+
+    /// 获取每行的编码
+    pub fn codes(&self) -> &Series<u32> {
+        &self.codes
+    }
+
+    /// 获取所有类别，下标即编码
+    pub fn categories(&self) -> &[String] {
+        self.dictionary.categories()
+    }
+
+    /// 获取共享的类别字典
+    pub fn dictionary(&self) -> &Arc<CategoricalDictionary> {
+        &self.dictionary
+    }
+
+    /// 检查两个分类 Series 是否共享同一个字典（此时编码可以直接比较）
+    pub fn same_dictionary(&self, other: &CategoricalSeries) -> bool {
+        Arc::ptr_eq(&self.dictionary, &other.dictionary)
+    }
+
+    /// 获取指定索引处的类别，null 或越界时返回 `None`
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.codes
+            .get_opt(index)
+            .flatten()
+            .and_then(|code| self.dictionary.category(*code))
+    }
+
+    /// 遍历所有值，null 位置产出 `None`
+    pub fn iter(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        self.codes
+            .iter()
+            .map(|opt| opt.and_then(|code| self.dictionary.category(*code)))
+    }
+
+    /// 解码为字符串 Series
+    pub fn to_string_series(&self) -> Series<String> {
+        let values: Vec<Option<String>> = self.iter().map(|opt| opt.map(str::to_string)).collect();
+        Series::new_from_options(self.codes.name().to_string(), values)
+    }
+
+    /// 将另一个分类 Series 的所有元素追加到末尾
+    ///
+    /// 两者字典不同时，对方的类别会被合并到本 Series 的字典中（不影响共享原字典的其他 Series）。
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 要追加的分类 Series，其名称会被忽略
+    pub fn append(&mut self, other: &CategoricalSeries) {
+        if self.same_dictionary(other) {
+            self.codes.append(&other.codes);
+            return;
+        }
+        let dictionary = Arc::make_mut(&mut self.dictionary);
+        let recoded: Vec<Option<u32>> = other
+            .iter()
+            .map(|opt| opt.map(|category| dictionary.get_or_insert(category)))
+            .collect();
+        self.codes.extend(recoded);
+    }
+
+    /// 用新的编码构建共享同一字典的分类 Series
+    fn with_codes(&self, codes: Box<dyn SeriesTrait>) -> AxionResult<Box<dyn SeriesTrait>> {
+        let codes = codes.as_any().downcast_ref::<Series<u32>>().cloned().ok_or_else(|| {
+            AxionError::InternalError(format!("分类 Series '{}' 的编码类型错误", self.name()))
+        })?;
+        Ok(Box::new(CategoricalSeries { codes, dictionary: Arc::clone(&self.dictionary) }))
+    }
+}
+
+impl SeriesTrait for CategoricalSeries {
+    fn name(&self) -> &str {
+        self.codes.name()
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Categorical
+    }
+
+    fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn SeriesTrait> {
+        Box::new(self.clone())
+    }
+
+    fn get_str(&self, index: usize) -> Option<String> {
+        self.codes.get_opt(index).map(|opt_code| {
+            opt_code
+                .and_then(|code| self.dictionary.category(*code))
+                .unwrap_or("null")
+                .to_string()
+        })
+    }
+
+    fn is_null_at(&self, index: usize) -> bool {
+        self.codes.is_null_at(index)
+    }
+
+    fn slice(&self, start: usize, length: usize) -> Box<dyn SeriesTrait> {
+        let codes = self.codes.slice(start, length);
+        let codes = codes.as_any().downcast_ref::<Series<u32>>().cloned().unwrap_or_default();
+        Box::new(CategoricalSeries { codes, dictionary: Arc::clone(&self.dictionary) })
+    }
+
+    fn filter(&self, mask: &Series<bool>) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.with_codes(SeriesTrait::filter(&self.codes, mask)?)
+    }
+
+    fn take_indices(&self, indices: &[usize]) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.with_codes(self.codes.take_indices(indices)?)
+    }
+
+    fn take_indices_option(&self, indices: &[Option<usize>]) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.with_codes(self.codes.take_indices_option(indices)?)
+    }
+
+    fn append_series(&mut self, other: &dyn SeriesTrait) -> AxionResult<()> {
+        match other.as_any().downcast_ref::<CategoricalSeries>() {
+            Some(other_categorical) => {
+                self.append(other_categorical);
+                Ok(())
+            }
+            None => Err(AxionError::TypeMismatch {
+                expected: self.dtype(),
+                found: other.dtype(),
+                name: self.name().to_string(),
+            }),
+        }
+    }
+
+    fn rename(&mut self, new_name: &str) {
+        self.codes.rename(new_name.to_string());
+    }
+
+    /// 按类别字符串比较，字典不同但取值相同的分类 Series 视为相等
+    fn series_equal(&self, other: &dyn SeriesTrait) -> bool {
+        match other.as_any().downcast_ref::<CategoricalSeries>() {
+            Some(other_categorical) => {
+                self.name() == other_categorical.name()
+                    && self.len() == other_categorical.len()
+                    && self.iter().eq(other_categorical.iter())
+            }
+            None => false,
+        }
+    }
+
+    /// 按类别字符串的字典序比较，null 排在最后
+    fn compare_row(&self, a_idx: usize, b_idx: usize) -> Ordering {
+        match (self.get(a_idx), self.get(b_idx)) {
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    fn get_as_f64(&self, _index: usize) -> AxionResult<Option<f64>> {
+        Ok(None)
+    }
+
+    /// 转换为 String 时解码类别，转换为其他类型时先解码再按字符串转换
+    fn cast(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        match dtype {
+            DataType::Categorical => Ok(self.clone_box()),
+            DataType::String => Ok(Box::new(self.to_string_series())),
+            other => SeriesTrait::cast(&self.to_string_series(), other),
+        }
+    }
+
+    fn cast_lenient(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        match dtype {
+            DataType::Categorical | DataType::String => self.cast(dtype),
+            other => SeriesTrait::cast_lenient(&self.to_string_series(), other),
+        }
+    }
+}
+
+impl Display for CategoricalSeries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for (i, opt_val) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", opt_val.unwrap_or("null"))?;
+        }
+        write!(f, "]")
+    }
+}
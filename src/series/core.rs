@@ -3,17 +3,21 @@
 //! 提供 Series 数据结构的完整实现，包括基本操作、数学运算、比较操作等。
 
 use crate::dtype::{DataType, DataTypeTrait}; 
+use crate::decimal::Decimal;
 use crate::error::{AxionError, CastError, AxionResult}; 
 use super::interface::SeriesTrait;
 use super::ops::{SeriesArithScalar, SeriesCompareScalar, SeriesCompare, SeriesCompareSeries, SeriesArithSeries}; 
 use super::string::StringAccessor;
+use super::datetime::DatetimeAccessor;
+use super::categorical::CategoricalSeries;
+use super::bitmap::{Bitmap, BitmapIter};
 
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Display};
 use std::any::Any;
 use num_traits::{Float, Zero, ToPrimitive}; 
 use std::iter::{FromIterator, Sum};
-use std::ops::{Add, Sub, Mul, Div, Rem, Deref};
+use std::ops::{Add, Sub, Mul, Div, Rem};
 
 use rayon::prelude::*;
 
@@ -137,14 +141,41 @@ impl<'a> IntoSeriesData<String> for &'a [&'a str] {
 /// 
 /// 一维的类型化数据序列，支持空值处理和高效计算。
 /// 类似于 pandas 的 Series 或 R 的向量。
+/// 
+/// 值以连续的 `Vec<T>` 存储，null 信息单独记录在有效性位图中，
+/// 相比 `Vec<Option<T>>` 更节省内存，也便于向量化计算。
+///
+/// # 迁移说明
+///
+/// 存储布局改为值缓冲区加有效性位图后，以下接口发生了不兼容的变化：
+///
+/// - 公开字段 `data: Vec<Option<T>>` 被移除
+/// - `Deref<Target = Vec<Option<T>>>` 实现被移除，不能再直接对 Series 调用 `Vec` 的方法
+/// - `data_internal()` 由返回借用的 `&Vec<Option<T>>` 改为每次返回新分配的 `Vec<Option<T>>`
+///
+/// 只读访问请改用不复制数据的借用接口：逐个元素用 [`Series::get`] 或 [`Series::iter`]，
+/// 批量访问用 [`Series::values`] 加 [`Series::validity`]；需要取得所有权时使用
+/// [`Series::take_inner`] 或 [`Series::into_parts`]。
+///
+/// ```rust
+/// # use axion_data::Series;
+/// let s = Series::new_from_options("a".to_string(), vec![Some(1), None, Some(3)]);
+/// // 以前写作 s.data[2] 或 s[2]
+/// assert_eq!(s.get(2), Some(&3));
+/// // 以前写作 s.data_internal().iter().flatten().sum()
+/// let total: i32 = s.values().iter().zip(s.validity().iter()).filter(|(_, valid)| *valid).map(|(v, _)| v).sum();
+/// assert_eq!(total, 4);
+/// ```
 pub struct Series<T>
 {
     /// Series 的名称标识符
     name: String,
     /// 数据类型信息
     dtype: DataType,
-    /// 实际数据存储，使用 Option<T> 支持空值
-    pub data: Vec<Option<T>>,
+    /// 连续存储的值，null 位置存放占位值 `T::default()`
+    values: Vec<T>,
+    /// 有效性位图，置位表示对应位置的值非 null
+    validity: Bitmap,
     /// 元数据标志，用于性能优化
     flags: SeriesFlags,
 }
@@ -155,7 +186,8 @@ impl<T: Clone> Clone for Series<T> {
         Series {
             name: self.name.clone(),
             dtype: self.dtype.clone(),
-            data: self.data.clone(),
+            values: self.values.clone(),
+            validity: self.validity.clone(),
             flags: self.flags,
         }
     }
@@ -179,18 +211,21 @@ where
 /// Display 实现，以数组格式显示数据
 impl<T> Display for Series<T>
 where
-    T: Display,
+    T: DataTypeTrait + Display,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "[")?;
 
         let mut first = true;
-        for opt_val in &self.data {
+        for opt_val in self.iter() {
             if !first {
                 write!(f, ", ")?;
             }
             match opt_val {
-                Some(value) => write!(f, "{}", value)?,
+                Some(value) => match value.format_with_dtype(&self.dtype) {
+                    Some(formatted) => write!(f, "{}", formatted)?,
+                    None => write!(f, "{}", value)?,
+                },
                 None => write!(f, "null")?,
             }
             first = false;
@@ -206,36 +241,59 @@ impl<T> Default for Series<T> {
         Series {
             name: String::new(),
             dtype: DataType::Null,
-            data: Vec::new(),
+            values: Vec::new(),
+            validity: Bitmap::new(),
             flags: SeriesFlags::default(),
         }
     }
 }
 
-/// Deref 实现，允许像 Vec<Option<T>> 一样访问
-impl<T> Deref for Series<T> {
-    type Target = Vec<Option<T>>;
+/// Series 元素迭代器
+/// 
+/// 同时遍历值缓冲区和有效性位图，null 位置产出 `None`。
+pub struct SeriesIter<'a, T> {
+    values: std::slice::Iter<'a, T>,
+    validity: BitmapIter<'a>,
+}
+
+impl<'a, T> Iterator for SeriesIter<'a, T> {
+    type Item = Option<&'a T>;
 
-    fn deref(&self) -> &Self::Target {
-        &self.data
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.values.next()?;
+        let is_valid = self.validity.next()?;
+        Some(if is_valid { Some(value) } else { None })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
     }
 }
 
+impl<T> ExactSizeIterator for SeriesIter<'_, T> {}
+
 impl<T> Series<T> {
+    /// 由值缓冲区和有效性位图直接构建 Series（内部使用，调用方保证长度一致）
+    fn from_raw(name: String, dtype: DataType, values: Vec<T>, validity: Bitmap) -> Self {
+        debug_assert_eq!(values.len(), validity.len());
+        Series { name, dtype, values, validity, flags: SeriesFlags::default() }
+    }
+
     /// 从数据源创建新的 Series，所有值标记为非空
     pub fn new<D>(name: String, data_source: D) -> Self
     where
         T: DataTypeTrait + Clone + Debug + Send + Sync + 'static,
         D: IntoSeriesData<T>,
     {
-        let (data, dtype) = data_source.into_series_data();
-        let data_opts = data.into_iter().map(Some).collect();
-        Series { name, dtype, data: data_opts, flags: SeriesFlags::default() }
+        let (values, dtype) = data_source.into_series_data();
+        let validity = Bitmap::new_with_value(values.len(), true);
+        Series::from_raw(name, dtype, values, validity)
     }
 
     /// 创建指定类型的空 Series
     pub fn new_empty(name: String, dtype: DataType) -> Self {
-        Series { name, dtype, data: Vec::new(), flags: SeriesFlags::default() }
+        Series::from_raw(name, dtype, Vec::new(), Bitmap::new())
     }
 
     /// 从可选值向量创建 Series，支持显式空值
@@ -244,12 +302,84 @@ impl<T> Series<T> {
         T: DataTypeTrait,
     {
         let dtype = T::DTYPE;
-        Series { name, dtype, data, flags: SeriesFlags::default() }
+        let mut values = Vec::with_capacity(data.len());
+        let mut validity = Bitmap::with_capacity(data.len());
+        for opt_val in data {
+            validity.push(opt_val.is_some());
+            values.push(opt_val.unwrap_or_default());
+        }
+        Series::from_raw(name, dtype, values, validity)
+    }
+
+    /// 从值缓冲区和有效性位图创建 Series
+    /// 
+    /// 位图中未置位的位置视为 null，对应的值仅作为占位存在。
+    /// 
+    /// # 错误
+    /// 
+    /// 如果值缓冲区与位图长度不一致，返回 `MismatchedLengths`
+    pub fn from_parts(name: String, values: Vec<T>, validity: Bitmap) -> AxionResult<Self>
+    where
+        T: DataTypeTrait,
+    {
+        if values.len() != validity.len() {
+            return Err(AxionError::MismatchedLengths {
+                expected: values.len(),
+                found: validity.len(),
+                name,
+            });
+        }
+        Ok(Series::from_raw(name, T::DTYPE, values, validity))
+    }
+
+    /// 替换 Series 的数据类型参数，不修改底层值
+    ///
+    /// 用于为时间类型指定时间单位和时区，例如将 `Series<Datetime>` 标记为毫秒精度的 UTC 时间。
+    ///
+    /// # 参数
+    ///
+    /// * `dtype` - 新的数据类型，必须与元素类型的 `DataType` 变体相同
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::TypeMismatch` - 数据类型变体与元素类型不匹配
+    /// * `AxionError::InvalidArgument` - 时区字符串无法解析，或 Decimal 的精度参数无效
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::{DataType, Datetime, Series, TimeUnit};
+    /// let ts = Series::new("ts".into(), vec![Datetime(1_700_000_000_000)])
+    ///     .with_dtype(DataType::Datetime(TimeUnit::Milliseconds, Some("UTC".into())))?;
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn with_dtype(mut self, dtype: DataType) -> AxionResult<Self>
+    where
+        T: DataTypeTrait,
+    {
+        if std::mem::discriminant(&dtype) != std::mem::discriminant(&T::DTYPE) {
+            return Err(AxionError::TypeMismatch {
+                expected: T::DTYPE,
+                found: dtype,
+                name: self.name,
+            });
+        }
+        if let DataType::Datetime(_, Some(tz)) = &dtype {
+            if crate::temporal::parse_tz_offset(tz).is_none() {
+                return Err(AxionError::InvalidArgument(format!("无法解析的时区: '{}'", tz)));
+            }
+        }
+        if let DataType::Decimal(precision, scale) = &dtype {
+            crate::decimal::check_decimal_dtype(*precision, *scale)?;
+        }
+        self.dtype = dtype;
+        Ok(self)
     }
 
     /// 清空 Series 的所有数据
     pub fn clear(&mut self) {
-        self.data.clear();
+        self.values.clear();
+        self.validity = Bitmap::new();
         self.dtype = DataType::Null;
         self.flags = SeriesFlags::default();
     }
@@ -262,7 +392,35 @@ impl<T> Series<T> {
             }
         }
         self.flags.clear_sorted();
-        self.data.push(value);
+        self.validity.push(value.is_some());
+        self.values.push(value.unwrap_or_default());
+    }
+
+    /// 将另一个 Series 的所有元素追加到末尾
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 要追加的 Series，其名称会被忽略
+    pub fn append(&mut self, other: &Series<T>) where T: Clone {
+        if self.dtype == DataType::Null {
+            self.dtype = other.dtype.clone();
+        }
+        self.flags.clear_sorted();
+        self.values.extend_from_slice(&other.values);
+        self.validity.extend_from_bitmap(&other.validity);
+    }
+
+    /// 将迭代器中的所有元素追加到末尾，`None` 表示 null
+    ///
+    /// # 参数
+    ///
+    /// * `iter` - 产生 `Option<T>` 的迭代器
+    pub fn extend<I>(&mut self, iter: I) where T: DataTypeTrait, I: IntoIterator<Item = Option<T>> {
+        let iter = iter.into_iter();
+        self.values.reserve(iter.size_hint().0);
+        for value in iter {
+            self.push(value);
+        }
     }
 
     // === 基本属性访问方法 ===
@@ -279,42 +437,85 @@ impl<T> Series<T> {
 
     /// 获取 Series 的长度（包括空值）
     pub fn len(&self) -> usize {
-        self.data.len()
+        self.values.len()
     }
 
     /// 检查 Series 是否为空
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.values.is_empty()
+    }
+
+    /// 获取 null 值的数量
+    pub fn null_count(&self) -> usize {
+        self.validity.unset_count()
+    }
+
+    /// 检查指定索引处的值是否有效（非 null），越界返回 `false`
+    #[inline]
+    pub fn is_valid(&self, index: usize) -> bool {
+        index < self.len() && self.validity.get(index)
     }
 
     /// 获取指定索引处的值引用（跳过空值）
+    #[inline]
     pub fn get(&self, index: usize) -> Option<&T> {
-        self.data.get(index).and_then(|opt_val| opt_val.as_ref())
+        if self.is_valid(index) {
+            Some(&self.values[index])
+        } else {
+            None
+        }
     }
 
     /// 返回迭代器，遍历所有元素（包括空值）
-    pub fn iter(&self) -> impl Iterator<Item = Option<&T>> + '_ {
-        self.data.iter().map(|opt_val| opt_val.as_ref())
+    pub fn iter(&self) -> SeriesIter<'_, T> {
+        SeriesIter {
+            values: self.values.iter(),
+            validity: self.validity.iter(),
+        }
     }
 
     /// 返回迭代器，只遍历有效值（跳过空值）
     pub fn iter_valid(&self) -> impl Iterator<Item = &T> + '_ {
-        self.data.iter().filter_map(|opt_val| opt_val.as_ref())
+        self.iter().flatten()
     }
 
     /// 返回迭代器，遍历有效值的拥有副本
     pub fn iter_valid_owned(&self) -> impl Iterator<Item = T> + '_ where T: Clone {
-        self.data.iter().filter_map(|opt_val| opt_val.clone())
+        self.iter().flatten().cloned()
     }
 
-    /// 获取内部数据向量的引用
-    pub fn data_internal(&self) -> &Vec<Option<T>> {
-        &self.data
+    /// 获取连续存储的值缓冲区
+    /// 
+    /// null 位置存放的是占位值，需要结合 `validity` 判断是否有效。
+    pub fn values(&self) -> &[T] {
+        &self.values
     }
 
-    /// 消费 Series，返回内部数据向量
+    /// 获取有效性位图
+    pub fn validity(&self) -> &Bitmap {
+        &self.validity
+    }
+
+    /// 以 `Vec<Option<T>>` 形式物化 Series 的数据
+    ///
+    /// 每次调用都会复制全部数据；只读访问请使用 [`Series::iter`]，
+    /// 或 [`Series::values`] 加 [`Series::validity`]。
+    pub fn data_internal(&self) -> Vec<Option<T>> where T: Clone {
+        self.iter().map(|opt_val| opt_val.cloned()).collect()
+    }
+
+    /// 消费 Series，返回 `Vec<Option<T>>` 形式的数据
     pub fn take_inner(self) -> Vec<Option<T>> {
-        self.data
+        self.values
+            .into_iter()
+            .zip(self.validity.iter())
+            .map(|(value, is_valid)| if is_valid { Some(value) } else { None })
+            .collect()
+    }
+
+    /// 消费 Series，返回值缓冲区和有效性位图
+    pub fn into_parts(self) -> (Vec<T>, Bitmap) {
+        (self.values, self.validity)
     }
 
     /// 修改 Series 的名称
@@ -362,7 +563,18 @@ impl<T> Series<T> {
     /// - `Some(None)` - 空值  
     /// - `None` - 索引越界
     pub fn get_opt(&self, index: usize) -> Option<Option<&T>> {
-        self.data.get(index).map(|opt_t| opt_t.as_ref())
+        if index < self.len() {
+            Some(self.get(index))
+        } else {
+            None
+        }
+    }
+
+    /// 按索引列表重新排列值和有效性（内部使用，调用方保证索引有效）
+    fn gather(&self, indices: &[usize]) -> (Vec<T>, Bitmap) where T: Clone {
+        let values = indices.iter().map(|&idx| self.values[idx].clone()).collect();
+        let validity = indices.iter().map(|&idx| self.validity.get(idx)).collect();
+        (values, validity)
     }
 
     /// 就地排序 Series
@@ -371,9 +583,9 @@ impl<T> Series<T> {
     /// * `reverse` - false 为升序，true 为降序
     pub fn sort(&mut self, reverse: bool)
     where
-        T: Ord,
+        T: Ord + Clone,
     {
-        let compare_options = |a: &Option<T>, b: &Option<T>| -> Ordering {
+        let compare_options = |a: Option<&T>, b: Option<&T>| -> Ordering {
             match (a, b) {
                 (Some(va), Some(vb)) => va.cmp(vb),
                 (None, None) => Ordering::Equal,
@@ -382,61 +594,79 @@ impl<T> Series<T> {
             }
         };
 
+        let mut indices: Vec<usize> = (0..self.len()).collect();
+        if reverse {
+            indices.sort_by(|&a, &b| compare_options(self.get(b), self.get(a)));
+        } else {
+            indices.sort_by(|&a, &b| compare_options(self.get(a), self.get(b)));
+        }
+        let (values, validity) = self.gather(&indices);
+        self.values = values;
+        self.validity = validity;
+
         if reverse {
-            self.data.sort_by(|a, b| compare_options(b, a));
             self.flags.set_sorted(false, true);
         } else {
-            self.data.sort_by(compare_options);
             self.flags.set_sorted(true, false);
         }
     }
 
-    /// 类型转换（实验性功能）
-    /// 
-    /// 目前只支持 f64 到 f64 的转换
+    /// 严格类型转换
+    ///
+    /// 支持所有整数、浮点数、布尔和字符串类型之间的双向转换，null 保持为 null。
+    /// 浮点数转换为整数时向零截断；非零数值转换为布尔值时为 true；
+    /// 字符串转换为布尔值时只接受不区分大小写的 "true" 和 "false"。
+    ///
+    /// # 错误
+    ///
+    /// 任何一个非 null 值无法用目标类型表示（溢出、NaN、字符串解析失败等）时
+    /// 返回 `AxionError::CastError`
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::Series;
+    /// let s = Series::new("a".into(), vec!["1", "2", "300"]);
+    /// let ints: Series<i32> = s.cast()?;
+    /// assert!(s.cast::<i8>().is_err()); // 300 超出 i8 范围
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
     pub fn cast<NewType>(&self) -> AxionResult<Series<NewType>>
     where
-        T: Clone + 'static,
-        NewType: DataTypeTrait + Clone + Debug + Default + 'static,
+        T: DataTypeTrait,
+        NewType: DataTypeTrait,
     {
-        let target_dtype = NewType::default().as_dtype();
-
-        let mut new_data = Vec::with_capacity(self.len());
-        for opt_val in self.data.iter() {
-            match opt_val {
-                Some(val) => {
-                    if std::any::TypeId::of::<T>() == std::any::TypeId::of::<f64>()
-                        && std::any::TypeId::of::<NewType>() == std::any::TypeId::of::<f64>()
-                    {
-                        let any_ref = val as &dyn std::any::Any;
-                        if let Some(float_val) = any_ref.downcast_ref::<f64>() {
-                            let new_val_any = float_val as &dyn std::any::Any;
-                            if let Some(new_val) = new_val_any.downcast_ref::<NewType>() {
-                                new_data.push(Some(new_val.clone()));
-                            } else {
-                                return Err(AxionError::CastError(CastError("Failed to downcast to target type after 'as' cast".to_string())));
-                            }
-                        } else {
-                            return Err(AxionError::CastError(CastError("Failed to downcast original value".to_string())));
-                        }
-                    } else {
-                        return Err(AxionError::CastError(CastError(format!(
-                            "Unsupported cast from {:?} to {:?}",
-                            self.dtype(),
-                            target_dtype
-                        ))));
-                    }
-                }
-                None => new_data.push(None),
+        let converted = self.cast_lenient::<NewType>();
+        // 宽松转换只会额外产生 null，null 数量不变说明所有值都转换成功
+        if converted.null_count() != self.null_count() {
+            if let Some(idx) = (0..self.len()).find(|&idx| self.is_valid(idx) && !converted.is_valid(idx)) {
+                return Err(AxionError::CastError(CastError(format!(
+                    "无法将 Series '{}' 第 {} 行的值 {:?} 从 {:?} 转换为 {:?}",
+                    self.name, idx, self.values[idx], self.dtype, NewType::DTYPE
+                ))));
             }
         }
+        Ok(converted)
+    }
 
-        Ok(Series {
-            name: self.name.clone(),
-            dtype: target_dtype,
-            data: new_data,
-            flags: SeriesFlags::default(),
-        })
+    /// 宽松类型转换
+    ///
+    /// 转换规则与 [`Series::cast`] 相同，但无法转换的值会变为 null 而不是返回错误。
+    pub fn cast_lenient<NewType>(&self) -> Series<NewType>
+    where
+        T: DataTypeTrait,
+        NewType: DataTypeTrait,
+    {
+        let mut values = Vec::with_capacity(self.len());
+        let mut validity = Bitmap::with_capacity(self.len());
+        for opt_val in self.iter() {
+            let converted = opt_val
+                .and_then(|val| val.to_cast_value())
+                .and_then(|value| NewType::from_cast_value(&value));
+            validity.push(converted.is_some());
+            values.push(converted.unwrap_or_default());
+        }
+        Series::from_raw(self.name.clone(), NewType::DTYPE, values, validity)
     }
 
     // === 数值计算方法 ===
@@ -446,14 +676,10 @@ impl<T> Series<T> {
     where
         T: Sum<T> + Clone + Zero,
     {
-        let iter = self.iter_valid_owned();
-        let total: T = iter.sum();
-
-        if self.data.iter().any(|opt| opt.is_some()) {
-            Some(total)
-        } else {
-            None
+        if self.null_count() == self.len() {
+            return None;
         }
+        Some(self.iter_valid_owned().sum())
     }
 
     /// 计算所有有效值的最小值
@@ -461,9 +687,7 @@ impl<T> Series<T> {
     where
         T: PartialOrd + Clone,
     {
-        self.data
-            .iter()
-            .filter_map(|opt| opt.as_ref())
+        self.iter_valid()
             .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
             .cloned()
     }
@@ -473,9 +697,7 @@ impl<T> Series<T> {
     where
         T: PartialOrd + Clone,
     {
-        self.data
-            .iter()
-            .filter_map(|opt| opt.as_ref())
+        self.iter_valid()
             .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
             .cloned()
     }
@@ -487,7 +709,7 @@ impl<T> Series<T> {
     where
         T: Float,
     {
-        let new_data: Vec<Option<bool>> = self.data.iter().map(|opt_val| {
+        let new_data: Vec<Option<bool>> = self.iter().map(|opt_val| {
             opt_val.map(|v| v.is_nan())
                    .or(Some(false))
         }).collect();
@@ -499,7 +721,7 @@ impl<T> Series<T> {
     where
         T: Float,
     {
-        let new_data: Vec<Option<bool>> = self.data.iter().map(|opt_val| {
+        let new_data: Vec<Option<bool>> = self.iter().map(|opt_val| {
             opt_val.map(|v| !v.is_nan())
                    .or(Some(true))
         }).collect();
@@ -511,7 +733,7 @@ impl<T> Series<T> {
     where
         T: Float,
     {
-        let new_data: Vec<Option<bool>> = self.data.iter().map(|opt_val| {
+        let new_data: Vec<Option<bool>> = self.iter().map(|opt_val| {
             opt_val.map(|v| v.is_infinite())
                    .or(Some(false))
         }).collect();
@@ -527,8 +749,8 @@ impl<T> Series<T> {
         U: DataTypeTrait + Clone + Debug,
         F: FnMut(Option<T>) -> Option<U>,
     {
-        let new_data: Vec<Option<U>> = self.data.iter()
-                                             .cloned()
+        let new_data: Vec<Option<U>> = self.iter()
+                                             .map(|opt_val| opt_val.cloned())
                                              .map(f)
                                              .collect();
         Series::new_from_options(self.name.clone(), new_data)
@@ -547,23 +769,9 @@ impl<T> Series<T> {
             );
         }
 
-        let new_data: Vec<Option<T>> = self.data
-            .iter()
-            .zip(mask.data.iter())
-            .filter_map(|(data_opt, mask_opt)| {
-                match mask_opt {
-                    Some(true) => Some(data_opt.clone()),
-                    _ => None,
-                }
-            })
-            .collect();
-
-        Series {
-            name: self.name.clone(),
-            dtype: self.dtype.clone(),
-            data: new_data,
-            flags: SeriesFlags::default(),
-        }
+        let indices: Vec<usize> = mask.true_indices();
+        let (values, validity) = self.gather(&indices);
+        Series::from_raw(self.name.clone(), self.dtype.clone(), values, validity)
     }
 
     /// 检查两个 Series 是否完全相等（包括名称）
@@ -574,7 +782,7 @@ impl<T> Series<T> {
         if self.name != other.name || self.dtype != other.dtype || self.len() != other.len() {
             return false;
         }
-        self.data.iter().eq(other.data.iter())
+        self.iter().eq(other.iter())
     }
 
     /// 检查两个 Series 的数据是否相等（忽略名称）
@@ -585,7 +793,7 @@ impl<T> Series<T> {
         if self.name != other.name || self.dtype != other.dtype || self.len() != other.len() {
             return false;
         }
-        self.data.iter().eq(other.data.iter())
+        self.iter().eq(other.iter())
     }
 
     // === 内部操作方法 ===
@@ -601,7 +809,7 @@ impl<T> Series<T> {
             panic!("Cannot perform operation on Series of different lengths");
         }
 
-        let new_data = self.data.iter().zip(other.data.iter()).map(|(opt_a, opt_b)| {
+        let new_data = self.iter().zip(other.iter()).map(|(opt_a, opt_b)| {
             match (opt_a, opt_b) {
                 (Some(a), Some(b)) => Some(op(a.clone(), b.clone())),
                 _ => None,
@@ -618,8 +826,8 @@ impl<T> Series<T> {
         U: DataTypeTrait + Clone + Debug,
         F: Fn(T, T) -> U,
     {
-        let new_data = self.data.iter().map(|opt_a| {
-            opt_a.as_ref().map(|a| op(a.clone(), scalar.clone()))
+        let new_data = self.iter().map(|opt_a| {
+            opt_a.map(|a| op(a.clone(), scalar.clone()))
         }).collect();
         Series::new_from_options(self.name.clone(), new_data)
     }
@@ -632,7 +840,7 @@ impl<T> Series<T> {
         let mut sum = 0.0f64;
         let mut count = 0usize;
 
-        for val in self.data.iter().flatten() {
+        for val in self.iter_valid() {
             if let Some(float_val) = val.to_f64() {
                 sum += float_val;
                 count += 1;
@@ -654,8 +862,8 @@ impl<T> Series<T> {
         U: DataTypeTrait + Clone + Debug + Display + Send + Sync + 'static,
         F: Fn(Option<&T>) -> Option<U>,
     {
-        let new_data: Vec<Option<U>> = self.data.iter()
-            .map(|opt_ref_t| func(opt_ref_t.as_ref()))
+        let new_data: Vec<Option<U>> = self.iter()
+            .map(func)
             .collect();
 
         if self.is_empty() {
@@ -679,9 +887,10 @@ impl<T> Series<T> {
             return Series::new_empty(self.name.clone(), U::DTYPE);
         }
 
-        let new_data: Vec<Option<U>> = self.data
+        let new_data: Vec<Option<U>> = self.values
             .par_iter()
-            .map(|opt_val| func(opt_val.as_ref()))
+            .enumerate()
+            .map(|(idx, val)| func(if self.validity.get(idx) { Some(val) } else { None }))
             .collect();
 
         Series::new_from_options(self.name.clone(), new_data)
@@ -695,6 +904,11 @@ impl Series<String> {
     pub fn str(&self) -> StringAccessor<'_> {
         StringAccessor::new(self)
     }
+
+    /// 返回时间操作访问器，字符串按 ISO-8601 即时解析，无法解析的值视为 null
+    pub fn dt(&self) -> DatetimeAccessor {
+        DatetimeAccessor::from_strings(self)
+    }
 }
 
 // === 运算符重载实现 ===
@@ -775,7 +989,7 @@ where
         if self.len() != rhs.len() {
             panic!("Cannot perform operation on Series of different lengths");
         }
-        let new_data = self.data.iter().zip(rhs.data.iter()).map(|(opt_a, opt_b)| {
+        let new_data = self.iter().zip(rhs.iter()).map(|(opt_a, opt_b)| {
             match (opt_a, opt_b) {
                 (Some(a), Some(b)) => {
                     if b.is_zero() { None }
@@ -814,7 +1028,7 @@ where
         if self.len() != rhs.len() {
             panic!("Cannot perform operation on Series of different lengths");
         }
-        let new_data = self.data.iter().zip(rhs.data.iter()).map(|(opt_a, opt_b)| {
+        let new_data = self.iter().zip(rhs.iter()).map(|(opt_a, opt_b)| {
             match (opt_a, opt_b) {
                 (Some(a), Some(b)) => {
                     if b.is_zero() { None }
@@ -851,14 +1065,9 @@ where
 {
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
         let data: Vec<T> = iter.into_iter().collect();
-        let (data_vec, dtype) = data.into_series_data();
-        let data_opts = data_vec.into_iter().map(Some).collect();
-        Series {
-            name: String::new(),
-            dtype,
-            data: data_opts,
-            flags: SeriesFlags::default(),
-        }
+        let (values, dtype) = data.into_series_data();
+        let validity = Bitmap::new_with_value(values.len(), true);
+        Series::from_raw(String::new(), dtype, values, validity)
     }
 }
 
@@ -881,13 +1090,8 @@ where
     fn from(tuple: (String, Vec<T>)) -> Self {
         let (name, data) = tuple;
         let dtype = data.first().map_or(DataType::Null, |v| v.as_dtype());
-        let data_opts = data.into_iter().map(Some).collect();
-        Series {
-            name,
-            dtype,
-            data: data_opts,
-            flags: SeriesFlags::default(),
-        }
+        let validity = Bitmap::new_with_value(data.len(), true);
+        Series::from_raw(name, dtype, data, validity)
     }
 }
 
@@ -902,12 +1106,9 @@ where
             .find_map(|opt_val| opt_val.as_ref().map(|v| v.as_dtype()))
             .unwrap_or(DataType::Null);
 
-        Series {
-            name,
-            dtype,
-            data,
-            flags: SeriesFlags::default(),
-        }
+        let mut series = Series::new_from_options(name, data);
+        series.dtype = dtype;
+        series
     }
 }
 
@@ -918,13 +1119,8 @@ where
     fn from(tuple: (String, &'a [T])) -> Self {
         let (name, data_slice) = tuple;
         let dtype = data_slice.first().map_or(DataType::Null, |v| v.as_dtype());
-        let data_opts = data_slice.iter().cloned().map(Some).collect();
-        Series {
-            name,
-            dtype,
-            data: data_opts,
-            flags: SeriesFlags::default(),
-        }
+        let validity = Bitmap::new_with_value(data_slice.len(), true);
+        Series::from_raw(name, dtype, data_slice.to_vec(), validity)
     }
 }
 
@@ -935,13 +1131,8 @@ where
     fn from(tuple: (String, [T; N])) -> Self {
         let (name, data_array) = tuple;
         let dtype = data_array.first().map_or(DataType::Null, |v| v.as_dtype());
-        let data_opts = Vec::from(data_array).into_iter().map(Some).collect();
-        Series {
-            name,
-            dtype,
-            data: data_opts,
-            flags: SeriesFlags::default(),
-        }
+        let validity = Bitmap::new_with_value(N, true);
+        Series::from_raw(name, dtype, Vec::from(data_array), validity)
     }
 }
 
@@ -952,10 +1143,76 @@ where
     T: DataTypeTrait + Clone + Debug,
 {
     type Item = Option<&'a T>;
-    type IntoIter = std::iter::Map<std::slice::Iter<'a, Option<T>>, fn(&'a Option<T>) -> Option<&'a T>>;
+    type IntoIter = SeriesIter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.data.iter().map(|opt_val| opt_val.as_ref())
+        self.iter()
+    }
+}
+
+/// 将 Series 转换为由 `dtype` 指定的类型，返回 trait 对象
+fn cast_to_dtype<T: DataTypeTrait>(series: &Series<T>, dtype: &DataType, strict: bool) -> AxionResult<Box<dyn SeriesTrait>> {
+    macro_rules! cast_to {
+        ($t:ty) => {{
+            let converted: Series<$t> = if strict { series.cast()? } else { series.cast_lenient() };
+            Ok(Box::new(converted))
+        }};
+    }
+
+    if dtype.is_temporal() {
+        return crate::temporal::cast_to_temporal(series, dtype, strict);
+    }
+    // 时间类型转换为字符串或分类时使用显示格式，转换为数值类型时使用底层整数
+    if let Some(raw) = crate::temporal::temporal_raw_values(series) {
+        let source_dtype = series.dtype();
+        if matches!(dtype, DataType::String | DataType::Categorical) {
+            let data = raw.into_iter().map(|opt| opt.map(|v| crate::temporal::format_temporal(v, &source_dtype))).collect();
+            return cast_to_dtype(&Series::<String>::new_from_options(series.name().to_string(), data), dtype, strict);
+        }
+        if dtype.is_numeric() {
+            return cast_to_dtype(&Series::<i64>::new_from_options(series.name().to_string(), raw), dtype, strict);
+        }
+        return Err(AxionError::UnsupportedOperation(format!(
+            "不支持将 Series '{}' 从 {:?} 转换为 {:?}",
+            series.name(),
+            source_dtype,
+            dtype
+        )));
+    }
+    if let DataType::Decimal(precision, scale) = dtype {
+        return crate::decimal::cast_to_decimal(series, *precision, *scale, strict);
+    }
+    // Decimal 的值依赖列的小数位数，先格式化为字符串再转换为目标类型
+    if let Some(decimals) = (series as &dyn Any).downcast_ref::<Series<Decimal>>() {
+        let strings = crate::decimal::decimal_to_strings(decimals, dtype.is_integer());
+        return cast_to_dtype(&strings, dtype, strict);
+    }
+
+    match dtype {
+        DataType::Int8 => cast_to!(i8),
+        DataType::Int16 => cast_to!(i16),
+        DataType::Int32 => cast_to!(i32),
+        DataType::Int64 => cast_to!(i64),
+        DataType::Int128 => cast_to!(i128),
+        DataType::UInt8 => cast_to!(u8),
+        DataType::UInt16 => cast_to!(u16),
+        DataType::UInt32 => cast_to!(u32),
+        DataType::UInt64 => cast_to!(u64),
+        DataType::UInt128 => cast_to!(u128),
+        DataType::Float32 => cast_to!(f32),
+        DataType::Float64 => cast_to!(f64),
+        DataType::Bool => cast_to!(bool),
+        DataType::String => cast_to!(String),
+        DataType::Categorical => {
+            let strings: Series<String> = series.cast_lenient();
+            Ok(Box::new(CategoricalSeries::from_string_series(&strings)))
+        }
+        _ => Err(AxionError::UnsupportedOperation(format!(
+            "不支持将 Series '{}' 从 {:?} 转换为 {:?}",
+            series.name(),
+            series.dtype(),
+            dtype
+        ))),
     }
 }
 
@@ -964,17 +1221,23 @@ where
 impl Series<bool> {
     /// 检查是否所有值都为 true
     pub fn all(&self) -> bool {
-        self.data
-            .iter()
+        self.iter()
             .all(|opt_val| matches!(opt_val, Some(true)))
     }
 
     /// 检查是否存在任何 true 值
     pub fn any(&self) -> bool {
-        self.data
-            .iter()
+        self.iter()
             .any(|opt_val| matches!(opt_val, Some(true)))
     }
+
+    /// 返回所有值为 true 的位置索引（null 视为 false）
+    pub fn true_indices(&self) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter_map(|(idx, opt_val)| matches!(opt_val, Some(true)).then_some(idx))
+            .collect()
+    }
 }
 
 // === SeriesTrait 实现 ===
@@ -1004,16 +1267,16 @@ where
     }
 
     fn get_str(&self, index: usize) -> Option<String> {
-        self.data.get(index).map(|opt_val| {
+        self.get_opt(index).map(|opt_val| {
             match opt_val {
-                Some(value) => value.to_string(),
+                Some(value) => value.format_with_dtype(&self.dtype).unwrap_or_else(|| value.to_string()),
                 None => "null".to_string(),
             }
         })
     }
 
     fn is_null_at(&self, index: usize) -> bool {
-        self.data.get(index).map_or(true, |opt_val| opt_val.is_none())
+        !self.is_valid(index)
     }
 
     fn clone_box(&self) -> Box<dyn SeriesTrait> {
@@ -1023,51 +1286,63 @@ where
     fn slice(&self, offset: usize, length: usize) -> Box<dyn SeriesTrait> {
         let start = std::cmp::min(offset, self.len());
         let end = std::cmp::min(start + length, self.len());
-        let sliced_data = self.data[start..end].to_vec();
-        let new_series = Series::new_from_options(self.name.clone(), sliced_data);
-        Box::new(new_series)
+        let values = self.values[start..end].to_vec();
+        let validity = self.validity.slice(start, end);
+        Box::new(Series::from_raw(self.name.clone(), self.dtype.clone(), values, validity))
     }
 
     fn filter(&self, mask: &Series<bool>) -> AxionResult<Box<dyn SeriesTrait>> {
-        let mut new_data = Vec::with_capacity(self.len());
-
-        for (opt_val, opt_mask_val) in self.data.iter().zip(mask.data_internal().iter()) {
-            if let Some(true) = opt_mask_val {
-                new_data.push(opt_val.clone());
-            }
-        }
-
-        let new_series = Series::new_from_options(self.name.clone(), new_data);
-        Ok(Box::new(new_series))
+        let indices: Vec<usize> = mask.true_indices()
+            .into_iter()
+            .take_while(|&idx| idx < self.len())
+            .collect();
+        let (values, validity) = self.gather(&indices);
+        Ok(Box::new(Series::from_raw(self.name.clone(), self.dtype.clone(), values, validity)))
     }
 
     fn take_indices(&self, indices: &[usize]) -> AxionResult<Box<dyn SeriesTrait>> {
-        let mut new_data = Vec::with_capacity(indices.len());
-
-        for &idx in indices {
-            new_data.push(self.data.get(idx).cloned().ok_or_else(|| AxionError::IndexOutOfBounds(idx, self.len()))?);
+        if let Some(&idx) = indices.iter().find(|&&idx| idx >= self.len()) {
+            return Err(AxionError::IndexOutOfBounds(idx, self.len()));
         }
-
-        let new_series = Series::new_from_options(self.name.clone(), new_data);
-        Ok(Box::new(new_series))
+        let (values, validity) = self.gather(indices);
+        Ok(Box::new(Series::from_raw(self.name.clone(), self.dtype.clone(), values, validity)))
     }
 
     fn take_indices_option(&self, indices: &[Option<usize>]) -> AxionResult<Box<dyn SeriesTrait>> {
-        let mut new_data = Vec::with_capacity(indices.len());
+        let mut values = Vec::with_capacity(indices.len());
+        let mut validity = Bitmap::with_capacity(indices.len());
         for opt_idx in indices {
             match opt_idx {
                 Some(idx) => {
-                    let opt_val = self.data.get(*idx)
-                        .ok_or_else(|| AxionError::IndexOutOfBounds(*idx, self.len()))?
-                        .clone();
-                    new_data.push(opt_val);
+                    if *idx >= self.len() {
+                        return Err(AxionError::IndexOutOfBounds(*idx, self.len()));
+                    }
+                    values.push(self.values[*idx].clone());
+                    validity.push(self.validity.get(*idx));
                 }
                 None => {
-                    new_data.push(None);
+                    values.push(T::default());
+                    validity.push(false);
                 }
             }
         }
-        Ok(Box::new(Series::new_from_options(self.name.clone(), new_data)))
+        Ok(Box::new(Series::from_raw(self.name.clone(), self.dtype.clone(), values, validity)))
+    }
+
+    fn append_series(&mut self, other: &dyn SeriesTrait) -> AxionResult<()> {
+        // 同一 Rust 类型可能对应不同的数据类型（如小数位数不同的 Decimal、时间单位不同的 Datetime），
+        // 值的含义不同，必须先比较完整的数据类型
+        let other_series = other
+            .as_any()
+            .downcast_ref::<Series<T>>()
+            .filter(|other_series| other_series.dtype == self.dtype)
+            .ok_or_else(|| AxionError::TypeMismatch {
+                expected: self.dtype(),
+                found: other.dtype(),
+                name: self.name.clone(),
+            })?;
+        self.append(other_series);
+        Ok(())
     }
 
     fn rename(&mut self, new_name: &str){
@@ -1080,7 +1355,7 @@ where
         }
         if let Some(other_series) = other.as_any().downcast_ref::<Series<T>>() {
             if T::DTYPE == DataType::Float32 || T::DTYPE == DataType::Float64 {
-                self.data.iter().zip(other_series.data.iter()).all(|(a, b)| {
+                self.len() == other_series.len() && self.iter().zip(other_series.iter()).all(|(a, b)| {
                     match (a, b) {
                         (Some(val_a), Some(val_b)) => {
                             let f_a = unsafe { *(val_a as *const T as *const f64) };
@@ -1092,7 +1367,7 @@ where
                     }
                 })
             } else {
-                self.data == other_series.data
+                self.len() == other_series.len() && self.iter().eq(other_series.iter())
             }
         } else {
             false
@@ -1100,8 +1375,8 @@ where
     }
 
     fn compare_row(&self, a_idx: usize, b_idx: usize) -> Ordering {
-        let a_opt = self.data.get(a_idx);
-        let b_opt = self.data.get(b_idx);
+        let a_opt = self.get_opt(a_idx);
+        let b_opt = self.get_opt(b_idx);
 
         match (a_opt, b_opt) {
             (Some(Some(a_val)), Some(Some(b_val))) => {
@@ -1119,8 +1394,16 @@ where
         }
     }
 
+    fn cast(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        cast_to_dtype(self, &dtype, true)
+    }
+
+    fn cast_lenient(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        cast_to_dtype(self, &dtype, false)
+    }
+
     fn get_as_f64(&self, index: usize) -> AxionResult<Option<f64>> {
-        match self.data.get(index) {
+        match self.get_opt(index) {
             Some(Some(val)) => {
                 let any_val = val as &dyn Any;
 
@@ -1128,12 +1411,18 @@ where
                 else if let Some(v) = any_val.downcast_ref::<i16>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<i32>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<i64>() { Ok(v.to_f64()) }
+                else if let Some(v) = any_val.downcast_ref::<i128>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<u8>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<u16>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<u32>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<u64>() { Ok(v.to_f64()) }
+                else if let Some(v) = any_val.downcast_ref::<u128>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<f32>() { Ok(v.to_f64()) }
                 else if let Some(v) = any_val.downcast_ref::<f64>() { Ok(Some(*v)) }
+                else if let Some(v) = any_val.downcast_ref::<Decimal>() {
+                    let scale = if let DataType::Decimal(_, scale) = self.dtype { scale } else { 0 };
+                    Ok(Some(v.to_f64(scale)))
+                }
                 else {
                     Ok(None)
                 }
@@ -1160,8 +1449,8 @@ impl Clone for Box<dyn SeriesTrait> {
 macro_rules! impl_compare_scalar {
     ($method_name:ident, $op:tt) => {
         fn $method_name(&self, rhs: Rhs) -> AxionResult<Series<bool>> {
-            let new_data: Vec<Option<bool>> = self.data.iter().map(|opt_val| {
-                opt_val.as_ref().map(|val| val $op &rhs)
+            let new_data: Vec<Option<bool>> = self.iter().map(|opt_val| {
+                opt_val.map(|val| val $op &rhs)
             }).collect();
             Ok(Series::new_from_options(format!("{}_{}", self.name, stringify!($method_name)), new_data))
         }
@@ -1185,8 +1474,8 @@ where
 macro_rules! impl_arith_scalar {
     ($method_name:ident, $op_trait:ident, $op_method:ident, $op_symbol:tt, $output_assoc_type:ident) => {
         fn $method_name(&self, rhs: Rhs) -> AxionResult<Series<Self::$output_assoc_type>> {
-            let new_data: Vec<Option<Self::$output_assoc_type>> = self.data.iter().map(|opt_val| {
-                opt_val.as_ref().map(|val| val.clone().$op_method(rhs.clone()))
+            let new_data: Vec<Option<Self::$output_assoc_type>> = self.iter().map(|opt_val| {
+                opt_val.map(|val| val.clone().$op_method(rhs.clone()))
             }).collect();
             Ok(Series::new_from_options(format!("{}_{}", self.name, stringify!($method_name)), new_data))
         }
@@ -1197,8 +1486,8 @@ macro_rules! impl_arith_scalar {
                 let new_data = vec![None; self.len()];
                 Ok(Series::new_from_options(format!("{}_{}_by_zero", self.name, stringify!($method_name)), new_data))
             } else {
-                let new_data: Vec<Option<Self::$output_assoc_type>> = self.data.iter().map(|opt_val| {
-                    opt_val.as_ref().map(|val| val.clone().$op_method(rhs.clone()))
+                let new_data: Vec<Option<Self::$output_assoc_type>> = self.iter().map(|opt_val| {
+                    opt_val.map(|val| val.clone().$op_method(rhs.clone()))
                 }).collect();
                 Ok(Series::new_from_options(format!("{}_{}", self.name, stringify!($method_name)), new_data))
             }
@@ -1242,10 +1531,10 @@ macro_rules! impl_compare_series {
                 });
             }
 
-            let new_data: Vec<Option<bool>> = self.data.iter()
-                .zip(rhs.data.iter())
+            let new_data: Vec<Option<bool>> = self.iter()
+                .zip(rhs.iter())
                 .map(|(opt_left, opt_right)| {
-                    match (opt_left.as_ref(), opt_right.as_ref()) {
+                    match (opt_left, opt_right) {
                         (Some(left_val), Some(right_val)) => Some(left_val $op right_val),
                         _ => None,
                     }
@@ -1457,11 +1746,11 @@ macro_rules! impl_arith_series {
             }
 
           
-            let new_data: Vec<Option<Self::$output_assoc_type>> = self.data.iter()
-                .zip(rhs.data.iter())
+            let new_data: Vec<Option<Self::$output_assoc_type>> = self.iter()
+                .zip(rhs.iter())
                 .map(|(opt_left, opt_right)| {
                     // 如果任一操作数为 None，结果为 None
-                    match (opt_left.as_ref(), opt_right.as_ref()) {
+                    match (opt_left, opt_right) {
                         (Some(left), Some(right)) => {
                             // 克隆左右操作数
                             // 如果 right 是整数 0，panic
@@ -1507,19 +1796,51 @@ where
 impl<T: DataTypeTrait + Clone + 'static> Series<T> {
     /// 返回一个布尔 Series，表示每个元素是否为 null
     pub fn is_null(&self) -> Series<bool> {
-        let data: Vec<Option<bool>> = self.data.iter().map(|v| Some(v.is_none())).collect();
-        Series::new_from_options(format!("{}_is_null", self.name), data)
+        let values: Vec<bool> = self.validity.iter().map(|is_valid| !is_valid).collect();
+        Series::from_raw(format!("{}_is_null", self.name), DataType::Bool, values, Bitmap::new_with_value(self.len(), true))
     }
 
     /// 返回一个布尔 Series，表示每个元素是否非 null
     pub fn not_null(&self) -> Series<bool> {
-        let data: Vec<Option<bool>> = self.data.iter().map(|v| Some(v.is_some())).collect();
-        Series::new_from_options(format!("{}_not_null", self.name), data)
+        let values: Vec<bool> = self.validity.iter().collect();
+        Series::from_raw(format!("{}_not_null", self.name), DataType::Bool, values, Bitmap::new_with_value(self.len(), true))
     }
 
     /// 用指定值填充 null，返回新 Series
     pub fn fill_null(&self, value: T) -> Series<T> {
-        let data: Vec<Option<T>> = self.data.iter().map(|v| v.clone().or(Some(value.clone()))).collect();
-        Series::new_from_options(self.name.clone(), data)
+        let values: Vec<T> = self.iter()
+            .map(|opt_val| opt_val.cloned().unwrap_or_else(|| value.clone()))
+            .collect();
+        Series::from_raw(self.name.clone(), self.dtype.clone(), values, Bitmap::new_with_value(self.len(), true))
     }
-}
\ No newline at end of file
+
+    /// 将掩码为 true 的位置原地设置为指定值（掩码中的 null 视为 false）
+    ///
+    /// # 参数
+    ///
+    /// * `mask` - 与 Series 等长的布尔掩码
+    /// * `value` - 要写入的值，传入 `None` 表示设置为 null
+    ///
+    /// # 错误
+    ///
+    /// 如果掩码长度与 Series 长度不一致，返回 `AxionError::MismatchedLengths`
+    pub fn set_where(&mut self, mask: &Series<bool>, value: Option<T>) -> AxionResult<()> {
+        if mask.len() != self.len() {
+            return Err(AxionError::MismatchedLengths {
+                expected: self.len(),
+                found: mask.len(),
+                name: format!("{} 的赋值掩码", self.name),
+            });
+        }
+        let indices = mask.true_indices();
+        if indices.is_empty() {
+            return Ok(());
+        }
+        self.flags.clear_sorted();
+        for idx in indices {
+            self.values[idx] = value.clone().unwrap_or_default();
+            self.validity.set(idx, value.is_some());
+        }
+        Ok(())
+    }
+}
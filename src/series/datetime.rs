@@ -0,0 +1,422 @@
+use super::core::Series;
+use super::interface::SeriesTrait;
+use crate::dtype::{DataType, TimeUnit};
+use crate::error::{AxionError, AxionResult};
+use crate::temporal::{civil_from_days, days_from_civil, parse_datetime, parse_tz_offset, Date, Datetime, Interval};
+
+/// 每天的纳秒数
+const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+/// 每秒的纳秒数
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+const WEEKDAY_NAMES: [&str; 7] = ["Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday"];
+const MONTH_NAMES: [&str; 12] = [
+    "January", "February", "March", "April", "May", "June",
+    "July", "August", "September", "October", "November", "December",
+];
+
+/// 时间 Series 的专用操作访问器
+///
+/// 提供时间分量提取、截断、取整、格式化和纪元换算等方法。
+/// 可用于 `Series<Datetime>`、`Series<Date>`，以及包含 ISO-8601 字符串的 `Series<String>`
+/// （字符串会即时解析，无法解析的值视为 null）。
+///
+/// 分量提取、截断和格式化均基于所在时区的本地时间，`epoch` 基于 UTC。
+///
+/// # 使用方式
+///
+/// 通过 `.dt()` 方法获取该访问器实例，或对 `DataFrame` 中的列使用 `DatetimeAccessor::from_series`。
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::Series;
+/// let s = Series::new("ts".to_string(), vec!["2024-03-15T10:45:00", "2024-03-16T23:10:00"]);
+/// let hours = s.dt().hour()?;
+/// let hourly = s.dt().truncate("1h")?;
+/// let labels = s.dt().strftime("%Y/%m/%d %H:%M")?;
+/// # Ok::<(), axion_data::AxionError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct DatetimeAccessor {
+    name: String,
+    /// 以纳秒表示的 UTC 时间点
+    instants: Vec<Option<i128>>,
+    /// 结果 Datetime Series 使用的时间单位
+    unit: TimeUnit,
+    /// 结果 Datetime Series 使用的时区
+    tz: Option<String>,
+    /// 本地时间相对 UTC 的偏移纳秒数
+    offset_nanos: i128,
+}
+
+impl DatetimeAccessor {
+    /// 由 Datetime Series 创建访问器，沿用其时间单位和时区
+    pub(crate) fn from_datetime(series: &Series<Datetime>) -> Self {
+        let (unit, tz) = match series.dtype() {
+            DataType::Datetime(unit, tz) => (unit, tz),
+            _ => (TimeUnit::Microseconds, None),
+        };
+        let nanos_per_unit = unit.nanos_per_unit() as i128;
+        let instants = series.iter().map(|opt| opt.map(|value| value.0 as i128 * nanos_per_unit)).collect();
+        Self::with_tz(series.name().to_string(), instants, unit, tz)
+    }
+
+    /// 由 Date Series 创建访问器，日期视为当天零点，结果使用微秒精度
+    pub(crate) fn from_date(series: &Series<Date>) -> Self {
+        let instants = series.iter().map(|opt| opt.map(|date| date.0 as i128 * NANOS_PER_DAY)).collect();
+        Self::with_tz(series.name().to_string(), instants, TimeUnit::Microseconds, None)
+    }
+
+    /// 由字符串 Series 创建访问器，按 ISO-8601 即时解析
+    ///
+    /// 所有有效值都带有时区偏移时视为 UTC 时间，否则视为无时区时间；
+    /// 带偏移的值总是先换算为 UTC。
+    pub(crate) fn from_strings(series: &Series<String>) -> Self {
+        let parsed: Vec<Option<(i64, bool)>> = series
+            .iter()
+            .map(|opt| opt.and_then(|s| parse_datetime(s, TimeUnit::Nanoseconds)))
+            .collect();
+        let mut valid = parsed.iter().flatten().peekable();
+        let all_with_offset = valid.peek().is_some() && valid.all(|(_, has_offset)| *has_offset);
+        let tz = all_with_offset.then(|| "UTC".to_string());
+        let instants = parsed.into_iter().map(|opt| opt.map(|(value, _)| value as i128)).collect();
+        Self::with_tz(series.name().to_string(), instants, TimeUnit::Microseconds, tz)
+    }
+
+    /// 由任意 Series 创建访问器
+    ///
+    /// # 错误
+    ///
+    /// 如果 Series 不是 Date、Datetime 或 String 类型，返回 `AxionError::TypeMismatch`
+    pub fn from_series(series: &dyn SeriesTrait) -> AxionResult<Self> {
+        let any = series.as_any();
+        if let Some(datetimes) = any.downcast_ref::<Series<Datetime>>() {
+            Ok(Self::from_datetime(datetimes))
+        } else if let Some(dates) = any.downcast_ref::<Series<Date>>() {
+            Ok(Self::from_date(dates))
+        } else if let Some(strings) = any.downcast_ref::<Series<String>>() {
+            Ok(Self::from_strings(strings))
+        } else {
+            Err(AxionError::TypeMismatch {
+                expected: DataType::Datetime(TimeUnit::Microseconds, None),
+                found: series.dtype(),
+                name: series.name().to_string(),
+            })
+        }
+    }
+
+    fn with_tz(name: String, instants: Vec<Option<i128>>, unit: TimeUnit, tz: Option<String>) -> Self {
+        let offset_nanos = tz.as_deref().and_then(parse_tz_offset).unwrap_or(0) as i128 * NANOS_PER_SECOND;
+        Self { name, instants, unit, tz, offset_nanos }
+    }
+
+    /// 以本地时间表示的纳秒时间点，null 为 `None`
+    pub(crate) fn local_instants(&self) -> Vec<Option<i128>> {
+        self.instants.iter().map(|opt| opt.map(|nanos| nanos + self.offset_nanos)).collect()
+    }
+
+    /// 由本地纳秒时间点构建结果 Datetime Series，超出 i64 范围的值为 null
+    pub(crate) fn datetime_series_from_local(&self, name: String, local: &[i128]) -> AxionResult<Series<Datetime>> {
+        let nanos_per_unit = self.unit.nanos_per_unit() as i128;
+        let data: Vec<Option<Datetime>> = local
+            .iter()
+            .map(|nanos| i64::try_from((nanos - self.offset_nanos).div_euclid(nanos_per_unit)).ok().map(Datetime))
+            .collect();
+        Series::new_from_options(name, data).with_dtype(self.output_dtype())
+    }
+
+    /// 结果 Datetime Series 的数据类型
+    fn output_dtype(&self) -> DataType {
+        DataType::Datetime(self.unit, self.tz.clone())
+    }
+
+    /// 将本地时间分量映射为新的 Series
+    fn map_local<R, F>(&self, suffix: &str, f: F) -> Series<R>
+    where
+        R: crate::dtype::DataTypeTrait,
+        F: Fn(LocalTime) -> R,
+    {
+        let data: Vec<Option<R>> = self.instants
+            .iter()
+            .map(|opt| opt.map(|nanos| f(LocalTime::new(nanos + self.offset_nanos))))
+            .collect();
+        Series::new_from_options(format!("{}_{}", self.name, suffix), data)
+    }
+
+    /// 将本地纳秒时间映射回结果 Datetime Series，超出 i64 范围的值为 null
+    fn local_to_datetime_series<F>(&self, name: String, f: F) -> AxionResult<Series<Datetime>>
+    where
+        F: Fn(i128) -> i128,
+    {
+        let nanos_per_unit = self.unit.nanos_per_unit() as i128;
+        let data: Vec<Option<Datetime>> = self.instants
+            .iter()
+            .map(|opt| {
+                opt.and_then(|nanos| {
+                    let utc = f(nanos + self.offset_nanos) - self.offset_nanos;
+                    i64::try_from(utc.div_euclid(nanos_per_unit)).ok().map(Datetime)
+                })
+            })
+            .collect();
+        Series::new_from_options(name, data).with_dtype(self.output_dtype())
+    }
+
+    /// 提取年份
+    pub fn year(&self) -> AxionResult<Series<i32>> {
+        Ok(self.map_local("year", |t| t.year as i32))
+    }
+
+    /// 提取月份（1-12）
+    pub fn month(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("month", |t| t.month))
+    }
+
+    /// 提取月内日期（1-31）
+    pub fn day(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("day", |t| t.day))
+    }
+
+    /// 提取小时（0-23）
+    pub fn hour(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("hour", |t| t.hour()))
+    }
+
+    /// 提取分钟（0-59）
+    pub fn minute(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("minute", |t| t.minute()))
+    }
+
+    /// 提取秒（0-59）
+    pub fn second(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("second", |t| t.second()))
+    }
+
+    /// 提取 ISO 星期（周一为 1，周日为 7）
+    pub fn weekday(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("weekday", |t| t.weekday()))
+    }
+
+    /// 提取年内天数（1-366）
+    pub fn ordinal_day(&self) -> AxionResult<Series<u32>> {
+        Ok(self.map_local("ordinal_day", |t| t.ordinal_day()))
+    }
+
+    /// 将时间向下截断到指定间隔的边界
+    ///
+    /// # 参数
+    ///
+    /// * `every` - 间隔字符串，如 `"1h"`、`"15m"`、`"1d"`、`"1w"`（对齐到周一）、`"1mo"`、`"1y"`
+    ///
+    /// # 返回值
+    ///
+    /// 返回 Datetime Series，沿用源列的时间单位和时区（Date 和字符串列为微秒精度）
+    ///
+    /// # 错误
+    ///
+    /// 间隔无效、非正或同时包含月与固定长度单位时返回 `AxionError::InvalidArgument`
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # let s = axion_data::Series::new("ts".to_string(), vec!["2024-03-15T10:45:00"]);
+    /// let hourly = s.dt().truncate("1h")?;
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn truncate(&self, every: &str) -> AxionResult<Series<Datetime>> {
+        let interval = Interval::parse(every)?;
+        interval.check_truncatable(every)?;
+        self.local_to_datetime_series(format!("{}_truncate", self.name), |t| interval.truncate(t))
+    }
+
+    /// 将时间取整到最近的间隔边界，恰好位于中点时向上取整
+    ///
+    /// # 参数
+    ///
+    /// * `every` - 间隔字符串，格式同 `truncate`
+    ///
+    /// # 错误
+    ///
+    /// 间隔无效、非正或同时包含月与固定长度单位时返回 `AxionError::InvalidArgument`
+    pub fn round(&self, every: &str) -> AxionResult<Series<Datetime>> {
+        let interval = Interval::parse(every)?;
+        interval.check_truncatable(every)?;
+        self.local_to_datetime_series(format!("{}_round", self.name), |t| interval.round(t))
+    }
+
+    /// 按格式字符串格式化为字符串
+    ///
+    /// # 参数
+    ///
+    /// * `fmt` - 格式字符串，支持 `%Y %y %m %d %e %H %I %p %M %S %f %j %u %a %A %b %B %z %F %T %s %%`
+    ///   （`%f` 为 9 位纳秒，`%z` 为 `+HHMM` 形式的偏移）
+    ///
+    /// # 错误
+    ///
+    /// 包含不支持的格式说明符时返回 `AxionError::InvalidArgument`
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # let s = axion_data::Series::new("ts".to_string(), vec!["2024-03-15T10:45:00"]);
+    /// let labels = s.dt().strftime("%Y-%m-%d %H:%M")?;
+    /// # assert_eq!(labels.get(0), Some(&"2024-03-15 10:45".to_string()));
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn strftime(&self, fmt: &str) -> AxionResult<Series<String>> {
+        let items = parse_format(fmt)?;
+        let offset_seconds = (self.offset_nanos / NANOS_PER_SECOND) as i64;
+        let data: Vec<Option<String>> = self.instants
+            .iter()
+            .map(|opt| {
+                opt.map(|nanos| {
+                    let local = LocalTime::new(nanos + self.offset_nanos);
+                    let mut output = String::new();
+                    for item in &items {
+                        match item {
+                            FormatItem::Literal(text) => output.push_str(text),
+                            FormatItem::Spec(spec) => local.write_spec(*spec, nanos, offset_seconds, &mut output),
+                        }
+                    }
+                    output
+                })
+            })
+            .collect();
+        Ok(Series::new_from_options(format!("{}_strftime", self.name), data))
+    }
+
+    /// 计算自 Unix 纪元起的时间（基于 UTC，向下取整）
+    ///
+    /// # 参数
+    ///
+    /// * `unit` - 结果单位：`"d"`、`"s"`、`"ms"`、`"us"` 或 `"ns"`
+    ///
+    /// # 错误
+    ///
+    /// 单位无效时返回 `AxionError::InvalidArgument`
+    pub fn epoch(&self, unit: &str) -> AxionResult<Series<i64>> {
+        let nanos_per_unit: i128 = match unit {
+            "d" => NANOS_PER_DAY,
+            "s" => NANOS_PER_SECOND,
+            "ms" => 1_000_000,
+            "us" => 1_000,
+            "ns" => 1,
+            _ => return Err(AxionError::InvalidArgument(format!(
+                "无效的纪元单位 '{}'，支持 d、s、ms、us、ns",
+                unit
+            ))),
+        };
+        let data: Vec<Option<i64>> = self.instants
+            .iter()
+            .map(|opt| opt.and_then(|nanos| i64::try_from(nanos.div_euclid(nanos_per_unit)).ok()))
+            .collect();
+        Ok(Series::new_from_options(format!("{}_epoch", self.name), data))
+    }
+}
+
+/// 拆分后的本地时间
+struct LocalTime {
+    days: i64,
+    year: i64,
+    month: u32,
+    day: u32,
+    nanos_of_day: i128,
+}
+
+impl LocalTime {
+    fn new(local_nanos: i128) -> Self {
+        let days = local_nanos.div_euclid(NANOS_PER_DAY) as i64;
+        let (year, month, day) = civil_from_days(days);
+        Self { days, year, month, day, nanos_of_day: local_nanos.rem_euclid(NANOS_PER_DAY) }
+    }
+
+    fn hour(&self) -> u32 {
+        (self.nanos_of_day / (3600 * NANOS_PER_SECOND)) as u32
+    }
+
+    fn minute(&self) -> u32 {
+        (self.nanos_of_day / (60 * NANOS_PER_SECOND) % 60) as u32
+    }
+
+    fn second(&self) -> u32 {
+        (self.nanos_of_day / NANOS_PER_SECOND % 60) as u32
+    }
+
+    fn weekday(&self) -> u32 {
+        // 1970-01-01 是周四
+        ((self.days + 3).rem_euclid(7) + 1) as u32
+    }
+
+    fn ordinal_day(&self) -> u32 {
+        (self.days - days_from_civil(self.year, 1, 1) + 1) as u32
+    }
+
+    fn write_spec(&self, spec: char, utc_nanos: i128, offset_seconds: i64, output: &mut String) {
+        let text = match spec {
+            'Y' => format!("{:04}", self.year),
+            'y' => format!("{:02}", self.year.rem_euclid(100)),
+            'm' => format!("{:02}", self.month),
+            'd' => format!("{:02}", self.day),
+            'e' => format!("{:>2}", self.day),
+            'H' => format!("{:02}", self.hour()),
+            'I' => format!("{:02}", (self.hour() + 11) % 12 + 1),
+            'p' => (if self.hour() < 12 { "AM" } else { "PM" }).to_string(),
+            'M' => format!("{:02}", self.minute()),
+            'S' => format!("{:02}", self.second()),
+            'f' => format!("{:09}", self.nanos_of_day % NANOS_PER_SECOND),
+            'j' => format!("{:03}", self.ordinal_day()),
+            'u' => self.weekday().to_string(),
+            'a' => WEEKDAY_NAMES[self.weekday() as usize - 1][..3].to_string(),
+            'A' => WEEKDAY_NAMES[self.weekday() as usize - 1].to_string(),
+            'b' => MONTH_NAMES[self.month as usize - 1][..3].to_string(),
+            'B' => MONTH_NAMES[self.month as usize - 1].to_string(),
+            'z' => {
+                let sign = if offset_seconds < 0 { '-' } else { '+' };
+                format!("{}{:02}{:02}", sign, offset_seconds.abs() / 3600, offset_seconds.abs() % 3600 / 60)
+            }
+            'F' => format!("{:04}-{:02}-{:02}", self.year, self.month, self.day),
+            'T' => format!("{:02}:{:02}:{:02}", self.hour(), self.minute(), self.second()),
+            's' => utc_nanos.div_euclid(NANOS_PER_SECOND).to_string(),
+            _ => "%".to_string(),
+        };
+        output.push_str(&text);
+    }
+}
+
+/// strftime 格式字符串的组成部分
+enum FormatItem {
+    Literal(String),
+    Spec(char),
+}
+
+/// 解析 strftime 格式字符串
+fn parse_format(fmt: &str) -> AxionResult<Vec<FormatItem>> {
+    const SUPPORTED: &str = "YymdeHIpMSfjuaAbBzFTs%";
+    let mut items = Vec::new();
+    let mut literal = String::new();
+    let mut chars = fmt.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some(spec) if SUPPORTED.contains(spec) => {
+                if !literal.is_empty() {
+                    items.push(FormatItem::Literal(std::mem::take(&mut literal)));
+                }
+                items.push(FormatItem::Spec(spec));
+            }
+            other => {
+                return Err(AxionError::InvalidArgument(format!(
+                    "不支持的格式说明符 '%{}'",
+                    other.map(String::from).unwrap_or_default()
+                )));
+            }
+        }
+    }
+    if !literal.is_empty() {
+        items.push(FormatItem::Literal(literal));
+    }
+    Ok(items)
+}
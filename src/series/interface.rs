@@ -116,6 +116,17 @@ pub trait SeriesTrait: Display + Debug + Send + Sync + Any {
     /// 如果任何非 `None` 索引超出范围
     fn take_indices_option(&self, indices: &[Option<usize>]) -> AxionResult<Box<dyn SeriesTrait>>;
 
+    /// 将另一个相同类型的 Series 的所有元素追加到末尾
+    /// 
+    /// # 参数
+    /// 
+    /// * `other` - 要追加的 Series
+    /// 
+    /// # 错误
+    /// 
+    /// 如果 `other` 的数据类型与当前 Series 不同，返回 `AxionError::TypeMismatch`
+    fn append_series(&mut self, other: &dyn SeriesTrait) -> AxionResult<()>;
+
     /// 重命名 Series
     /// 
     /// # 参数
@@ -161,4 +172,30 @@ pub trait SeriesTrait: Display + Debug + Send + Sync + Any {
     /// - 如果值无法转换为 f64（如字符串），返回 `Ok(None)`
     /// - 成功转换时返回 `Ok(Some(f64))`
     fn get_as_f64(&self, index: usize) -> AxionResult<Option<f64>>;
+
+    /// 将 Series 严格转换为指定的数据类型
+    /// 
+    /// 无需知道 Series 的具体 Rust 类型即可重新指定列的类型，转换规则见 [`Series::cast`]。
+    /// 时间类型与字符串之间按 ISO-8601 格式转换，与数值类型之间按底层整数（天数或时间单位数）转换。
+    /// 
+    /// # 参数
+    /// 
+    /// * `dtype` - 目标数据类型
+    /// 
+    /// # 错误
+    /// 
+    /// - 不支持转换为目标数据类型时返回 `AxionError::UnsupportedOperation`
+    /// - 任何非 null 值无法转换时返回 `AxionError::CastError`
+    fn cast(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>>;
+
+    /// 将 Series 宽松转换为指定的数据类型，无法转换的值变为 null
+    /// 
+    /// # 参数
+    /// 
+    /// * `dtype` - 目标数据类型
+    /// 
+    /// # 错误
+    /// 
+    /// 不支持转换为目标数据类型时返回 `AxionError::UnsupportedOperation`
+    fn cast_lenient(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>>;
 }
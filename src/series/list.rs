@@ -1,11 +1,16 @@
-use crate::dtype::DataType;
+use crate::dataframe::groupby::{column_group_values, create_empty_series_from_dtype, Agg};
+use crate::dtype::{DataType, DataTypeTrait};
 use crate::error::{AxionError, AxionResult};
 use super::core::Series;
 use super::interface::SeriesTrait;
 use std::any::Any;
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::fmt::{self, Debug, Display};
 
+/// 拼接后的列表元素，以及每行元素在拼接结果中的位置
+type FlattenedList = (Box<dyn SeriesTrait>, Vec<Vec<usize>>);
+
 /// 表示包含其他 Series 的列表类型 Series
 /// 
 /// ListSeries 允许在 DataFrame 的单个列中存储复杂的嵌套数据结构。
@@ -50,6 +55,114 @@ impl ListSeries {
     pub fn get_inner_series(&self, index: usize) -> Option<&dyn SeriesTrait> {
         self.data.get(index).and_then(|opt_box| opt_box.as_deref())
     }
+
+    /// 将另一个 ListSeries 的所有元素追加到末尾
+    /// 
+    /// # 参数
+    /// 
+    /// * `other` - 要追加的 ListSeries，其名称会被忽略
+    /// 
+    /// # 错误
+    /// 
+    /// 如果两者的内部元素类型不同，返回 `AxionError::TypeMismatch`
+    pub fn append(&mut self, other: &ListSeries) -> AxionResult<()> {
+        if other.inner_dtype != self.inner_dtype {
+            return Err(AxionError::TypeMismatch {
+                expected: self.dtype(),
+                found: other.dtype(),
+                name: self.name.clone(),
+            });
+        }
+        self.data.extend(other.data.iter().cloned());
+        Ok(())
+    }
+
+    /// 将迭代器中的所有列表元素追加到末尾，`None` 表示 null
+    /// 
+    /// # 参数
+    /// 
+    /// * `iter` - 产生 `Option<Box<dyn SeriesTrait>>` 的迭代器
+    /// 
+    /// # 错误
+    /// 
+    /// 如果某个元素的数据类型与内部元素类型不同，返回 `AxionError::TypeMismatch`，
+    /// 此时不会追加任何元素
+    pub fn extend<I>(&mut self, iter: I) -> AxionResult<()>
+    where
+        I: IntoIterator<Item = Option<Box<dyn SeriesTrait>>>,
+    {
+        let new_items: Vec<Option<Box<dyn SeriesTrait>>> = iter.into_iter().collect();
+        if let Some(mismatched) = new_items.iter().flatten().find(|inner| inner.dtype() != self.inner_dtype) {
+            return Err(AxionError::TypeMismatch {
+                expected: self.inner_dtype.clone(),
+                found: mismatched.dtype(),
+                name: self.name.clone(),
+            });
+        }
+        self.data.extend(new_items);
+        Ok(())
+    }
+
+    /// 获取列表操作访问器
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use axion_data::{new_list_series, Series};
+    /// # let tags = new_list_series("tags".to_string(), vec![
+    /// #     Box::new(Series::new(String::new(), vec!["a", "b"])),
+    /// #     Box::new(Series::new(String::new(), vec!["c"])),
+    /// # ])?;
+    /// let lengths = tags.list().len()?;
+    /// let first = tags.list().get(0)?;
+    /// # Ok::<(), axion_data::AxionError>(())
+    /// ```
+    pub fn list(&self) -> ListAccessor<'_> {
+        ListAccessor::new(self)
+    }
+
+    /// 将所有内部 Series 依次拼接为一个 Series，同时返回每行元素在拼接结果中的位置
+    ///
+    /// null 行对应空的位置列表。拼接结果以本 Series 的名称命名。
+    ///
+    /// # 错误
+    ///
+    /// 所有行均为 null 且无法为内部元素类型创建空 Series 时返回 `AxionError::UnsupportedOperation`
+    pub(crate) fn flatten(&self) -> AxionResult<FlattenedList> {
+        let mut flat: Option<Box<dyn SeriesTrait>> = None;
+        let mut rows = Vec::with_capacity(self.len());
+        let mut offset = 0;
+        for inner in &self.data {
+            let inner_len = inner.as_ref().map_or(0, |inner| inner.len());
+            if let Some(inner) = inner {
+                match flat.as_mut() {
+                    Some(flat) => flat.append_series(inner.as_ref())?,
+                    None => flat = Some(inner.clone_box()),
+                }
+            }
+            rows.push((offset..offset + inner_len).collect());
+            offset += inner_len;
+        }
+        let mut flat = match flat {
+            Some(flat) => flat,
+            None => create_empty_series_from_dtype(self.name.clone(), self.inner_dtype.clone())?,
+        };
+        flat.rename(&self.name);
+        Ok((flat, rows))
+    }
+
+    /// 对每个非 null 的内部 Series 应用同一个变换，结果保持原名称和内部元素类型
+    fn map_inner<F>(&self, f: F) -> AxionResult<ListSeries>
+    where
+        F: Fn(&dyn SeriesTrait) -> AxionResult<Box<dyn SeriesTrait>>,
+    {
+        let data = self
+            .data
+            .iter()
+            .map(|inner| inner.as_deref().map(&f).transpose())
+            .collect::<AxionResult<Vec<_>>>()?;
+        Ok(ListSeries::new(self.name.clone(), data, self.inner_dtype.clone()))
+    }
 }
 
 // 为 ListSeries 实现 SeriesTrait
@@ -133,7 +246,7 @@ impl SeriesTrait for ListSeries {
              });
         }
         let mut new_data = Vec::with_capacity(self.len());
-        for (opt_val, opt_mask) in self.data.iter().zip(mask.data_internal().iter()) {
+        for (opt_val, opt_mask) in self.data.iter().zip(mask.iter()) {
             if let Some(true) = opt_mask {
                 new_data.push(opt_val.clone());
             }
@@ -173,6 +286,17 @@ impl SeriesTrait for ListSeries {
         Ok(Box::new(ListSeries::new(self.name.clone(), new_data, self.inner_dtype.clone())))
     }
 
+    fn append_series(&mut self, other: &dyn SeriesTrait) -> AxionResult<()> {
+        match other.as_any().downcast_ref::<ListSeries>() {
+            Some(other_list) => self.append(other_list),
+            None => Err(AxionError::TypeMismatch {
+                expected: self.dtype(),
+                found: other.dtype(),
+                name: self.name.clone(),
+            }),
+        }
+    }
+
     fn rename(&mut self, new_name: &str) {
         self.name = new_name.to_string();
     }
@@ -221,11 +345,25 @@ impl SeriesTrait for ListSeries {
         Ok(None)
     }    
 
+    fn cast(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        if dtype == self.dtype() {
+            return Ok(self.clone_box());
+        }
+        Err(AxionError::UnsupportedOperation(format!(
+            "不支持将列表 Series '{}' 从 {:?} 转换为 {:?}",
+            self.name,
+            self.dtype(),
+            dtype
+        )))
+    }
+
+    fn cast_lenient(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.cast(dtype)
+    }
+
     fn is_null_at(&self, index: usize) -> bool {
-        // self.data is Vec<Option<T>>
-        // If index is out of bounds, get returns None, map_or returns true (treat as null)
-        // If index is valid, opt_val_ref is &Option<T>, is_none() checks if this Option<T> is None
-        self.data.get(index).map_or(true, |opt_val_ref| opt_val_ref.is_none())
+        // 索引越界时 get 返回 None，同样视为 null
+        self.data.get(index).is_none_or(|opt_val_ref| opt_val_ref.is_none())
     }
 }
 
@@ -292,4 +430,218 @@ pub fn new_list_series(name: String, data: Vec<Box<dyn SeriesTrait>>) -> AxionRe
     let data_opts = data.into_iter().map(Some).collect();
 
     Ok(ListSeries::new(name, data_opts, inner_dtype))
-}
\ No newline at end of file
+}
+
+/// 列表 Series 的专用操作访问器
+///
+/// 对每一行的内部 Series 分别进行计算，null 行的结果仍为 null。
+///
+/// # 使用方式
+///
+/// 通过 `ListSeries` 的 `.list()` 方法获取该访问器实例。
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::{new_list_series, Series};
+/// let scores = new_list_series("scores".to_string(), vec![
+///     Box::new(Series::new("".to_string(), vec![90_i64, 75])),
+///     Box::new(Series::new("".to_string(), vec![60_i64])),
+/// ])?;
+/// let totals = scores.list().sum()?;
+/// let has_sixty = scores.list().contains(60_i64)?;
+/// # Ok::<(), axion_data::AxionError>(())
+/// ```
+pub struct ListAccessor<'a> {
+    series: &'a ListSeries,
+}
+
+impl<'a> ListAccessor<'a> {
+    /// 创建新的 ListAccessor 实例
+    pub(super) fn new(series: &'a ListSeries) -> Self {
+        Self { series }
+    }
+
+    /// 获取每个列表的元素个数（包括 null 元素）
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的 u32 类型 Series，null 行保持为 null
+    pub fn len(&self) -> AxionResult<Series<u32>> {
+        let new_name = format!("{}_len", self.series.name());
+        let new_data: Vec<Option<u32>> = self.series.data.iter()
+            .map(|inner| inner.as_ref().map(|inner| inner.len() as u32))
+            .collect();
+        Ok(Series::new_from_options(new_name, new_data))
+    }
+
+    /// 获取每个列表中指定位置的元素
+    ///
+    /// # 参数
+    ///
+    /// * `index` - 元素位置（从 0 开始，负数表示从末尾倒数）
+    ///
+    /// # 返回值
+    ///
+    /// 返回内部元素类型的 Series，位置超出范围或所在行为 null 时结果为 null
+    pub fn get(&self, index: i64) -> AxionResult<Box<dyn SeriesTrait>> {
+        let (flat, rows) = self.series.flatten()?;
+        let selected: Vec<Option<usize>> = rows
+            .iter()
+            .map(|positions| {
+                let pos = if index >= 0 {
+                    Some(index as usize)
+                } else {
+                    positions.len().checked_sub(index.unsigned_abs() as usize)
+                };
+                pos.and_then(|pos| positions.get(pos).copied())
+            })
+            .collect();
+        let mut result = flat.take_indices_option(&selected)?;
+        result.rename(&format!("{}_get_{}", self.series.name(), index));
+        Ok(result)
+    }
+
+    /// 检查每个列表是否包含指定值
+    ///
+    /// # 参数
+    ///
+    /// * `value` - 要查找的值，类型必须与内部元素类型一致
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的布尔类型 Series，null 行保持为 null
+    ///
+    /// # 错误
+    ///
+    /// 如果值的类型与内部元素类型不一致，返回 `AxionError::TypeMismatch`
+    pub fn contains<T>(&self, value: T) -> AxionResult<Series<bool>>
+    where
+        T: DataTypeTrait + PartialEq + 'static,
+    {
+        let (flat, rows) = self.series.flatten()?;
+        let flat = flat.as_any().downcast_ref::<Series<T>>().ok_or_else(|| AxionError::TypeMismatch {
+            expected: self.series.inner_dtype.clone(),
+            found: value.as_dtype(),
+            name: self.series.name().to_string(),
+        })?;
+        let new_data: Vec<Option<bool>> = rows
+            .iter()
+            .enumerate()
+            .map(|(row, positions)| {
+                (!self.series.is_null_at(row)).then(|| positions.iter().any(|&idx| flat.get(idx) == Some(&value)))
+            })
+            .collect();
+        Ok(Series::new_from_options(format!("{}_contains", self.series.name()), new_data))
+    }
+
+    /// 计算每个列表中元素的和，结果类型规则与 [`Agg::Sum`] 相同
+    ///
+    /// 忽略 null 元素，空列表或 null 行的结果为 null。
+    ///
+    /// # 错误
+    ///
+    /// 如果内部元素类型不是数值类型，返回 `AxionError::UnsupportedOperation`
+    pub fn sum(&self) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.aggregate(Agg::Sum, "sum")
+    }
+
+    /// 计算每个列表中元素的平均值，结果类型规则与 [`Agg::Mean`] 相同
+    ///
+    /// 忽略 null 元素，空列表或 null 行的结果为 null。
+    ///
+    /// # 错误
+    ///
+    /// 如果内部元素类型不是数值类型，返回 `AxionError::UnsupportedOperation`
+    pub fn mean(&self) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.aggregate(Agg::Mean, "mean")
+    }
+
+    /// 获取每个列表中的最小元素，结果类型与内部元素类型相同
+    ///
+    /// 忽略 null 元素，空列表或 null 行的结果为 null。
+    ///
+    /// # 错误
+    ///
+    /// 如果内部元素类型不可比较，返回 `AxionError::UnsupportedOperation`
+    pub fn min(&self) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.aggregate(Agg::Min, "min")
+    }
+
+    /// 获取每个列表中的最大元素，结果类型与内部元素类型相同
+    ///
+    /// 忽略 null 元素，空列表或 null 行的结果为 null。
+    ///
+    /// # 错误
+    ///
+    /// 如果内部元素类型不可比较，返回 `AxionError::UnsupportedOperation`
+    pub fn max(&self) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.aggregate(Agg::Max, "max")
+    }
+
+    /// 用分隔符将每个列表的元素连接为一个字符串
+    ///
+    /// # 参数
+    ///
+    /// * `separator` - 元素之间的分隔符
+    ///
+    /// # 返回值
+    ///
+    /// 返回新的字符串 Series，null 元素会被跳过，null 行保持为 null
+    pub fn join(&self, separator: &str) -> AxionResult<Series<String>> {
+        let new_data: Vec<Option<String>> = self.series.data.iter()
+            .map(|inner| {
+                inner.as_ref().map(|inner| {
+                    (0..inner.len())
+                        .filter(|&idx| !inner.is_null_at(idx))
+                        .filter_map(|idx| inner.get_str(idx))
+                        .collect::<Vec<String>>()
+                        .join(separator)
+                })
+            })
+            .collect();
+        Ok(Series::new_from_options(format!("{}_join", self.series.name()), new_data))
+    }
+
+    /// 去除每个列表中的重复元素，保留每个值首次出现的位置（null 最多保留一个）
+    ///
+    /// # 错误
+    ///
+    /// 如果内部元素类型不支持分组比较（如 List、Struct），返回 `AxionError::UnsupportedOperation`
+    pub fn unique(&self) -> AxionResult<ListSeries> {
+        self.series.map_inner(|inner| {
+            let values = column_group_values(inner)?;
+            let mut seen = HashSet::with_capacity(values.len());
+            let keep: Vec<usize> = values
+                .into_iter()
+                .enumerate()
+                .filter_map(|(idx, value)| seen.insert(value).then_some(idx))
+                .collect();
+            inner.take_indices(&keep)
+        })
+    }
+
+    /// 对每个列表的元素排序，null 元素排在最后（降序时排在最前）
+    ///
+    /// # 参数
+    ///
+    /// * `descending` - 是否降序排列
+    pub fn sort(&self, descending: bool) -> AxionResult<ListSeries> {
+        self.series.map_inner(|inner| {
+            let mut indices: Vec<usize> = (0..inner.len()).collect();
+            indices.sort_by(|&a, &b| {
+                let order = inner.compare_row(a, b);
+                if descending { order.reverse() } else { order }
+            });
+            inner.take_indices(&indices)
+        })
+    }
+
+    /// 将每一行视为一个组，使用分组聚合计算结果
+    fn aggregate(&self, agg: Agg, suffix: &str) -> AxionResult<Box<dyn SeriesTrait>> {
+        let (flat, rows) = self.series.flatten()?;
+        let group_rows: Vec<&Vec<usize>> = rows.iter().collect();
+        let output_name = format!("{}_{}", self.series.name(), suffix);
+        agg.aggregate(flat.as_ref(), &output_name, &group_rows)
+    }
+}
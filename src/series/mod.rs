@@ -4,29 +4,45 @@
 //! 
 //! # 模块组织
 //! 
+//! - `bitmap` - 有效性位图实现
+//! - `categorical` - 分类类型 Series 实现
 //! - `core` - Series 核心实现
+//! - `datetime` - 时间操作扩展
 //! - `interface` - Series trait 定义
 //! - `list` - 列表类型 Series 实现
 //! - `ops` - Series 操作 trait 定义
 //! - `string` - 字符串操作扩展
+//! - `struct_series` - 结构体类型 Series 实现
 
+pub mod bitmap;
+pub mod categorical;
 pub mod core;
+pub mod datetime;
 pub mod interface;
 pub mod list;
 pub mod ops;
 pub mod string;
+pub mod struct_series;
 
 // 重新导出核心类型和 trait
+pub use self::bitmap::Bitmap;
+
 pub use self::core::{
     Series,
+    SeriesIter,
     SeriesFlags,
     IntoSeriesData,
     IntoSeriesBox,
 };
 
+pub use self::categorical::{CategoricalDictionary, CategoricalSeries};
+
 pub use self::interface::SeriesTrait;
 
+pub use self::struct_series::StructSeries;
+
 pub use self::list::{
+    ListAccessor,
     ListSeries,
     new_list_series,
 };
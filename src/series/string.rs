@@ -14,9 +14,11 @@ use crate::dtype::DataType;
 /// # 示例
 /// 
 /// ```rust
+/// # use axion_data::Series;
 /// let s = Series::new("names".to_string(), vec!["Alice", "Bob", "Charlie"]);
 /// let contains_result = s.str().contains("a")?;
 /// let uppercase_result = s.str().to_uppercase()?;
+/// # Ok::<(), axion_data::AxionError>(())
 /// ```
 pub struct StringAccessor<'a> {
     series: &'a Series<String>,
@@ -51,8 +53,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::Bool));
         }
-        let new_data: Vec<Option<bool>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.contains(pattern)))
+        let new_data: Vec<Option<bool>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.contains(pattern)))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -71,8 +73,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::Bool));
         }
-        let new_data: Vec<Option<bool>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.starts_with(pattern)))
+        let new_data: Vec<Option<bool>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.starts_with(pattern)))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -91,8 +93,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::Bool));
         }
-        let new_data: Vec<Option<bool>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.ends_with(pattern)))
+        let new_data: Vec<Option<bool>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.ends_with(pattern)))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -107,8 +109,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::UInt32));
         }
-        let new_data: Vec<Option<u32>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.len() as u32))
+        let new_data: Vec<Option<u32>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.len() as u32))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -128,8 +130,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::String));
         }
-        let new_data: Vec<Option<String>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.replace(from, to)))
+        let new_data: Vec<Option<String>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.replace(from, to)))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -144,8 +146,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::String));
         }
-        let new_data: Vec<Option<String>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.to_lowercase()))
+        let new_data: Vec<Option<String>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.to_lowercase()))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -160,8 +162,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::String));
         }
-        let new_data: Vec<Option<String>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.to_uppercase()))
+        let new_data: Vec<Option<String>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.to_uppercase()))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -176,8 +178,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::String));
         }
-        let new_data: Vec<Option<String>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.trim().to_string()))
+        let new_data: Vec<Option<String>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.trim().to_string()))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -192,8 +194,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::String));
         }
-        let new_data: Vec<Option<String>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.trim_start().to_string()))
+        let new_data: Vec<Option<String>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.trim_start().to_string()))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -208,8 +210,8 @@ impl<'a> StringAccessor<'a> {
         if self.series.is_empty() {
             return Ok(Series::new_empty(new_name, DataType::String));
         }
-        let new_data: Vec<Option<String>> = self.series.data.iter()
-            .map(|opt_s| opt_s.as_ref().map(|s| s.trim_end().to_string()))
+        let new_data: Vec<Option<String>> = self.series.iter()
+            .map(|opt_s| opt_s.map(|s| s.trim_end().to_string()))
             .collect();
         Ok(Series::new_from_options(new_name, new_data))
     }
@@ -0,0 +1,570 @@
+use crate::decimal::Decimal;
+use crate::dtype::{DataType, DataTypeTrait};
+use crate::error::{AxionError, AxionResult};
+use crate::temporal::{Date, Datetime, Duration};
+use super::bitmap::Bitmap;
+use super::categorical::CategoricalSeries;
+use super::core::Series;
+use super::interface::SeriesTrait;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+/// 结构体（记录）类型的 Series
+///
+/// 每行由一组固定的命名字段组成，每个字段本身是一个等长的 Series，
+/// 字段名即该字段 Series 的名称。整行可以为 null，此时读取字段时对应位置也为 null。
+///
+/// # 示例
+///
+/// ```rust
+/// # use axion_data::{DataFrame, Series, SeriesTrait, StructSeries};
+/// let address = StructSeries::new("address".to_string(), vec![
+///     Box::new(Series::new("street".to_string(), vec!["Main St", "Elm St"])) as Box<dyn SeriesTrait>,
+///     Box::new(Series::new("zip".to_string(), vec![10001_i64, 94105])),
+/// ])?;
+/// let zip = address.field("zip")?;
+/// let df = DataFrame::new(vec![Box::new(address)])?.unnest(&["address"])?;
+/// # Ok::<(), axion_data::AxionError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct StructSeries {
+    name: String,
+    /// 各字段的数据，长度均与 Series 相同
+    fields: Vec<Box<dyn SeriesTrait>>,
+    /// 行级有效性，false 表示整行为 null
+    validity: Bitmap,
+}
+
+impl StructSeries {
+    /// 由字段 Series 创建结构体 Series，所有行均为有效值
+    ///
+    /// # 参数
+    ///
+    /// * `name` - Series 名称
+    /// * `fields` - 字段 Series，字段名取自各 Series 的名称
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::NoColumnsProvided` - 没有提供任何字段
+    /// * `AxionError::MismatchedLengths` - 字段长度不一致
+    /// * `AxionError::DuplicateColumnName` - 字段名重复
+    pub fn new(name: String, fields: Vec<Box<dyn SeriesTrait>>) -> AxionResult<Self> {
+        let len = fields.first().map(|field| field.len()).ok_or(AxionError::NoColumnsProvided)?;
+        let mut names = HashSet::with_capacity(fields.len());
+        for field in &fields {
+            if field.len() != len {
+                return Err(AxionError::MismatchedLengths {
+                    expected: len,
+                    found: field.len(),
+                    name: field.name().to_string(),
+                });
+            }
+            if !names.insert(field.name()) {
+                return Err(AxionError::DuplicateColumnName(field.name().to_string()));
+            }
+        }
+        Ok(StructSeries { name, fields, validity: Bitmap::new_with_value(len, true) })
+    }
+
+    /// 设置行级有效性，`false` 表示整行为 null
+    ///
+    /// # 错误
+    ///
+    /// 如果有效性长度与 Series 长度不一致，返回 `AxionError::MismatchedLengths`
+    pub fn with_validity(mut self, validity: Bitmap) -> AxionResult<Self> {
+        if validity.len() != self.len() {
+            return Err(AxionError::MismatchedLengths {
+                expected: self.len(),
+                found: validity.len(),
+                name: format!("{} 的有效性位图", self.name),
+            });
+        }
+        self.validity = validity;
+        Ok(self)
+    }
+
+    /// 获取所有字段名
+    pub fn field_names(&self) -> Vec<&str> {
+        self.fields.iter().map(|field| field.name()).collect()
+    }
+
+    /// 获取指定字段，整行为 null 的位置在结果中也为 null
+    ///
+    /// # 错误
+    ///
+    /// 如果字段不存在，返回 `AxionError::ColumnNotFound`
+    pub fn field(&self, name: &str) -> AxionResult<Box<dyn SeriesTrait>> {
+        let field = self
+            .fields
+            .iter()
+            .find(|field| field.name() == name)
+            .ok_or_else(|| AxionError::ColumnNotFound(format!("结构体 '{}' 的字段 '{}'", self.name, name)))?;
+        self.mask_nulls(field.as_ref())
+    }
+
+    /// 获取所有字段，整行为 null 的位置在结果中也为 null
+    pub fn fields(&self) -> AxionResult<Vec<Box<dyn SeriesTrait>>> {
+        self.fields.iter().map(|field| self.mask_nulls(field.as_ref())).collect()
+    }
+
+    /// 将整行为 null 的位置在字段中也设置为 null
+    fn mask_nulls(&self, field: &dyn SeriesTrait) -> AxionResult<Box<dyn SeriesTrait>> {
+        if self.validity.unset_count() == 0 {
+            return Ok(field.clone_box());
+        }
+        let indices: Vec<Option<usize>> = self.validity.iter().enumerate().map(|(idx, valid)| valid.then_some(idx)).collect();
+        field.take_indices_option(&indices)
+    }
+
+    /// 对所有字段应用同一个变换，行级有效性由调用方给出
+    fn map_fields<F>(&self, validity: Bitmap, f: F) -> AxionResult<Box<dyn SeriesTrait>>
+    where
+        F: Fn(&dyn SeriesTrait) -> AxionResult<Box<dyn SeriesTrait>>,
+    {
+        let fields = self.fields.iter().map(|field| f(field.as_ref())).collect::<AxionResult<Vec<_>>>()?;
+        Ok(Box::new(StructSeries { name: self.name.clone(), fields, validity }))
+    }
+}
+
+impl SeriesTrait for StructSeries {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn dtype(&self) -> DataType {
+        DataType::Struct(self.fields.iter().map(|field| (field.name().to_string(), field.dtype())).collect())
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn SeriesTrait> {
+        Box::new(self.clone())
+    }
+
+    /// 格式化为 `{字段: 值, ...}`，整行为 null 时为 `"null"`
+    fn get_str(&self, index: usize) -> Option<String> {
+        if index >= self.len() {
+            return None;
+        }
+        if !self.validity.get(index) {
+            return Some("null".to_string());
+        }
+        let values: Vec<String> = self
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name(), field.get_str(index).unwrap_or_else(|| "null".to_string())))
+            .collect();
+        Some(format!("{{{}}}", values.join(", ")))
+    }
+
+    fn is_null_at(&self, index: usize) -> bool {
+        index >= self.len() || !self.validity.get(index)
+    }
+
+    fn slice(&self, start: usize, length: usize) -> Box<dyn SeriesTrait> {
+        let start = std::cmp::min(start, self.len());
+        let end = std::cmp::min(start + length, self.len());
+        let fields = self.fields.iter().map(|field| field.slice(start, length)).collect();
+        Box::new(StructSeries { name: self.name.clone(), fields, validity: self.validity.slice(start, end) })
+    }
+
+    fn filter(&self, mask: &Series<bool>) -> AxionResult<Box<dyn SeriesTrait>> {
+        if mask.len() != self.len() {
+            return Err(AxionError::MismatchedLengths {
+                expected: self.len(),
+                found: mask.len(),
+                name: format!("filter mask for struct series '{}'", self.name),
+            });
+        }
+        self.take_indices(&mask.true_indices())
+    }
+
+    fn take_indices(&self, indices: &[usize]) -> AxionResult<Box<dyn SeriesTrait>> {
+        let mut validity = Bitmap::with_capacity(indices.len());
+        for &idx in indices {
+            if idx >= self.len() {
+                return Err(AxionError::IndexOutOfBounds(idx, self.len()));
+            }
+            validity.push(self.validity.get(idx));
+        }
+        self.map_fields(validity, |field| field.take_indices(indices))
+    }
+
+    fn take_indices_option(&self, indices: &[Option<usize>]) -> AxionResult<Box<dyn SeriesTrait>> {
+        let mut validity = Bitmap::with_capacity(indices.len());
+        for opt_idx in indices {
+            match opt_idx {
+                Some(idx) if *idx >= self.len() => return Err(AxionError::IndexOutOfBounds(*idx, self.len())),
+                Some(idx) => validity.push(self.validity.get(*idx)),
+                None => validity.push(false),
+            }
+        }
+        self.map_fields(validity, |field| field.take_indices_option(indices))
+    }
+
+    fn append_series(&mut self, other: &dyn SeriesTrait) -> AxionResult<()> {
+        let other_struct = other
+            .as_any()
+            .downcast_ref::<StructSeries>()
+            .filter(|other_struct| other_struct.dtype() == self.dtype())
+            .ok_or_else(|| AxionError::TypeMismatch {
+                expected: self.dtype(),
+                found: other.dtype(),
+                name: self.name.clone(),
+            })?;
+        for (field, other_field) in self.fields.iter_mut().zip(&other_struct.fields) {
+            field.append_series(other_field.as_ref())?;
+        }
+        self.validity.extend_from_bitmap(&other_struct.validity);
+        Ok(())
+    }
+
+    fn rename(&mut self, new_name: &str) {
+        self.name = new_name.to_string();
+    }
+
+    /// 比较数据类型、行级有效性和各字段的值（整行为 null 的位置忽略字段值）
+    fn series_equal(&self, other: &dyn SeriesTrait) -> bool {
+        let Some(other_struct) = other.as_any().downcast_ref::<StructSeries>() else {
+            return false;
+        };
+        if self.dtype() != other_struct.dtype() || !self.validity.iter().eq(other_struct.validity.iter()) {
+            return false;
+        }
+        match (self.fields(), other_struct.fields()) {
+            (Ok(fields), Ok(other_fields)) => fields
+                .iter()
+                .zip(&other_fields)
+                .all(|(field, other_field)| field.series_equal(other_field.as_ref())),
+            _ => false,
+        }
+    }
+
+    /// 按字段顺序逐个比较，整行为 null 的排在最后
+    fn compare_row(&self, a_idx: usize, b_idx: usize) -> Ordering {
+        match (self.is_null_at(a_idx), self.is_null_at(b_idx)) {
+            (false, false) => self
+                .fields
+                .iter()
+                .map(|field| field.compare_row(a_idx, b_idx))
+                .find(|order| *order != Ordering::Equal)
+                .unwrap_or(Ordering::Equal),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (true, true) => Ordering::Equal,
+        }
+    }
+
+    fn get_as_f64(&self, _index: usize) -> AxionResult<Option<f64>> {
+        Ok(None)
+    }
+
+    /// 仅支持转换为相同类型，或转换为 String（每行格式化为 `{字段: 值, ...}`）
+    fn cast(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        if dtype == self.dtype() {
+            return Ok(self.clone_box());
+        }
+        if dtype == DataType::String {
+            let data: Vec<Option<String>> = (0..self.len())
+                .map(|idx| if self.is_null_at(idx) { None } else { self.get_str(idx) })
+                .collect();
+            return Ok(Box::new(Series::new_from_options(self.name.clone(), data)));
+        }
+        Err(AxionError::UnsupportedOperation(format!(
+            "不支持将结构体 Series '{}' 从 {:?} 转换为 {:?}",
+            self.name,
+            self.dtype(),
+            dtype
+        )))
+    }
+
+    fn cast_lenient(&self, dtype: DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        self.cast(dtype)
+    }
+}
+
+impl Display for StructSeries {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+        for idx in 0..self.len() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", self.get_str(idx).unwrap_or_default())?;
+        }
+        write!(f, "]")
+    }
+}
+
+// === 序列化 ===
+
+/// 单个字段的序列化数据，变体名标识存储类型，具体的类型参数（时间单位、精度等）保存在结构体的 dtype 中
+#[derive(Serialize, Deserialize)]
+enum FieldValues {
+    Bool(Vec<Option<bool>>),
+    Int8(Vec<Option<i8>>),
+    Int16(Vec<Option<i16>>),
+    Int32(Vec<Option<i32>>),
+    Int64(Vec<Option<i64>>),
+    Int128(Vec<Option<i128>>),
+    UInt8(Vec<Option<u8>>),
+    UInt16(Vec<Option<u16>>),
+    UInt32(Vec<Option<u32>>),
+    UInt64(Vec<Option<u64>>),
+    UInt128(Vec<Option<u128>>),
+    Float32(Vec<Option<FloatValue>>),
+    Float64(Vec<Option<FloatValue>>),
+    Decimal(Vec<Option<i128>>),
+    String(Vec<Option<String>>),
+    Categorical(Vec<Option<String>>),
+    Date(Vec<Option<i32>>),
+    Datetime(Vec<Option<i64>>),
+    Duration(Vec<Option<i64>>),
+    Struct(Box<StructSeriesData>),
+}
+
+/// 浮点字段值的序列化形式
+///
+/// JSON 无法表示非有限浮点数（serde_json 会把 NaN 和无穷大写成 null，与缺失值混淆），
+/// 因此有限值按数字写出，NaN、正无穷和负无穷分别写成字符串 `"NaN"`、`"inf"` 和 `"-inf"`。
+/// Float32 字段先无损扩展为 f64 再写出。
+struct FloatValue(f64);
+
+impl Serialize for FloatValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            v if v.is_finite() => serializer.serialize_f64(v),
+            v if v.is_nan() => serializer.serialize_str("NaN"),
+            v if v > 0.0 => serializer.serialize_str("inf"),
+            _ => serializer.serialize_str("-inf"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FloatValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FloatValueVisitor;
+
+        impl de::Visitor<'_> for FloatValueVisitor {
+            type Value = FloatValue;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "数字或 \"NaN\"、\"inf\"、\"-inf\" 之一")
+            }
+
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<FloatValue, E> {
+                Ok(FloatValue(v))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<FloatValue, E> {
+                Ok(FloatValue(v as f64))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<FloatValue, E> {
+                Ok(FloatValue(v as f64))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<FloatValue, E> {
+                match v {
+                    "NaN" => Ok(FloatValue(f64::NAN)),
+                    "inf" => Ok(FloatValue(f64::INFINITY)),
+                    "-inf" => Ok(FloatValue(f64::NEG_INFINITY)),
+                    _ => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(FloatValueVisitor)
+    }
+}
+
+/// 结构体 Series 的序列化形式
+#[derive(Serialize, Deserialize)]
+struct StructSeriesData {
+    name: String,
+    dtype: DataType,
+    validity: Vec<bool>,
+    fields: Vec<FieldValues>,
+}
+
+/// 向下转型为具体类型的 Series，失败时返回内部错误
+fn downcast_field<T: DataTypeTrait>(field: &dyn SeriesTrait) -> AxionResult<&Series<T>> {
+    field.as_any().downcast_ref::<Series<T>>().ok_or_else(|| {
+        AxionError::InternalError(format!("无法将字段 '{}' 向下转型为 {}", field.name(), std::any::type_name::<T>()))
+    })
+}
+
+impl FieldValues {
+    /// 从字段 Series 提取序列化数据
+    fn from_field(field: &dyn SeriesTrait) -> AxionResult<Self> {
+        macro_rules! values {
+            ($variant:ident, $t:ty) => {
+                FieldValues::$variant(downcast_field::<$t>(field)?.iter().map(|opt| opt.cloned()).collect())
+            };
+            ($variant:ident, $t:ty, $v:ident => $conv:expr) => {
+                FieldValues::$variant(downcast_field::<$t>(field)?.iter().map(|opt| opt.map(|$v| $conv)).collect())
+            };
+        }
+
+        Ok(match field.dtype() {
+            DataType::Bool => values!(Bool, bool),
+            DataType::Int8 => values!(Int8, i8),
+            DataType::Int16 => values!(Int16, i16),
+            DataType::Int32 => values!(Int32, i32),
+            DataType::Int64 => values!(Int64, i64),
+            DataType::Int128 => values!(Int128, i128),
+            DataType::UInt8 => values!(UInt8, u8),
+            DataType::UInt16 => values!(UInt16, u16),
+            DataType::UInt32 => values!(UInt32, u32),
+            DataType::UInt64 => values!(UInt64, u64),
+            DataType::UInt128 => values!(UInt128, u128),
+            DataType::Float32 => values!(Float32, f32, v => FloatValue(*v as f64)),
+            DataType::Float64 => values!(Float64, f64, v => FloatValue(*v)),
+            DataType::Decimal(_, _) => values!(Decimal, Decimal, v => v.0),
+            DataType::String => values!(String, String),
+            DataType::Date => values!(Date, Date, v => v.0),
+            DataType::Datetime(_, _) => values!(Datetime, Datetime, v => v.0),
+            DataType::Duration(_) => values!(Duration, Duration, v => v.0),
+            DataType::Categorical => {
+                let series = field.as_any().downcast_ref::<CategoricalSeries>().ok_or_else(|| {
+                    AxionError::InternalError(format!("无法将字段 '{}' 向下转型为 CategoricalSeries", field.name()))
+                })?;
+                FieldValues::Categorical(series.iter().map(|opt| opt.map(str::to_string)).collect())
+            }
+            DataType::Struct(_) => {
+                let series = field.as_any().downcast_ref::<StructSeries>().ok_or_else(|| {
+                    AxionError::InternalError(format!("无法将字段 '{}' 向下转型为 StructSeries", field.name()))
+                })?;
+                FieldValues::Struct(Box::new(StructSeriesData::try_from(series)?))
+            }
+            other => {
+                return Err(AxionError::UnsupportedOperation(format!(
+                    "结构体字段 '{}' 的数据类型 {:?} 不支持序列化",
+                    field.name(),
+                    other
+                )))
+            }
+        })
+    }
+
+    /// 按字段名和数据类型还原字段 Series
+    ///
+    /// # 错误
+    ///
+    /// 数据的存储类型与 `dtype` 不一致时返回 `AxionError::TypeMismatch`
+    fn into_field(self, name: String, dtype: &DataType) -> AxionResult<Box<dyn SeriesTrait>> {
+        macro_rules! field {
+            ($data:expr) => {
+                Box::new(Series::new_from_options(name, $data).with_dtype(dtype.clone())?)
+            };
+            ($data:expr, $wrap:expr) => {
+                Box::new(Series::new_from_options(name, $data.into_iter().map(|opt| opt.map($wrap)).collect()).with_dtype(dtype.clone())?)
+            };
+        }
+
+        let field: Box<dyn SeriesTrait> = match self {
+            FieldValues::Bool(data) => field!(data),
+            FieldValues::Int8(data) => field!(data),
+            FieldValues::Int16(data) => field!(data),
+            FieldValues::Int32(data) => field!(data),
+            FieldValues::Int64(data) => field!(data),
+            FieldValues::Int128(data) => field!(data),
+            FieldValues::UInt8(data) => field!(data),
+            FieldValues::UInt16(data) => field!(data),
+            FieldValues::UInt32(data) => field!(data),
+            FieldValues::UInt64(data) => field!(data),
+            FieldValues::UInt128(data) => field!(data),
+            FieldValues::Float32(data) => field!(data, |v: FloatValue| v.0 as f32),
+            FieldValues::Float64(data) => field!(data, |v: FloatValue| v.0),
+            FieldValues::Decimal(data) => field!(data, Decimal),
+            FieldValues::String(data) => field!(data),
+            FieldValues::Date(data) => field!(data, Date),
+            FieldValues::Datetime(data) => field!(data, Datetime),
+            FieldValues::Duration(data) => field!(data, Duration),
+            FieldValues::Categorical(data) => Box::new(CategoricalSeries::from_strings(name, data)),
+            FieldValues::Struct(data) => {
+                let mut series = StructSeries::try_from(*data)?;
+                series.rename(&name);
+                Box::new(series)
+            }
+        };
+        if field.dtype() != *dtype {
+            return Err(AxionError::TypeMismatch {
+                expected: dtype.clone(),
+                found: field.dtype(),
+                name: field.name().to_string(),
+            });
+        }
+        Ok(field)
+    }
+}
+
+impl TryFrom<&StructSeries> for StructSeriesData {
+    type Error = AxionError;
+
+    fn try_from(series: &StructSeries) -> AxionResult<Self> {
+        Ok(StructSeriesData {
+            name: series.name.clone(),
+            dtype: series.dtype(),
+            validity: series.validity.iter().collect(),
+            fields: series.fields.iter().map(|field| FieldValues::from_field(field.as_ref())).collect::<AxionResult<_>>()?,
+        })
+    }
+}
+
+impl TryFrom<StructSeriesData> for StructSeries {
+    type Error = AxionError;
+
+    fn try_from(data: StructSeriesData) -> AxionResult<Self> {
+        let field_dtypes = match &data.dtype {
+            DataType::Struct(field_dtypes) if field_dtypes.len() == data.fields.len() => field_dtypes,
+            other => {
+                return Err(AxionError::InvalidArgument(format!(
+                    "结构体 Series '{}' 的数据类型 {:?} 与 {} 个字段不匹配",
+                    data.name,
+                    other,
+                    data.fields.len()
+                )))
+            }
+        };
+        let fields = data
+            .fields
+            .into_iter()
+            .zip(field_dtypes)
+            .map(|(values, (name, dtype))| values.into_field(name.clone(), dtype))
+            .collect::<AxionResult<Vec<_>>>()?;
+        let mut validity = Bitmap::with_capacity(data.validity.len());
+        for valid in data.validity {
+            validity.push(valid);
+        }
+        StructSeries::new(data.name, fields)?.with_validity(validity)
+    }
+}
+
+/// 序列化为包含名称、数据类型、行级有效性和各字段数据的记录
+///
+/// 包含 List 字段时序列化失败。
+impl Serialize for StructSeries {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        StructSeriesData::try_from(self).map_err(ser::Error::custom)?.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StructSeries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        StructSeries::try_from(StructSeriesData::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
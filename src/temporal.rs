@@ -0,0 +1,899 @@
+//! 时间类型模块
+//!
+//! 提供 `Date`、`Datetime` 和 `Duration` 三种以整数存储的时间值类型，
+//! 以及 ISO-8601 字符串的解析与格式化、时间单位换算和 Series 级别的时间运算。
+//!
+//! 时区仅支持 `UTC` 和固定偏移（如 `+08:00`）。
+
+use crate::dtype::{DataType, DataTypeTrait, TimeUnit};
+use crate::error::{AxionError, AxionResult, CastError};
+use crate::series::datetime::DatetimeAccessor;
+use crate::series::{Series, SeriesTrait};
+use num_traits::ToPrimitive;
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::ops::{Add, Neg, Sub};
+
+/// 每天的纳秒数
+const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+/// 每秒的纳秒数
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+
+/// 日期值，存储自 1970-01-01 起的天数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date(pub i32);
+
+/// 日期时间值，存储自 Unix 纪元起的时间单位数
+///
+/// 时间单位和时区由所在 Series 的 `DataType::Datetime` 决定，单独使用时视为微秒。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Datetime(pub i64);
+
+/// 时间间隔值，存储时间单位数
+///
+/// 时间单位由所在 Series 的 `DataType::Duration` 决定，单独使用时视为微秒。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(pub i64);
+
+impl Date {
+    /// 由年、月、日创建日期，日期无效时返回 `None`
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year as i64, month) {
+            return None;
+        }
+        i32::try_from(days_from_civil(year as i64, month, day)).ok().map(Date)
+    }
+
+    /// 拆分为 (年, 月, 日)
+    pub fn ymd(&self) -> (i32, u32, u32) {
+        let (year, month, day) = civil_from_days(self.0 as i64);
+        (year as i32, month, day)
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_date(self.0))
+    }
+}
+
+impl Display for Datetime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_datetime(self.0, TimeUnit::Microseconds, None))
+    }
+}
+
+impl Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_duration(self.0, TimeUnit::Microseconds))
+    }
+}
+
+impl DataTypeTrait for Date {
+    const DTYPE: DataType = DataType::Date;
+
+    fn as_dtype(&self) -> DataType {
+        Self::DTYPE
+    }
+}
+
+impl DataTypeTrait for Datetime {
+    const DTYPE: DataType = DataType::Datetime(TimeUnit::Microseconds, None);
+
+    fn as_dtype(&self) -> DataType {
+        Self::DTYPE
+    }
+
+    fn format_with_dtype(&self, dtype: &DataType) -> Option<String> {
+        match dtype {
+            DataType::Datetime(unit, tz) => Some(format_datetime(self.0, *unit, tz.as_deref())),
+            _ => None,
+        }
+    }
+}
+
+impl DataTypeTrait for Duration {
+    const DTYPE: DataType = DataType::Duration(TimeUnit::Microseconds);
+
+    fn as_dtype(&self) -> DataType {
+        Self::DTYPE
+    }
+
+    fn format_with_dtype(&self, dtype: &DataType) -> Option<String> {
+        match dtype {
+            DataType::Duration(unit) => Some(format_duration(self.0, *unit)),
+            _ => None,
+        }
+    }
+}
+
+/// 以底层整数参与数值换算，如 as-of 连接中的容差比较
+macro_rules! impl_to_primitive {
+    ($($t:ty),+) => {
+        $(
+            impl ToPrimitive for $t {
+                fn to_i64(&self) -> Option<i64> {
+                    self.0.to_i64()
+                }
+
+                fn to_u64(&self) -> Option<u64> {
+                    self.0.to_u64()
+                }
+            }
+        )+
+    };
+}
+
+impl_to_primitive!(Date, Datetime, Duration);
+
+// === 同单位值之间的运算 ===
+
+impl Add for Duration {
+    type Output = Duration;
+
+    fn add(self, rhs: Duration) -> Duration {
+        Duration(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Duration {
+    type Output = Duration;
+
+    fn sub(self, rhs: Duration) -> Duration {
+        Duration(self.0 - rhs.0)
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    fn neg(self) -> Duration {
+        Duration(-self.0)
+    }
+}
+
+impl Add<Duration> for Datetime {
+    type Output = Datetime;
+
+    fn add(self, rhs: Duration) -> Datetime {
+        Datetime(self.0 + rhs.0)
+    }
+}
+
+impl Sub<Duration> for Datetime {
+    type Output = Datetime;
+
+    fn sub(self, rhs: Duration) -> Datetime {
+        Datetime(self.0 - rhs.0)
+    }
+}
+
+impl Sub for Datetime {
+    type Output = Duration;
+
+    fn sub(self, rhs: Datetime) -> Duration {
+        Duration(self.0 - rhs.0)
+    }
+}
+
+// === 日历换算 ===
+
+/// 判断是否为闰年
+pub(crate) fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// 获取指定月份的天数
+pub(crate) fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// 由公历年月日计算自 1970-01-01 起的天数
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let month_from_march = (month as i64 + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// 由自 1970-01-01 起的天数计算公历 (年, 月, 日)
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = if days >= 0 { days } else { days - 146_096 } / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_from_march + 2) / 5 + 1) as u32;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// 将时间值从一个单位换算到另一个单位，换算到更粗的单位时向下取整，溢出时返回 `None`
+pub(crate) fn convert_time_unit(value: i64, from: TimeUnit, to: TimeUnit) -> Option<i64> {
+    let nanos = value as i128 * from.nanos_per_unit() as i128;
+    i64::try_from(nanos.div_euclid(to.nanos_per_unit() as i128)).ok()
+}
+
+// === 解析 ===
+
+/// 解析时区字符串为相对 UTC 的偏移秒数
+///
+/// 支持 `UTC`、`Z` 以及 `+HH:MM`、`+HHMM`、`+HH` 形式的固定偏移。
+pub(crate) fn parse_tz_offset(tz: &str) -> Option<i64> {
+    let tz = tz.trim();
+    if tz.eq_ignore_ascii_case("utc") || tz.eq_ignore_ascii_case("z") {
+        return Some(0);
+    }
+    let sign = match tz.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let digits: String = tz[1..].chars().filter(|c| *c != ':').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i64>().ok()?, 0),
+        4 => (digits[..2].parse::<i64>().ok()?, digits[2..].parse::<i64>().ok()?),
+        _ => return None,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// 解析 `YYYY-MM-DD` 格式的日期，返回自 1970-01-01 起的天数
+pub(crate) fn parse_date(s: &str) -> Option<i32> {
+    let mut parts = s.trim().splitn(3, '-');
+    let year_str = parts.next()?;
+    let month_str = parts.next()?;
+    let day_str = parts.next()?;
+    if year_str.len() != 4 || month_str.len() != 2 || day_str.len() != 2 {
+        return None;
+    }
+    let all_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if !all_digits(year_str) || !all_digits(month_str) || !all_digits(day_str) {
+        return None;
+    }
+    Date::from_ymd(year_str.parse().ok()?, month_str.parse().ok()?, day_str.parse().ok()?).map(|date| date.0)
+}
+
+/// 解析 `HH:MM[:SS[.fffffffff]]` 格式的时间，返回当天的纳秒数
+fn parse_time_of_day(s: &str) -> Option<i128> {
+    let mut parts = s.splitn(3, ':');
+    let hour: i128 = parse_fixed_digits(parts.next()?, 2)?;
+    let minute: i128 = parse_fixed_digits(parts.next()?, 2)?;
+    let (second, fraction_nanos) = match parts.next() {
+        Some(seconds_str) => {
+            let (whole, fraction) = match seconds_str.split_once(['.', ',']) {
+                Some((whole, fraction)) => (whole, Some(fraction)),
+                None => (seconds_str, None),
+            };
+            let second: i128 = parse_fixed_digits(whole, 2)?;
+            let fraction_nanos = match fraction {
+                Some(digits) if !digits.is_empty() && digits.len() <= 9 && digits.chars().all(|c| c.is_ascii_digit()) => {
+                    digits.parse::<i128>().ok()? * 10_i128.pow(9 - digits.len() as u32)
+                }
+                Some(_) => return None,
+                None => 0,
+            };
+            (second, fraction_nanos)
+        }
+        None => (0, 0),
+    };
+    if hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    Some((hour * 3600 + minute * 60 + second) * NANOS_PER_SECOND + fraction_nanos)
+}
+
+/// 解析固定位数的十进制数字
+fn parse_fixed_digits(s: &str, len: usize) -> Option<i128> {
+    if s.len() != len || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// 解析 ISO-8601 日期时间字符串
+///
+/// 支持 `YYYY-MM-DD`、`YYYY-MM-DD[T| ]HH:MM[:SS[.f]]`，以及末尾的 `Z` 或 `±HH:MM` 偏移。
+/// 返回按 `unit` 表示的值以及字符串是否带有时区偏移；带偏移的值会换算为 UTC。
+pub(crate) fn parse_datetime(s: &str, unit: TimeUnit) -> Option<(i64, bool)> {
+    let s = s.trim();
+    if s.len() < 10 || !s.is_char_boundary(10) {
+        return None;
+    }
+    let days = parse_date(&s[..10])? as i128;
+    let rest = &s[10..];
+
+    let (time_nanos, offset_seconds) = if rest.is_empty() {
+        (0, None)
+    } else {
+        let time_str = rest.strip_prefix(['T', 't', ' '])?;
+        let (time_part, offset) = if let Some(stripped) = time_str.strip_suffix(['Z', 'z']) {
+            (stripped, Some(0))
+        } else if let Some(pos) = time_str.rfind(['+', '-']) {
+            (&time_str[..pos], Some(parse_tz_offset(&time_str[pos..])?))
+        } else {
+            (time_str.trim_end(), None)
+        };
+        (parse_time_of_day(time_part.trim_end())?, offset)
+    };
+
+    let total_nanos = days * NANOS_PER_DAY + time_nanos - offset_seconds.unwrap_or(0) as i128 * NANOS_PER_SECOND;
+    let value = i64::try_from(total_nanos.div_euclid(unit.nanos_per_unit() as i128)).ok()?;
+    Some((value, offset_seconds.is_some()))
+}
+
+/// 解析 ISO-8601 时间间隔字符串，如 `P1DT2H30M`、`PT0.5S`、`-PT15M`
+///
+/// 仅支持天、时、分、秒（秒可带小数），返回按 `unit` 表示的值。
+pub(crate) fn parse_duration(s: &str, unit: TimeUnit) -> Option<i64> {
+    let s = s.trim();
+    let (sign, body) = match s.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let body = body.strip_prefix(['P', 'p'])?;
+    if body.is_empty() {
+        return None;
+    }
+
+    let mut total_nanos: i128 = 0;
+    let mut in_time = false;
+    let mut number = String::new();
+    let mut seen_component = false;
+    for c in body.chars() {
+        match c.to_ascii_uppercase() {
+            'T' if !in_time && number.is_empty() => in_time = true,
+            '0'..='9' | '.' | ',' => number.push(if c == ',' { '.' } else { c }),
+            designator => {
+                let nanos_per_component: i128 = match (designator, in_time) {
+                    ('D', false) => NANOS_PER_DAY,
+                    ('H', true) => 3600 * NANOS_PER_SECOND,
+                    ('M', true) => 60 * NANOS_PER_SECOND,
+                    ('S', true) => NANOS_PER_SECOND,
+                    _ => return None,
+                };
+                if number.is_empty() {
+                    return None;
+                }
+                total_nanos += match number.split_once('.') {
+                    Some((whole, fraction)) if designator == 'S' => {
+                        if fraction.is_empty() || fraction.len() > 9 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+                            return None;
+                        }
+                        whole.parse::<i128>().ok()? * nanos_per_component
+                            + fraction.parse::<i128>().ok()? * 10_i128.pow(9 - fraction.len() as u32)
+                    }
+                    Some(_) => return None,
+                    None => number.parse::<i128>().ok()? * nanos_per_component,
+                };
+                number.clear();
+                seen_component = true;
+            }
+        }
+    }
+    if !number.is_empty() || !seen_component {
+        return None;
+    }
+    i64::try_from(sign * total_nanos.div_euclid(unit.nanos_per_unit() as i128)).ok()
+}
+
+// === 格式化 ===
+
+/// 将自 1970-01-01 起的天数格式化为 `YYYY-MM-DD`
+pub(crate) fn format_date(days: i32) -> String {
+    let (year, month, day) = civil_from_days(days as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// 格式化纳秒精度的一天内时间，小数部分仅在非零时按单位位数输出
+fn format_time_of_day(nanos_of_day: i128, unit: TimeUnit) -> String {
+    let total_seconds = nanos_of_day / NANOS_PER_SECOND;
+    let mut result = format!(
+        "{:02}:{:02}:{:02}",
+        total_seconds / 3600,
+        (total_seconds % 3600) / 60,
+        total_seconds % 60
+    );
+    let fraction_nanos = nanos_of_day % NANOS_PER_SECOND;
+    if fraction_nanos != 0 {
+        let digits = unit.fraction_digits();
+        let fraction = fraction_nanos / 10_i128.pow(9 - digits as u32);
+        result.push_str(&format!(".{:0width$}", fraction, width = digits));
+    }
+    result
+}
+
+/// 格式化日期时间为 `YYYY-MM-DD HH:MM:SS[.f]`，带时区时按偏移换算并附加 `±HH:MM`
+pub(crate) fn format_datetime(value: i64, unit: TimeUnit, tz: Option<&str>) -> String {
+    let offset_seconds = tz.and_then(parse_tz_offset);
+    let nanos = value as i128 * unit.nanos_per_unit() as i128
+        + offset_seconds.unwrap_or(0) as i128 * NANOS_PER_SECOND;
+    let days = nanos.div_euclid(NANOS_PER_DAY);
+    let (year, month, day) = civil_from_days(days as i64);
+    let mut result = format!(
+        "{:04}-{:02}-{:02} {}",
+        year,
+        month,
+        day,
+        format_time_of_day(nanos.rem_euclid(NANOS_PER_DAY), unit)
+    );
+    if let Some(offset) = offset_seconds {
+        let sign = if offset < 0 { '-' } else { '+' };
+        result.push_str(&format!("{}{:02}:{:02}", sign, offset.abs() / 3600, (offset.abs() % 3600) / 60));
+    }
+    result
+}
+
+/// 将时间间隔格式化为 ISO-8601 形式，如 `P1DT2H30M`、`PT0.5S`
+pub(crate) fn format_duration(value: i64, unit: TimeUnit) -> String {
+    let nanos = value as i128 * unit.nanos_per_unit() as i128;
+    if nanos == 0 {
+        return "PT0S".to_string();
+    }
+    let mut result = String::from(if nanos < 0 { "-P" } else { "P" });
+    let abs_nanos = nanos.abs();
+    let days = abs_nanos / NANOS_PER_DAY;
+    let remaining = abs_nanos % NANOS_PER_DAY;
+    if days > 0 {
+        result.push_str(&format!("{}D", days));
+    }
+    if remaining > 0 {
+        result.push('T');
+        let total_seconds = remaining / NANOS_PER_SECOND;
+        let (hours, minutes, seconds) = (total_seconds / 3600, (total_seconds % 3600) / 60, total_seconds % 60);
+        if hours > 0 {
+            result.push_str(&format!("{}H", hours));
+        }
+        if minutes > 0 {
+            result.push_str(&format!("{}M", minutes));
+        }
+        let fraction_nanos = remaining % NANOS_PER_SECOND;
+        if seconds > 0 || fraction_nanos > 0 {
+            result.push_str(&seconds.to_string());
+            if fraction_nanos > 0 {
+                let fraction = format!("{:09}", fraction_nanos);
+                result.push('.');
+                result.push_str(fraction.trim_end_matches('0'));
+            }
+            result.push('S');
+        }
+    }
+    result
+}
+
+// === 时间间隔字符串 ===
+
+/// 由间隔字符串（如 `"1h"`、`"15m"`、`"1mo"`、`"1d12h"`）解析得到的日历间隔
+///
+/// 月和年按日历计算，其余单位按固定纳秒数计算。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Interval {
+    /// 日历月数（年按 12 个月计）
+    pub(crate) months: i64,
+    /// 固定长度部分的纳秒数
+    pub(crate) nanos: i128,
+}
+
+impl Interval {
+    /// 解析间隔字符串
+    ///
+    /// 支持的单位：`ns`、`us`、`ms`、`s`、`m`、`h`、`d`、`w`、`mo`、`y`，可组合使用，允许前导 `-`。
+    ///
+    /// # 错误
+    ///
+    /// 字符串为空或包含无法识别的单位时返回 `AxionError::InvalidArgument`
+    pub(crate) fn parse(s: &str) -> AxionResult<Self> {
+        let invalid = || AxionError::InvalidArgument(format!("无效的时间间隔字符串: '{}'", s));
+        let trimmed = s.trim();
+        let (sign, body) = match trimmed.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, trimmed),
+        };
+        if body.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut interval = Interval { months: 0, nanos: 0 };
+        let mut chars = body.chars().peekable();
+        while chars.peek().is_some() {
+            let mut number = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_digit()) {
+                number.push(c);
+            }
+            let mut unit = String::new();
+            while let Some(c) = chars.next_if(|c| c.is_ascii_alphabetic()) {
+                unit.push(c);
+            }
+            let count: i64 = number.parse().map_err(|_| invalid())?;
+            match unit.as_str() {
+                "y" => interval.months += count * 12,
+                "mo" => interval.months += count,
+                _ => {
+                    let nanos_per_unit: i128 = match unit.as_str() {
+                        "ns" => 1,
+                        "us" => 1_000,
+                        "ms" => 1_000_000,
+                        "s" => NANOS_PER_SECOND,
+                        "m" => 60 * NANOS_PER_SECOND,
+                        "h" => 3600 * NANOS_PER_SECOND,
+                        "d" => NANOS_PER_DAY,
+                        "w" => 7 * NANOS_PER_DAY,
+                        _ => return Err(invalid()),
+                    };
+                    interval.nanos += count as i128 * nanos_per_unit;
+                }
+            }
+        }
+        interval.months *= sign;
+        interval.nanos *= sign as i128;
+        Ok(interval)
+    }
+
+    /// 检查间隔是否为正的单一类型间隔（纯日历月或纯固定长度），用于截断和取整
+    pub(crate) fn check_truncatable(&self, s: &str) -> AxionResult<()> {
+        let valid = (self.months > 0 && self.nanos == 0) || (self.months == 0 && self.nanos > 0);
+        if valid {
+            Ok(())
+        } else {
+            Err(AxionError::InvalidArgument(format!(
+                "时间间隔 '{}' 必须为正，且不能同时包含月/年与固定长度单位",
+                s
+            )))
+        }
+    }
+
+    /// 返回方向相反的间隔
+    pub(crate) fn negate(&self) -> Interval {
+        Interval { months: -self.months, nanos: -self.nanos }
+    }
+
+    /// 将纳秒时间戳加上本间隔，月份相加后日期超出当月天数时取当月最后一天
+    pub(crate) fn add_to(&self, nanos: i128) -> i128 {
+        let mut result = nanos;
+        if self.months != 0 {
+            let days = result.div_euclid(NANOS_PER_DAY);
+            let time_of_day = result.rem_euclid(NANOS_PER_DAY);
+            let (year, month, day) = civil_from_days(days as i64);
+            let month_index = year * 12 + month as i64 - 1 + self.months;
+            let (new_year, new_month) = (month_index.div_euclid(12), month_index.rem_euclid(12) as u32 + 1);
+            let new_day = day.min(days_in_month(new_year, new_month));
+            result = days_from_civil(new_year, new_month, new_day) as i128 * NANOS_PER_DAY + time_of_day;
+        }
+        result + self.nanos
+    }
+
+    /// 将纳秒时间戳向下截断到本间隔的边界
+    ///
+    /// 月间隔以公元 0 年 1 月为起点对齐，整周间隔对齐到周一，其余固定间隔以 Unix 纪元为起点对齐。
+    /// 调用方需先通过 `check_truncatable` 校验。
+    pub(crate) fn truncate(&self, nanos: i128) -> i128 {
+        if self.months > 0 {
+            let (year, month, _) = civil_from_days(nanos.div_euclid(NANOS_PER_DAY) as i64);
+            let month_index = year * 12 + month as i64 - 1;
+            let aligned = month_index - month_index.rem_euclid(self.months);
+            return days_from_civil(aligned.div_euclid(12), aligned.rem_euclid(12) as u32 + 1, 1) as i128 * NANOS_PER_DAY;
+        }
+        // 1970-01-05 是周一
+        let origin = if self.nanos % (7 * NANOS_PER_DAY) == 0 { 4 * NANOS_PER_DAY } else { 0 };
+        nanos - (nanos - origin).rem_euclid(self.nanos)
+    }
+
+    /// 将纳秒时间戳取整到最近的间隔边界，恰好位于中点时向上取整
+    pub(crate) fn round(&self, nanos: i128) -> i128 {
+        let lower = self.truncate(nanos);
+        let upper = self.add_to(lower);
+        if nanos - lower >= upper - nanos { upper } else { lower }
+    }
+}
+
+// === Series 级别的时间运算 ===
+
+/// 获取 Datetime Series 的时间单位和时区
+fn datetime_parts(series: &Series<Datetime>) -> (TimeUnit, Option<String>) {
+    match series.dtype() {
+        DataType::Datetime(unit, tz) => (unit, tz),
+        _ => (TimeUnit::Microseconds, None),
+    }
+}
+
+/// 获取 Duration Series 的时间单位
+fn duration_unit(series: &Series<Duration>) -> TimeUnit {
+    match series.dtype() {
+        DataType::Duration(unit) => unit,
+        _ => TimeUnit::Microseconds,
+    }
+}
+
+/// 逐元素组合两个等长 Series 的原始整数值，任一侧为 null 或结果溢出时为 null
+fn zip_temporal<A, B, R>(
+    left: &Series<A>,
+    right: &Series<B>,
+    dtype: DataType,
+    combine: impl Fn(&A, &B) -> Option<R>,
+) -> AxionResult<Series<R>>
+where
+    A: DataTypeTrait,
+    B: DataTypeTrait,
+    R: DataTypeTrait,
+{
+    if left.len() != right.len() {
+        return Err(AxionError::MismatchedLengths {
+            expected: left.len(),
+            found: right.len(),
+            name: right.name().to_string(),
+        });
+    }
+    let data: Vec<Option<R>> = left
+        .iter()
+        .zip(right.iter())
+        .map(|(a, b)| match (a, b) {
+            (Some(a), Some(b)) => combine(a, b),
+            _ => None,
+        })
+        .collect();
+    Series::new_from_options(left.name().to_string(), data).with_dtype(dtype)
+}
+
+impl Series<Datetime> {
+    /// 返回时间操作访问器
+    pub fn dt(&self) -> DatetimeAccessor {
+        DatetimeAccessor::from_datetime(self)
+    }
+
+    /// 计算与另一个 Datetime Series 的逐元素差值
+    ///
+    /// 右侧的值会先换算为左侧的时间单位，结果的时间单位与左侧相同。
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    /// * `AxionError::TypeMismatch` - 一侧带时区而另一侧不带时区
+    pub fn sub_datetime(&self, other: &Series<Datetime>) -> AxionResult<Series<Duration>> {
+        let (unit, tz) = datetime_parts(self);
+        let (other_unit, other_tz) = datetime_parts(other);
+        if tz.is_some() != other_tz.is_some() {
+            return Err(AxionError::TypeMismatch {
+                expected: self.dtype(),
+                found: other.dtype(),
+                name: other.name().to_string(),
+            });
+        }
+        zip_temporal(self, other, DataType::Duration(unit), |a, b| {
+            let b = convert_time_unit(b.0, other_unit, unit)?;
+            a.0.checked_sub(b).map(Duration)
+        })
+    }
+
+    /// 逐元素加上 Duration Series，结果保持左侧的时间单位和时区
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    pub fn add_duration(&self, other: &Series<Duration>) -> AxionResult<Series<Datetime>> {
+        let (unit, tz) = datetime_parts(self);
+        let other_unit = duration_unit(other);
+        zip_temporal(self, other, DataType::Datetime(unit, tz), |a, b| {
+            let b = convert_time_unit(b.0, other_unit, unit)?;
+            a.0.checked_add(b).map(Datetime)
+        })
+    }
+
+    /// 逐元素减去 Duration Series，结果保持左侧的时间单位和时区
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    pub fn sub_duration(&self, other: &Series<Duration>) -> AxionResult<Series<Datetime>> {
+        let (unit, tz) = datetime_parts(self);
+        let other_unit = duration_unit(other);
+        zip_temporal(self, other, DataType::Datetime(unit, tz), |a, b| {
+            let b = convert_time_unit(b.0, other_unit, unit)?;
+            a.0.checked_sub(b).map(Datetime)
+        })
+    }
+}
+
+impl Series<Date> {
+    /// 返回时间操作访问器，日期视为当天零点
+    pub fn dt(&self) -> DatetimeAccessor {
+        DatetimeAccessor::from_date(self)
+    }
+
+    /// 计算与另一个 Date Series 的逐元素差值，结果为毫秒精度的 Duration
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    pub fn sub_date(&self, other: &Series<Date>) -> AxionResult<Series<Duration>> {
+        let unit = TimeUnit::Milliseconds;
+        zip_temporal(self, other, DataType::Duration(unit), |a, b| {
+            let days = a.0 as i64 - b.0 as i64;
+            days.checked_mul(86_400_000).map(Duration)
+        })
+    }
+
+    /// 逐元素加上 Duration Series，不足一天的部分向下取整
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    pub fn add_duration(&self, other: &Series<Duration>) -> AxionResult<Series<Date>> {
+        let unit = duration_unit(other);
+        let nanos_per_day = NANOS_PER_DAY as i64 / unit.nanos_per_unit();
+        zip_temporal(self, other, DataType::Date, |a, b| {
+            let days = a.0 as i64 + b.0.div_euclid(nanos_per_day);
+            i32::try_from(days).ok().map(Date)
+        })
+    }
+}
+
+impl Series<Duration> {
+    /// 逐元素加上另一个 Duration Series，右侧先换算为左侧的时间单位
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    pub fn add_duration(&self, other: &Series<Duration>) -> AxionResult<Series<Duration>> {
+        let (unit, other_unit) = (duration_unit(self), duration_unit(other));
+        zip_temporal(self, other, DataType::Duration(unit), |a, b| {
+            let b = convert_time_unit(b.0, other_unit, unit)?;
+            a.0.checked_add(b).map(Duration)
+        })
+    }
+
+    /// 逐元素减去另一个 Duration Series，右侧先换算为左侧的时间单位
+    ///
+    /// # 错误
+    ///
+    /// * `AxionError::MismatchedLengths` - 两个 Series 长度不一致
+    pub fn sub_duration(&self, other: &Series<Duration>) -> AxionResult<Series<Duration>> {
+        let (unit, other_unit) = (duration_unit(self), duration_unit(other));
+        zip_temporal(self, other, DataType::Duration(unit), |a, b| {
+            let b = convert_time_unit(b.0, other_unit, unit)?;
+            a.0.checked_sub(b).map(Duration)
+        })
+    }
+}
+
+// === 类型转换 ===
+
+/// 获取时间 Series 的底层整数值（Date 为天数，其余为时间单位数），非时间 Series 返回 `None`
+pub(crate) fn temporal_raw_values(series: &dyn Any) -> Option<Vec<Option<i64>>> {
+    if let Some(dates) = series.downcast_ref::<Series<Date>>() {
+        Some(dates.iter().map(|opt| opt.map(|v| v.0 as i64)).collect())
+    } else if let Some(datetimes) = series.downcast_ref::<Series<Datetime>>() {
+        Some(datetimes.iter().map(|opt| opt.map(|v| v.0)).collect())
+    } else {
+        series
+            .downcast_ref::<Series<Duration>>()
+            .map(|durations| durations.iter().map(|opt| opt.map(|v| v.0)).collect())
+    }
+}
+
+/// 按数据类型将时间值格式化为字符串，与 `get_str` 的显示格式一致
+pub(crate) fn format_temporal(value: i64, dtype: &DataType) -> String {
+    match dtype {
+        DataType::Date => format_date(value as i32),
+        DataType::Datetime(unit, tz) => format_datetime(value, *unit, tz.as_deref()),
+        DataType::Duration(unit) => format_duration(value, *unit),
+        _ => value.to_string(),
+    }
+}
+
+/// 将 Series 转换为时间类型（由 `SeriesTrait::cast` 调用）
+///
+/// - 字符串按 ISO-8601 解析，规则与 CSV 读取相同；Datetime 不带偏移的值视为 UTC
+/// - 整数直接作为底层值（Date 为天数，其余为时间单位数）
+/// - Date 转换为 Datetime 取当地零点，Datetime 转换为 Date 取当地日期
+/// - Datetime 之间、Duration 之间按时间单位换算，换算到更粗的单位时向下取整
+///
+/// # 错误
+///
+/// * `AxionError::UnsupportedOperation` - 源类型不能转换为目标时间类型
+/// * `AxionError::CastError` - 严格模式下存在无法解析或超出范围的值
+pub(crate) fn cast_to_temporal<T: DataTypeTrait>(
+    series: &Series<T>,
+    dtype: &DataType,
+    strict: bool,
+) -> AxionResult<Box<dyn SeriesTrait>> {
+    let source_dtype = series.dtype();
+    let unsupported = || {
+        AxionError::UnsupportedOperation(format!(
+            "不支持将 Series '{}' 从 {:?} 转换为 {:?}",
+            series.name(),
+            source_dtype,
+            dtype
+        ))
+    };
+    // 当地时间相对 UTC 的偏移纳秒数
+    let offset_nanos = |tz: &Option<String>| {
+        tz.as_deref().and_then(parse_tz_offset).unwrap_or(0) as i128 * NANOS_PER_SECOND
+    };
+
+    let raw: Vec<Option<i64>> = if let Some(strings) = (series as &dyn Any).downcast_ref::<Series<String>>() {
+        strings
+            .iter()
+            .map(|opt| {
+                opt.and_then(|s| match dtype {
+                    DataType::Date => parse_date(s).map(|days| days as i64),
+                    DataType::Datetime(unit, _) => parse_datetime(s, *unit).map(|(value, _)| value),
+                    DataType::Duration(unit) => parse_duration(s, *unit),
+                    _ => None,
+                })
+            })
+            .collect()
+    } else if source_dtype.is_integer() {
+        series.cast_lenient::<i64>().iter().map(|opt| opt.copied()).collect()
+    } else {
+        let values = temporal_raw_values(series).ok_or_else(unsupported)?;
+        let convert: Box<dyn Fn(i64) -> Option<i64>> = match (&source_dtype, dtype) {
+            (DataType::Date, DataType::Date) => Box::new(Some),
+            (DataType::Date, DataType::Datetime(unit, tz)) => {
+                let (nanos_per_unit, offset) = (unit.nanos_per_unit() as i128, offset_nanos(tz));
+                Box::new(move |days| i64::try_from((days as i128 * NANOS_PER_DAY - offset).div_euclid(nanos_per_unit)).ok())
+            }
+            (DataType::Datetime(unit, tz), DataType::Date) => {
+                let (nanos_per_unit, offset) = (unit.nanos_per_unit() as i128, offset_nanos(tz));
+                Box::new(move |value| Some((value as i128 * nanos_per_unit + offset).div_euclid(NANOS_PER_DAY) as i64))
+            }
+            (DataType::Datetime(from, _), DataType::Datetime(to, _)) | (DataType::Duration(from), DataType::Duration(to)) => {
+                let (from, to) = (*from, *to);
+                Box::new(move |value| convert_time_unit(value, from, to))
+            }
+            _ => return Err(unsupported()),
+        };
+        values.into_iter().map(|opt| opt.and_then(&convert)).collect()
+    };
+
+    let name = series.name().to_string();
+    let converted: Box<dyn SeriesTrait> = match dtype {
+        DataType::Date => {
+            let data = raw.into_iter().map(|opt| opt.and_then(|days| i32::try_from(days).ok()).map(Date)).collect();
+            Box::new(Series::new_from_options(name, data))
+        }
+        DataType::Datetime(_, _) => {
+            let data = raw.into_iter().map(|opt| opt.map(Datetime)).collect();
+            Box::new(Series::new_from_options(name, data).with_dtype(dtype.clone())?)
+        }
+        DataType::Duration(_) => {
+            let data = raw.into_iter().map(|opt| opt.map(Duration)).collect();
+            Box::new(Series::new_from_options(name, data).with_dtype(dtype.clone())?)
+        }
+        _ => return Err(unsupported()),
+    };
+
+    if strict {
+        if let Some(idx) = (0..series.len()).find(|&idx| series.is_valid(idx) && converted.is_null_at(idx)) {
+            let value = match temporal_raw_values(series) {
+                Some(values) => values[idx].map(|v| format_temporal(v, &source_dtype)).unwrap_or_default(),
+                None => format!("{:?}", series.values()[idx]),
+            };
+            return Err(AxionError::CastError(CastError(format!(
+                "无法将 Series '{}' 第 {} 行的值 {} 从 {:?} 转换为 {:?}",
+                series.name(),
+                idx,
+                value,
+                source_dtype,
+                dtype
+            ))));
+        }
+    }
+    Ok(converted)
+}
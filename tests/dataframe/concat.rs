@@ -0,0 +1,80 @@
+use axion_data::{concat, df, AxionError, AxionResult, ConcatType, DataFrame, DataType, Datetime, Decimal, Series, SeriesTrait, TimeUnit};
+
+#[test]
+fn test_concat_vertical() -> AxionResult<()> {
+    let df1 = df![
+        "id": i32 => vec![1, 2],
+        "name": String => &["a", "b"]
+    ]?;
+    let df2 = df![
+        "name": String => vec![Some("c".to_string()), None],
+        "id": i32 => vec![3, 4]
+    ]?;
+
+    let stacked = concat(&[df1.clone(), DataFrame::new_empty(), df2], ConcatType::Vertical)?;
+    assert_eq!(stacked.shape(), (4, 2));
+    assert_eq!(stacked.columns_names(), vec!["id", "name"]);
+    let ids: &Series<i32> = stacked.downcast_column("id")?;
+    assert_eq!(ids.data_internal(), vec![Some(1), Some(2), Some(3), Some(4)]);
+    let names: &Series<String> = stacked.downcast_column("name")?;
+    assert_eq!(names.data_internal(), vec![Some("a".to_string()), Some("b".to_string()), Some("c".to_string()), None]);
+
+    let wrong_type = df!["id": i64 => vec![5], "name": String => &["e"]]?;
+    assert!(matches!(concat(&[df1.clone(), wrong_type], ConcatType::Vertical), Err(AxionError::TypeMismatch { .. })));
+    let extra_column = df!["id": i32 => vec![5], "name": String => &["e"], "age": i32 => vec![30]]?;
+    assert!(matches!(concat(&[df1, extra_column], ConcatType::Vertical), Err(AxionError::InvalidArgument(_))));
+    assert_eq!(concat(&[], ConcatType::Vertical)?.shape(), (0, 0));
+
+    Ok(())
+}
+
+#[test]
+fn test_concat_horizontal_and_diagonal() -> AxionResult<()> {
+    let features = df!["x": f64 => vec![1.0, 2.0], "y": f64 => vec![3.0, 4.0]]?;
+    let labels = df!["label": bool => vec![true, false]]?;
+
+    let widened = concat(&[features.clone(), labels], ConcatType::Horizontal)?;
+    assert_eq!(widened.columns_names(), vec!["x", "y", "label"]);
+    assert_eq!(widened.height(), 2);
+
+    let duplicate = df!["x": f64 => vec![5.0, 6.0]]?;
+    assert!(matches!(concat(&[features.clone(), duplicate], ConcatType::Horizontal), Err(AxionError::DuplicateColumnName(name)) if name == "x"));
+    let short = df!["z": f64 => vec![5.0]]?;
+    assert!(matches!(concat(&[features.clone(), short], ConcatType::Horizontal), Err(AxionError::MismatchedLengths { .. })));
+
+    let other = df!["y": f64 => vec![7.0], "z": i32 => vec![9]]?;
+    let diagonal = concat(&[features, other], ConcatType::Diagonal)?;
+    assert_eq!(diagonal.columns_names(), vec!["x", "y", "z"]);
+    assert_eq!(diagonal.column("z")?.dtype(), DataType::Int32);
+    let x: &Series<f64> = diagonal.downcast_column("x")?;
+    assert_eq!(x.data_internal(), vec![Some(1.0), Some(2.0), None]);
+    let y: &Series<f64> = diagonal.downcast_column("y")?;
+    assert_eq!(y.data_internal(), vec![Some(3.0), Some(4.0), Some(7.0)]);
+    let z: &Series<i32> = diagonal.downcast_column("z")?;
+    assert_eq!(z.data_internal(), vec![None, None, Some(9)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_concat_rejects_mismatched_dtype_parameters() -> AxionResult<()> {
+    let two_places = Series::<Decimal>::from_strs("price".to_string(), vec![Some("1.10")], 10, 2)?;
+    let four_places = Series::<Decimal>::from_strs("price".to_string(), vec![Some("1.1000")], 10, 4)?;
+    let df1 = DataFrame::new(vec![Box::new(two_places.clone())])?;
+    let df2 = DataFrame::new(vec![Box::new(four_places)])?;
+    assert!(matches!(concat(&[df1.clone(), df2.clone()], ConcatType::Vertical), Err(AxionError::TypeMismatch { .. })));
+    assert!(matches!(concat(&[df1.clone(), df2], ConcatType::Diagonal), Err(AxionError::TypeMismatch { .. })));
+
+    // 小数位数相同时正常拼接
+    let df3 = DataFrame::new(vec![Box::new(Series::<Decimal>::from_strs("price".to_string(), vec![Some("2.5")], 10, 2)?)])?;
+    let stacked = concat(&[df1, df3], ConcatType::Vertical)?;
+    assert_eq!(stacked.column("price")?.get_str(1), Some("2.50".to_string()));
+
+    let ms = Series::new("ts".to_string(), vec![Datetime(1)]).with_dtype(DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    let us = Series::new("ts".to_string(), vec![Datetime(1)]);
+    let mut appended: Box<dyn SeriesTrait> = Box::new(ms);
+    assert!(matches!(appended.append_series(&us), Err(AxionError::TypeMismatch { .. })));
+    assert_eq!(appended.len(), 1);
+
+    Ok(())
+}
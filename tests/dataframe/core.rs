@@ -1,4 +1,5 @@
-use axion_data::{df, AxionError, DataType, AxionResult, DataFrame, SeriesTrait, Series};
+use axion_data::{df, AxionError, DataType, AxionResult, DataFrame, Date, Datetime, Decimal, SeriesTrait, Series, TimeUnit};
+use axion_data::dataframe::{AsofJoinOptions, JoinOptions, JoinType, JoinValidation};
 
 #[test]
 fn test_df_macro_creation_and_properties() -> Result<(), AxionError> {
@@ -145,7 +146,7 @@ fn test_downcast_column() {
 
     let col_a_typed: AxionResult<&Series<i32>> = df.downcast_column("col_a");
     assert!(col_a_typed.is_ok());
-    assert_eq!(col_a_typed.unwrap().data_internal(), &vec![Some(1), Some(2), None, Some(4)]);
+    assert_eq!(col_a_typed.unwrap().data_internal(), vec![Some(1), Some(2), None, Some(4)]);
 
     let col_a_as_str: AxionResult<&Series<String>> = df.downcast_column("col_a");
     assert!(col_a_as_str.is_err());
@@ -203,7 +204,7 @@ fn test_head() {
     let head_df = df.head(2);
     assert_eq!(head_df.shape(), (2, 3));
     let col_a: &Series<i32> = head_df.downcast_column("col_a").unwrap();
-    assert_eq!(col_a.data_internal(), &vec![Some(1), Some(2)]);
+    assert_eq!(col_a.data_internal(), vec![Some(1), Some(2)]);
 
     let head_all = df.head(5);
     assert_eq!(head_all.shape(), (4, 3));
@@ -216,7 +217,7 @@ fn test_tail() {
     let tail_df = df.tail(2);
     assert_eq!(tail_df.shape(), (2, 3));
     let col_a: &Series<i32> = tail_df.downcast_column("col_a").unwrap();
-    assert_eq!(col_a.data_internal(), &vec![None, Some(4)]);
+    assert_eq!(col_a.data_internal(), vec![None, Some(4)]);
 
     let tail_all = df.tail(5);
     assert_eq!(tail_all.shape(), (4, 3));
@@ -238,43 +239,43 @@ fn test_join_column_name_conflict() -> AxionResult<()> {
     }?;
 
     // Inner Join
-    let inner_df = df_left.inner_join(&df_right, "id", "id")?;
+    let inner_df = df_left.inner_join(&df_right, &["id"], &["id"])?;
     assert_eq!(inner_df.shape(), (2, 5));
     assert_eq!(
         inner_df.columns_names(),
         vec!["id", "value", "left_only", "value_right", "right_only"]
     );
     let id_col: &Series<String> = inner_df.downcast_column("id")?;
-    assert_eq!(id_col.data_internal(), &vec![Some("c".to_string()), Some("d".to_string())]);
+    assert_eq!(id_col.data_internal(), vec![Some("c".to_string()), Some("d".to_string())]);
     let value_col: &Series<i32> = inner_df.downcast_column("value")?;
-    assert_eq!(value_col.data_internal(), &vec![Some(3), Some(4)]);
+    assert_eq!(value_col.data_internal(), vec![Some(3), Some(4)]);
     let value_right_col: &Series<i32> = inner_df.downcast_column("value_right")?;
-    assert_eq!(value_right_col.data_internal(), &vec![Some(300), Some(400)]);
+    assert_eq!(value_right_col.data_internal(), vec![Some(300), Some(400)]);
 
     // Left Join
-    let left_df = df_left.left_join(&df_right, "id", "id")?;
+    let left_df = df_left.left_join(&df_right, &["id"], &["id"])?;
     assert_eq!(left_df.shape(), (4, 5));
     assert_eq!(
         left_df.columns_names(),
         vec!["id", "value", "left_only", "value_right", "right_only"]
     );
     let value_right_col_left: &Series<i32> = left_df.downcast_column("value_right")?;
-    assert_eq!(value_right_col_left.data_internal(), &vec![None, None, Some(300), Some(400)]);
+    assert_eq!(value_right_col_left.data_internal(), vec![None, None, Some(300), Some(400)]);
 
     // Right Join
-    let right_df = df_left.right_join(&df_right, "id", "id")?;
+    let right_df = df_left.right_join(&df_right, &["id"], &["id"])?;
     assert_eq!(right_df.shape(), (4, 5));
     assert_eq!(
         right_df.columns_names(),
         vec!["id", "value", "right_only", "value_left", "left_only"]
     );
     let value_left_col: &Series<i32> = right_df.downcast_column("value_left")?;
-    assert_eq!(value_left_col.data_internal(), &vec![Some(3), Some(4), None, None]);
+    assert_eq!(value_left_col.data_internal(), vec![Some(3), Some(4), None, None]);
     let value_col_right: &Series<i32> = right_df.downcast_column("value")?;
-    assert_eq!(value_col_right.data_internal(), &vec![Some(300), Some(400), Some(500), Some(600)]);
+    assert_eq!(value_col_right.data_internal(), vec![Some(300), Some(400), Some(500), Some(600)]);
 
     // Outer Join
-    let outer_df = df_left.outer_join(&df_right, "id", "id")?;
+    let outer_df = df_left.outer_join(&df_right, &["id"], &["id"])?;
     assert_eq!(outer_df.shape(), (6, 5));
     assert_eq!(
         outer_df.columns_names(),
@@ -290,6 +291,310 @@ fn test_join_column_name_conflict() -> AxionResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_join_on_integer_and_bool_keys() -> AxionResult<()> {
+    let facts = df! {
+        "store_id": i64 => vec![1, 2, 2, 3],
+        "amount": f64 => vec![10.0, 20.0, 30.0, 40.0],
+    }?;
+    let stores = df! {
+        "id": i64 => vec![2, 3, 4],
+        "city": String => &["北京", "上海", "广州"],
+    }?;
+
+    let inner = facts.inner_join(&stores, &["store_id"], &["id"])?;
+    assert_eq!(inner.columns_names(), vec!["store_id", "amount", "city"]);
+    let ids: &Series<i64> = inner.downcast_column("store_id")?;
+    assert_eq!(ids.data_internal(), vec![Some(2), Some(2), Some(3)]);
+    let cities: &Series<String> = inner.downcast_column("city")?;
+    assert_eq!(
+        cities.data_internal(),
+        vec![Some("北京".to_string()), Some("北京".to_string()), Some("上海".to_string())]
+    );
+
+    let flags_left = df! {
+        "flag" => vec![true, false],
+        "x" => vec![1, 2],
+    }?;
+    let flags_right = df! {
+        "flag" => vec![false],
+        "y" => vec![20_u32],
+    }?;
+    let left = flags_left.left_join(&flags_right, &["flag"], &["flag"])?;
+    let y: &Series<u32> = left.downcast_column("y")?;
+    assert_eq!(y.data_internal(), vec![None, Some(20)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_join_on_multiple_keys() -> AxionResult<()> {
+    let left = df! {
+        "id": u32 => vec![1, 1, 2, 2],
+        "date": String => &["2024-01-01", "2024-01-02", "2024-01-01", "2024-01-02"],
+        "sales": i32 => vec![10, 20, 30, 40],
+    }?;
+    let right = df! {
+        "id": u32 => vec![1, 2, 2],
+        "date": String => &["2024-01-02", "2024-01-01", "2024-01-03"],
+        "target": i32 => vec![15, 25, 35],
+    }?;
+
+    let inner = left.inner_join(&right, &["id", "date"], &["id", "date"])?;
+    assert_eq!(inner.shape(), (2, 4));
+    assert_eq!(inner.columns_names(), vec!["id", "date", "sales", "target"]);
+    let sales: &Series<i32> = inner.downcast_column("sales")?;
+    assert_eq!(sales.data_internal(), vec![Some(20), Some(30)]);
+    let target: &Series<i32> = inner.downcast_column("target")?;
+    assert_eq!(target.data_internal(), vec![Some(15), Some(25)]);
+
+    let outer = left.outer_join(&right, &["id", "date"], &["id", "date"])?;
+    assert_eq!(outer.shape(), (5, 4));
+
+    let right_joined = left.right_join(&right, &["id", "date"], &["id", "date"])?;
+    assert_eq!(right_joined.columns_names(), vec!["id", "date", "target", "sales"]);
+    let sales_right: &Series<i32> = right_joined.downcast_column("sales")?;
+    assert_eq!(sales_right.data_internal(), vec![Some(20), Some(30), None]);
+
+    Ok(())
+}
+
+#[test]
+fn test_join_key_errors() -> AxionResult<()> {
+    let left = df! { "id": i32 => vec![1, 2] }?;
+    let right = df! { "id": i64 => vec![1, 2] }?;
+
+    match left.inner_join(&right, &["id"], &["id"]) {
+        Err(AxionError::JoinKeyTypeError { expected, found, .. }) => {
+            assert_eq!(expected, DataType::Int32);
+            assert_eq!(found, DataType::Int64);
+        }
+        other => panic!("Expected JoinKeyTypeError, got {:?}", other.map(|df| df.shape())),
+    }
+    assert!(matches!(
+        left.inner_join(&left, &["id"], &["id", "id"]),
+        Err(AxionError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        left.inner_join(&left, &["missing"], &["id"]),
+        Err(AxionError::ColumnNotFound(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_join_options_suffix_and_nulls() -> AxionResult<()> {
+    let keys: Vec<Option<String>> = vec![Some("a".into()), None, Some("b".into())];
+    let right_keys: Vec<Option<String>> = vec![None, Some("a".into())];
+    let left = df! {
+        "key": String => keys,
+        "value": i32 => vec![1, 2, 3],
+    }?;
+    let right = df! {
+        "key": String => right_keys,
+        "value": i32 => vec![10, 20],
+    }?;
+
+    // 默认 null 键不匹配
+    let inner = left.inner_join(&right, &["key"], &["key"])?;
+    assert_eq!(inner.shape(), (1, 3));
+    let left_joined = left.left_join(&right, &["key"], &["key"])?;
+    let value_right: &Series<i32> = left_joined.downcast_column("value_right")?;
+    assert_eq!(value_right.data_internal(), vec![Some(20), None, None]);
+
+    let options = JoinOptions::builder()
+        .with_right_suffix("_r")
+        .nulls_equal(true)
+        .build();
+    let inner_nulls = left.join(&right, &["key"], &["key"], JoinType::Inner, Some(options))?;
+    assert_eq!(inner_nulls.columns_names(), vec!["key", "value", "value_r"]);
+    let value_r: &Series<i32> = inner_nulls.downcast_column("value_r")?;
+    assert_eq!(value_r.data_internal(), vec![Some(20), Some(10)]);
+
+    let right_options = JoinOptions::builder().with_left_suffix("_l").build();
+    let right_joined = left.join(&right, &["key"], &["key"], JoinType::Right, Some(right_options))?;
+    assert_eq!(right_joined.columns_names(), vec!["key", "value", "value_l"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_join_validation() -> AxionResult<()> {
+    let left = df! {
+        "id": i64 => vec![1, 2, 2],
+        "x": i32 => vec![1, 2, 3],
+    }?;
+    let right = df! {
+        "id": i64 => vec![1, 2],
+        "y": i32 => vec![10, 20],
+    }?;
+
+    let many_to_one = JoinOptions::builder().validate(JoinValidation::ManyToOne).build();
+    let joined = left.join(&right, &["id"], &["id"], JoinType::Inner, Some(many_to_one))?;
+    assert_eq!(joined.height(), 3);
+
+    let one_to_many = JoinOptions::builder().validate(JoinValidation::OneToMany).build();
+    match left.join(&right, &["id"], &["id"], JoinType::Inner, Some(one_to_many)) {
+        Err(AxionError::JoinValidationError { validate, side }) => {
+            assert_eq!(validate, "one_to_many");
+            assert_eq!(side, "左侧");
+        }
+        other => panic!("Expected JoinValidationError, got {:?}", other.map(|df| df.shape())),
+    }
+
+    let one_to_one = JoinOptions::builder().validate(JoinValidation::OneToOne).build();
+    assert!(right.join(&right, &["id"], &["id"], JoinType::Left, Some(one_to_one.clone())).is_ok());
+    assert!(matches!(
+        right.join(&left, &["id"], &["id"], JoinType::Left, Some(one_to_one)),
+        Err(AxionError::JoinValidationError { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_semi_anti_cross_join() -> AxionResult<()> {
+    let customers = df! {
+        "id": i32 => vec![Some(1), Some(2), Some(3), None],
+        "name": String => &["a", "b", "c", "d"],
+    }?;
+    let orders = df! {
+        "customer_id": i32 => vec![Some(1), Some(1), Some(3), None],
+        "amount": f64 => vec![1.0, 2.0, 3.0, 4.0],
+    }?;
+
+    let semi = customers.semi_join(&orders, &["id"], &["customer_id"])?;
+    assert_eq!(semi.columns_names(), vec!["id", "name"]);
+    let semi_names: &Series<String> = semi.downcast_column("name")?;
+    assert_eq!(semi_names.data_internal(), vec![Some("a".to_string()), Some("c".to_string())]);
+
+    let anti = customers.anti_join(&orders, &["id"], &["customer_id"])?;
+    let anti_names: &Series<String> = anti.downcast_column("name")?;
+    assert_eq!(anti_names.data_internal(), vec![Some("b".to_string()), Some("d".to_string())]);
+
+    let sizes = df! { "size": String => &["S", "M"] }?;
+    let colors = df! {
+        "color": String => &["red", "blue", "green"],
+        "size": String => &["x", "y", "z"],
+    }?;
+    let cross = sizes.cross_join(&colors)?;
+    assert_eq!(cross.shape(), (6, 3));
+    assert_eq!(cross.columns_names(), vec!["size", "color", "size_right"]);
+    let size_col: &Series<String> = cross.downcast_column("size")?;
+    assert_eq!(size_col.get(3), Some(&"M".to_string()));
+    let color_col: &Series<String> = cross.downcast_column("color")?;
+    assert_eq!(color_col.get(4), Some(&"blue".to_string()));
+
+    let empty_cross = sizes.cross_join(&colors.head(0))?;
+    assert_eq!(empty_cross.shape(), (0, 3));
+
+    Ok(())
+}
+
+#[test]
+fn test_join_asof() -> AxionResult<()> {
+    let readings = df! {
+        "ts": i64 => vec![5, 1, 12, 20, 7],
+        "sensor": String => &["a", "a", "b", "a", "b"],
+        "value": f64 => vec![0.5, 0.1, 1.2, 2.0, 0.7],
+    }?;
+    let mut calibration_ts = Series::new("ts".to_string(), vec![0_i64, 4, 6, 10]);
+    calibration_ts.sort(false);
+    let calibrations = DataFrame::new(vec![
+        Box::new(calibration_ts),
+        Box::new(Series::new("sensor".to_string(), vec!["a", "b", "a", "b"])),
+        Box::new(Series::new("offset".to_string(), vec![1, 2, 3, 4])),
+    ])?;
+
+    // 不分组：匹配键不大于左键的最后一行
+    let joined = readings.join_asof(&calibrations, "ts", "ts", None)?;
+    assert_eq!(joined.columns_names(), vec!["ts", "sensor", "value", "sensor_right", "offset"]);
+    let offset: &Series<i32> = joined.downcast_column("offset")?;
+    assert_eq!(offset.data_internal(), vec![Some(2), Some(1), Some(4), Some(4), Some(3)]);
+
+    // 按传感器分组并限制最大键差
+    let options = AsofJoinOptions::builder()
+        .with_by(&["sensor"])
+        .with_tolerance(5.0)
+        .build();
+    let grouped = readings.join_asof(&calibrations, "ts", "ts", Some(options))?;
+    assert_eq!(grouped.columns_names(), vec!["ts", "sensor", "value", "offset"]);
+    let grouped_offset: &Series<i32> = grouped.downcast_column("offset")?;
+    assert_eq!(grouped_offset.data_internal(), vec![Some(1), Some(1), Some(4), None, Some(2)]);
+
+    // 键类型必须为数值
+    let by_name = readings.join_asof(&calibrations, "sensor", "sensor", None);
+    assert!(matches!(by_name, Err(AxionError::UnsupportedOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_join_on_temporal_keys() -> AxionResult<()> {
+    let orders = DataFrame::new(vec![
+        Box::new(Series::new_from_options("day".to_string(), vec![Date::from_ymd(2024, 3, 1), Date::from_ymd(2024, 3, 2), None])),
+        Box::new(Series::new("amount".to_string(), vec![10, 20, 30])),
+    ])?;
+    let rates = DataFrame::new(vec![
+        Box::new(Series::new_from_options("day".to_string(), vec![Date::from_ymd(2024, 3, 2), Date::from_ymd(2024, 3, 1)])),
+        Box::new(Series::new("rate".to_string(), vec![1.5, 1.25])),
+    ])?;
+    let joined = orders.inner_join(&rates, &["day"], &["day"])?;
+    assert_eq!(joined.column("day")?.dtype(), DataType::Date);
+    assert_eq!(joined.column("day")?.get_str(0), Some("2024-03-01".to_string()));
+    let rate: &Series<f64> = joined.downcast_column("rate")?;
+    assert_eq!(rate.data_internal(), vec![Some(1.25), Some(1.5)]);
+
+    // 时间单位或时区不同的 Datetime 键不能连接
+    let ms = DataType::Datetime(TimeUnit::Milliseconds, None);
+    let trades = DataFrame::new(vec![
+        Box::new(Series::new("ts".to_string(), vec![Datetime(1_000), Datetime(5_500), Datetime(9_000)]).with_dtype(ms.clone())?),
+        Box::new(Series::new("qty".to_string(), vec![1, 2, 3])),
+    ])?;
+    let quotes = DataFrame::new(vec![
+        Box::new(Series::new("ts".to_string(), vec![Datetime(0), Datetime(5_000)]).with_dtype(ms.clone())?),
+        Box::new(Series::new("bid".to_string(), vec![99.5, 100.0])),
+    ])?;
+    let us_quotes = DataFrame::new(vec![Box::new(Series::new("ts".to_string(), vec![Datetime(0)]))])?;
+    assert!(matches!(trades.inner_join(&us_quotes, &["ts"], &["ts"]), Err(AxionError::JoinKeyTypeError { .. })));
+
+    // 容差按列的时间单位（毫秒）计算
+    let options = AsofJoinOptions::builder().with_tolerance(2_000.0).build();
+    let asof = trades.join_asof(&quotes, "ts", "ts", Some(options))?;
+    assert_eq!(asof.column("ts")?.dtype(), ms);
+    let bid: &Series<f64> = asof.downcast_column("bid")?;
+    assert_eq!(bid.data_internal(), vec![Some(99.5), Some(100.0), None]);
+
+    Ok(())
+}
+
+#[test]
+fn test_join_on_decimal_keys() -> AxionResult<()> {
+    let orders = DataFrame::new(vec![
+        Box::new(Series::<Decimal>::from_strs("price".to_string(), vec![Some("1.50"), Some("2.00"), Some("9.99")], 10, 2)?),
+        Box::new(Series::new("qty".to_string(), vec![1, 2, 3])),
+    ])?;
+    // 总位数不同但小数位数相同的 Decimal 键可以连接
+    let tiers = DataFrame::new(vec![
+        Box::new(Series::<Decimal>::from_strs("price".to_string(), vec![Some("2"), Some("1.5")], 6, 2)?),
+        Box::new(Series::new("tier".to_string(), vec!["mid".to_string(), "low".to_string()])),
+    ])?;
+    let joined = orders.inner_join(&tiers, &["price"], &["price"])?;
+    assert_eq!(joined.column("price")?.dtype(), DataType::Decimal(10, 2));
+    assert_eq!(joined.column("price")?.get_str(0), Some("1.50".to_string()));
+    let tier: &Series<String> = joined.downcast_column("tier")?;
+    assert_eq!(tier.data_internal(), vec![Some("low".to_string()), Some("mid".to_string())]);
+
+    // 小数位数不同时缩放后的整数含义不同，不能连接
+    let scaled = DataFrame::new(vec![
+        Box::new(Series::<Decimal>::from_strs("price".to_string(), vec![Some("1.5")], 10, 1)?),
+    ])?;
+    assert!(matches!(orders.inner_join(&scaled, &["price"], &["price"]), Err(AxionError::JoinKeyTypeError { .. })));
+
+    Ok(())
+}
+
 fn create_sample_df_for_col_ops() -> AxionResult<DataFrame> {
     df![
         "col_a" => vec![Some(10), Some(20), Some(30)],
@@ -448,4 +753,47 @@ fn test_df_rename_column() -> AxionResult<()> {
     assert_eq!(df.columns_names(), expected_names_after_rename);
 
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn test_with_column_insert_and_set_where() -> AxionResult<()> {
+    let mut df = df![
+        "name": String => &["a", "b", "c"],
+        "age": i32 => vec![30, -1, 25],
+        "fare": f64 => vec![10.0, 20.0, 30.0]
+    ]?;
+
+    df.with_column(Box::new(Series::new("age".to_string(), vec![31_i64, 0, 26])))?;
+    assert_eq!(df.columns_names(), vec!["name", "age", "fare"]);
+    assert_eq!(df.dtypes()[1], DataType::Int64);
+    assert_eq!(df.schema().get("age"), Some(&DataType::Int64));
+
+    df.with_column(Box::new(Series::new("flag".to_string(), vec![true, false, true])))?;
+    assert_eq!(df.columns_names(), vec!["name", "age", "fare", "flag"]);
+    let too_short = df.with_column(Box::new(Series::new("fare".to_string(), vec![1.0_f64])));
+    assert!(matches!(too_short, Err(AxionError::MismatchedLengths { .. })));
+
+    df.insert_column(0, Box::new(Series::new("id".to_string(), vec![1_u32, 2, 3])))?;
+    df.insert_column(5, Box::new(Series::new("last".to_string(), vec![0_u8, 0, 0])))?;
+    assert_eq!(df.columns_names(), vec!["id", "name", "age", "fare", "flag", "last"]);
+    assert!(matches!(df.insert_column(9, Box::new(Series::new("x".to_string(), vec![0_u8, 0, 0]))), Err(AxionError::IndexOutOfBounds(9, 6))));
+    assert!(matches!(df.insert_column(1, Box::new(Series::new("id".to_string(), vec![0_u8, 0, 0]))), Err(AxionError::DuplicateColumnName(_))));
+    assert_eq!(df.width(), 6);
+
+    let mask = Series::new_from_options("mask".to_string(), vec![Some(false), Some(true), None]);
+    df.set_where("fare", &mask, Some(0.0))?;
+    df.set_where::<i64>("age", &mask, None)?;
+    df.set_where("name", &mask, Some("x".to_string()))?;
+    let fare: &Series<f64> = df.downcast_column("fare")?;
+    assert_eq!(fare.data_internal(), vec![Some(10.0), Some(0.0), Some(30.0)]);
+    let age: &Series<i64> = df.downcast_column("age")?;
+    assert_eq!(age.data_internal(), vec![Some(31), None, Some(26)]);
+    let name: &Series<String> = df.downcast_column("name")?;
+    assert_eq!(name.data_internal(), vec![Some("a".to_string()), Some("x".to_string()), Some("c".to_string())]);
+
+    assert!(matches!(df.set_where("fare", &mask, Some(1_i32)), Err(AxionError::TypeMismatch { .. })));
+    assert!(matches!(df.set_where("missing", &mask, Some(1_i32)), Err(AxionError::ColumnNotFound(_))));
+    let short_mask = Series::new("mask".to_string(), vec![true]);
+    assert!(matches!(df.set_where("fare", &short_mask, Some(1.0)), Err(AxionError::MismatchedLengths { .. })));
+
+    Ok(())
+}
@@ -1,8 +1,9 @@
 use axion_data::df;
 use axion_data::error::{AxionResult, AxionError};
-use axion_data::dataframe::DataFrame;
-use axion_data::dtype::DataType;
-use axion_data::series::Series;
+use axion_data::dataframe::{Agg, ClosedWindow, DataFrame, GroupByOptions};
+use axion_data::dtype::{DataType, TimeUnit};
+use axion_data::series::{new_list_series, Series, SeriesArithSeries};
+use axion_data::{Date, Datetime, Decimal, Duration};
 
 #[test]
 fn test_groupby_count_single_key_string() -> AxionResult<()> {
@@ -127,12 +128,19 @@ fn test_groupby_error_handling() -> AxionResult<()> {
     }
 
     // 不支持的类型
-    let df_float = df![
-        "group": f64 => vec![1.1, 2.2, 1.1],
-        "value": i64 => vec![1, 2, 3]
-    ]?;
-    match df_float.groupby(&["group"]) {
-        Err(AxionError::UnsupportedOperation(msg)) if msg.contains("Float64") => {}
+    let list_col = new_list_series(
+        "group".to_string(),
+        vec![
+            Box::new(Series::new("".to_string(), vec![1, 2])),
+            Box::new(Series::new("".to_string(), vec![3])),
+        ],
+    )?;
+    let df_list = DataFrame::new(vec![
+        Box::new(list_col),
+        Box::new(Series::new("value".to_string(), vec![1_i64, 2])),
+    ])?;
+    match df_list.groupby(&["group"]) {
+        Err(AxionError::UnsupportedOperation(msg)) if msg.contains("List") => {}
         _ => panic!("Expected UnsupportedOperation error"),
     }
 
@@ -221,12 +229,12 @@ fn test_groupby_sum_with_nulls_and_nan() -> AxionResult<()> {
     // 比较key列
     let key_col_actual = sorted_summed_nan_df.column("key")?.as_any().downcast_ref::<Series<String>>().unwrap();
     let key_col_expected = expected_nan_df.column("key")?.as_any().downcast_ref::<Series<String>>().unwrap();
-    assert_eq!(key_col_actual.data, key_col_expected.data);
+    assert_eq!(key_col_actual.data_internal(), key_col_expected.data_internal());
 
     // 比较val列
     let val_col_actual = sorted_summed_nan_df.column("val")?.as_any().downcast_ref::<Series<f64>>().unwrap();
     let val_col_expected = expected_nan_df.column("val")?.as_any().downcast_ref::<Series<f64>>().unwrap();
-    assert_eq!(val_col_actual.data, val_col_expected.data);
+    assert_eq!(val_col_actual.data_internal(), val_col_expected.data_internal());
 
     Ok(())
 }
@@ -339,4 +347,518 @@ fn test_groupby_mean_empty_df() -> AxionResult<()> {
     assert_eq!(mean_df, expected_df);
 
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn test_groupby_wide_integer_and_float_keys() -> AxionResult<()> {
+    let df = df![
+        "id": i64 => vec![10_000_000_000, 7, 10_000_000_000, 7, 3],
+        "code": u8 => vec![1, 1, 1, 2, 2],
+        "value": f64 => vec![1.0, 2.0, 3.0, 4.0, 5.0]
+    ]?;
+
+    let summed = df.groupby(&["id"])?.sum()?.sort(&["id"], &[false])?;
+    let expected = df![
+        "id": i64 => vec![3_i64, 7, 10_000_000_000],
+        "code": u64 => vec![2_u64, 3, 2],
+        "value": f64 => vec![5.0, 6.0, 4.0]
+    ]?;
+    assert_eq!(summed, expected);
+    assert_eq!(summed.column("id")?.dtype(), DataType::Int64);
+
+    let counted = df.groupby(&["id", "code"])?.count()?.sort(&["id", "code"], &[false, false])?;
+    let expected_count = df![
+        "id": i64 => vec![3_i64, 7, 7, 10_000_000_000],
+        "code": u8 => vec![2_u8, 1, 2, 1],
+        "count": u32 => vec![1_u32, 1, 1, 2]
+    ]?;
+    assert_eq!(counted, expected_count);
+
+    // 浮点键按位模式分组，0.0 与 -0.0 归为同一组
+    let df_float = df![
+        "group": f64 => vec![1.5, 0.0, 1.5, -0.0],
+        "value": i32 => vec![1, 2, 3, 4]
+    ]?;
+    let float_counts = df_float.groupby(&["group"])?.count()?.sort(&["group"], &[false])?;
+    let counts: &Series<u32> = float_counts.downcast_column("count")?;
+    assert_eq!(counts.data_internal(), vec![Some(2), Some(2)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_keep_null_keys() -> AxionResult<()> {
+    let group_data: Vec<Option<String>> = vec![Some("a".into()), None, Some("a".into()), Some("b".into()), None];
+    let df = df![
+        "group" => group_data,
+        "value" => &[1, 2, 3, 4, 5]
+    ]?;
+
+    let options = GroupByOptions::builder().dropna(false).build();
+    // 排序时 null 排在最后
+    let counted = df.groupby_with_options(&["group"], options.clone())?.count()?.sort(&["group"], &[false])?;
+    let groups: &Series<String> = counted.downcast_column("group")?;
+    assert_eq!(groups.data_internal(), vec![Some("a".to_string()), Some("b".to_string()), None]);
+    let counts: &Series<u32> = counted.downcast_column("count")?;
+    assert_eq!(counts.data_internal(), vec![Some(2), Some(1), Some(2)]);
+
+    let summed = df.groupby_with_options(&["group"], options)?.sum()?.sort(&["group"], &[false])?;
+    let sums: &Series<i32> = summed.downcast_column("value")?;
+    assert_eq!(sums.data_internal(), vec![Some(4), Some(4), Some(7)]);
+
+    // 多列键中任一列为 null 都会形成独立分组
+    let df_multi = df![
+        "k1": i64 => vec![Some(1), Some(1), None, Some(1)],
+        "k2": bool => vec![Some(true), None, None, None],
+        "v": i32 => vec![1, 2, 3, 4]
+    ]?;
+    let keep_nulls = GroupByOptions::builder().dropna(false).build();
+    let multi = df_multi.groupby_with_options(&["k1", "k2"], keep_nulls)?.count()?;
+    assert_eq!(multi.height(), 3);
+    assert_eq!(df_multi.groupby(&["k1", "k2"])?.count()?.height(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_output_order() -> AxionResult<()> {
+    let df = df![
+        "key": String => &["c", "a", "c", "b", "a"],
+        "value": i32 => vec![1, 2, 3, 4, 5]
+    ]?;
+
+    let grouped = df.groupby(&["key"])?;
+    for result in [grouped.count()?, grouped.sum()?, grouped.mean()?, grouped.max()?] {
+        let keys: &Series<String> = result.downcast_column("key")?;
+        assert_eq!(keys.data_internal(), vec![Some("c".to_string()), Some("a".to_string()), Some("b".to_string())]);
+    }
+    let summed = grouped.sum()?;
+    let sums: &Series<i32> = summed.downcast_column("value")?;
+    assert_eq!(sums.data_internal(), vec![Some(4), Some(7), Some(4)]);
+
+    let sorted = GroupByOptions::builder().sort(true).dropna(false).build();
+    let df_nulls = df![
+        "key": i64 => vec![Some(3), None, Some(1), Some(3), Some(2)],
+        "value": f64 => vec![1.0, 2.0, 3.0, 4.0, 5.0]
+    ]?;
+    let grouped_sorted = df_nulls.groupby_with_options(&["key"], sorted)?;
+    for result in [grouped_sorted.count()?, grouped_sorted.min()?] {
+        let keys: &Series<i64> = result.downcast_column("key")?;
+        assert_eq!(keys.data_internal(), vec![Some(1), Some(2), Some(3), None]);
+    }
+    let min_df = grouped_sorted.min()?;
+    let mins: &Series<f64> = min_df.downcast_column("value")?;
+    assert_eq!(mins.data_internal(), vec![Some(3.0), Some(5.0), Some(1.0), Some(2.0)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_agg() -> AxionResult<()> {
+    let df = df![
+        "pclass": i64 => vec![Some(3), Some(1), Some(3), Some(1), Some(2)],
+        "fare": f64 => vec![Some(7.25), Some(71.28), None, Some(53.1), Some(13.0)],
+        "age": i64 => vec![Some(22), Some(38), Some(26), None, Some(27)],
+        "name": String => vec![Some("a".to_string()), Some("b".to_string()), None, Some("d".to_string()), Some("e".to_string())]
+    ]?;
+
+    let report = df.groupby(&["pclass"])?.agg(&[
+        ("fare", Agg::Mean, "avg_fare"),
+        ("age", Agg::Max, "oldest"),
+        ("age", Agg::Sum, "total_age"),
+        ("name", Agg::Count, "passengers"),
+    ])?;
+
+    assert_eq!(report.columns_names(), vec!["pclass", "avg_fare", "oldest", "total_age", "passengers"]);
+    let keys: &Series<i64> = report.downcast_column("pclass")?;
+    assert_eq!(keys.data_internal(), vec![Some(3), Some(1), Some(2)]);
+    let avg_fare: &Series<f64> = report.downcast_column("avg_fare")?;
+    assert_eq!(avg_fare.data_internal(), vec![Some(7.25), Some((71.28 + 53.1) / 2.0), Some(13.0)]);
+    let oldest: &Series<i64> = report.downcast_column("oldest")?;
+    assert_eq!(oldest.data_internal(), vec![Some(26), Some(38), Some(27)]);
+    let total_age: &Series<i64> = report.downcast_column("total_age")?;
+    assert_eq!(total_age.data_internal(), vec![Some(48), Some(38), Some(27)]);
+    let passengers: &Series<u32> = report.downcast_column("passengers")?;
+    assert_eq!(passengers.data_internal(), vec![Some(1), Some(2), Some(1)]);
+
+    let grouped = df.groupby(&["pclass"])?;
+    assert!(matches!(grouped.agg(&[("name", Agg::Mean, "m")]), Err(AxionError::UnsupportedOperation(_))));
+    assert!(matches!(grouped.agg(&[("missing", Agg::Sum, "s")]), Err(AxionError::ColumnNotFound(_))));
+    assert!(matches!(grouped.agg(&[("fare", Agg::Sum, "pclass")]), Err(AxionError::DuplicateColumnName(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_statistical_aggregations() -> AxionResult<()> {
+    let df = df![
+        "key": String => vec![Some("a".to_string()), Some("b".to_string()), Some("a".to_string()), Some("a".to_string()), Some("b".to_string()), Some("a".to_string())],
+        "value": f64 => vec![Some(1.0), Some(10.0), Some(3.0), None, Some(f64::NAN), Some(2.0)],
+        "tag": String => vec![None, Some("x".to_string()), Some("y".to_string()), Some("y".to_string()), Some("z".to_string()), Some("w".to_string())]
+    ]?;
+    let grouped = df.groupby(&["key"])?;
+
+    let var = grouped.var(1)?;
+    let var_col: &Series<f64> = var.downcast_column("value")?;
+    assert_eq!(var_col.data_internal(), vec![Some(1.0), None]);
+    assert!(!var.columns_names().contains(&"tag"));
+
+    let std = grouped.std(0)?;
+    let std_col: &Series<f64> = std.downcast_column("value")?;
+    let std_a = std_col.get(0).copied().unwrap();
+    assert!((std_a - (2.0f64 / 3.0).sqrt()).abs() < 1e-12);
+    assert_eq!(std_col.get(1), Some(&0.0));
+
+    let median = grouped.median()?;
+    let median_col: &Series<f64> = median.downcast_column("value")?;
+    assert_eq!(median_col.data_internal(), vec![Some(2.0), Some(10.0)]);
+
+    let quantile = grouped.quantile(0.25)?;
+    let quantile_col: &Series<f64> = quantile.downcast_column("value")?;
+    assert_eq!(quantile_col.data_internal(), vec![Some(1.5), Some(10.0)]);
+    assert!(matches!(grouped.quantile(1.5), Err(AxionError::InvalidArgument(_))));
+
+    let n_unique = grouped.n_unique()?;
+    let unique_tags: &Series<u32> = n_unique.downcast_column("tag")?;
+    assert_eq!(unique_tags.data_internal(), vec![Some(2), Some(2)]);
+
+    let first = grouped.first()?;
+    let first_tags: &Series<String> = first.downcast_column("tag")?;
+    assert_eq!(first_tags.data_internal(), vec![Some("y".to_string()), Some("x".to_string())]);
+    let last = grouped.last()?;
+    let last_values: &Series<f64> = last.downcast_column("value")?;
+    assert_eq!(last_values.get(0), Some(&2.0));
+    assert!(last_values.get(1).unwrap().is_nan());
+
+    let nth = grouped.nth(1)?;
+    let nth_values: &Series<f64> = nth.downcast_column("value")?;
+    assert_eq!(nth_values.get(0), Some(&3.0));
+    assert!(nth_values.get(1).unwrap().is_nan());
+    let from_end = grouped.nth(-3)?;
+    let from_end_values: &Series<f64> = from_end.downcast_column("value")?;
+    assert_eq!(from_end_values.data_internal()[0], Some(1.0));
+    assert_eq!(from_end_values.data_internal()[1], None);
+
+    let custom = grouped.agg(&[("value", Agg::Quantile(1.0), "max_q"), ("tag", Agg::Nth(5), "sixth")])?;
+    let max_q: &Series<f64> = custom.downcast_column("max_q")?;
+    assert_eq!(max_q.data_internal(), vec![Some(3.0), Some(10.0)]);
+    let sixth: &Series<String> = custom.downcast_column("sixth")?;
+    assert_eq!(sixth.data_internal(), vec![None, None]);
+    assert!(matches!(grouped.agg(&[("tag", Agg::Std(1), "s")]), Err(AxionError::UnsupportedOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_transform() -> AxionResult<()> {
+    let df = df![
+        "pclass": i32 => vec![Some(1), Some(3), Some(1), None, Some(3)],
+        "fare": f64 => vec![80.0, 8.0, 60.0, 30.0, 10.0]
+    ]?;
+    let grouped = df.groupby(&["pclass"])?;
+
+    let group_mean = grouped.transform("fare", Agg::Mean)?;
+    assert_eq!(group_mean.name(), "fare");
+    assert_eq!(group_mean.len(), df.height());
+    let group_mean: &Series<f64> = group_mean.as_any().downcast_ref().unwrap();
+    assert_eq!(group_mean.data_internal(), vec![Some(70.0), Some(9.0), Some(70.0), None, Some(9.0)]);
+
+    let fare: &Series<f64> = df.downcast_column("fare")?;
+    let deviation = fare.sub_series(group_mean)?;
+    assert_eq!(deviation.data_internal(), vec![Some(10.0), Some(-1.0), Some(-10.0), None, Some(1.0)]);
+
+    let group_size = grouped.transform("fare", Agg::Count)?;
+    let group_size: &Series<u32> = group_size.as_any().downcast_ref().unwrap();
+    assert_eq!(group_size.data_internal(), vec![Some(2), Some(2), Some(2), None, Some(2)]);
+
+    assert!(matches!(grouped.transform("missing", Agg::Mean), Err(AxionError::ColumnNotFound(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_apply() -> AxionResult<()> {
+    let df = df![
+        "key": String => &["a", "b", "a", "b", "a"],
+        "value": i64 => vec![5, 1, 7, 3, 6]
+    ]?;
+    let grouped = df.groupby(&["key"])?;
+
+    let top_two = grouped.apply(|group| Ok(group.sort(&["value"], &[true])?.head(2)))?;
+    assert_eq!(top_two.columns_names(), vec!["key", "value"]);
+    let keys: &Series<String> = top_two.downcast_column("key")?;
+    assert_eq!(keys.data_internal(), vec![Some("a".to_string()), Some("a".to_string()), Some("b".to_string()), Some("b".to_string())]);
+    let values: &Series<i64> = top_two.downcast_column("value")?;
+    assert_eq!(values.data_internal(), vec![Some(7), Some(6), Some(3), Some(1)]);
+
+    let sizes = grouped.apply(|group| {
+        df![
+            "rows": u32 => vec![group.height() as u32]
+        ]
+    })?;
+    let rows: &Series<u32> = sizes.downcast_column("rows")?;
+    assert_eq!(rows.data_internal(), vec![Some(3), Some(2)]);
+
+    let mismatched = grouped.apply(|group| {
+        if group.height() == 3 { group.select(&["key"]) } else { group.select(&["value"]) }
+    });
+    assert!(matches!(mismatched, Err(AxionError::InvalidArgument(_))));
+
+    let failing = grouped.apply(|_| Err(AxionError::ComputeError("boom".into())));
+    assert!(matches!(failing, Err(AxionError::ComputeError(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_parallel_matches_serial() -> AxionResult<()> {
+    let n = 5000;
+    let keys: Vec<Option<i64>> = (0..n).map(|i| if i % 97 == 0 { None } else { Some((i * 7919) % 113) }).collect();
+    let labels: Vec<String> = (0..n).map(|i| format!("g{}", i % 3)).collect();
+    let values: Vec<f64> = (0..n).map(|i| (i as f64) * 0.5).collect();
+    let df = df![
+        "key": i64 => keys,
+        "label": String => labels,
+        "value": f64 => values
+    ]?;
+
+    for dropna in [true, false] {
+        let serial = df.groupby_with_options(&["key", "label"], GroupByOptions::builder().dropna(dropna).build())?;
+        let parallel = df.groupby_with_options(&["key", "label"], GroupByOptions::builder().dropna(dropna).parallel(true).build())?;
+        let specs = [("value", Agg::Sum, "sum"), ("value", Agg::Median, "median"), ("label", Agg::Count, "n")];
+        let serial_result = serial.agg(&specs)?;
+        let parallel_result = parallel.agg(&specs)?;
+        assert_eq!(serial_result.shape(), parallel_result.shape());
+        for col in serial_result.columns_names() {
+            assert!(serial_result.column(col)?.series_equal(parallel_result.column(col)?), "列 {} 不一致", col);
+        }
+    }
+
+    let par_sorted = df.groupby_with_options(&["key"], GroupByOptions::builder().sort(true).parallel(true).build())?.mean()?;
+    let serial_sorted = df.groupby_with_options(&["key"], GroupByOptions::builder().sort(true).build())?.mean()?;
+    for col in serial_sorted.columns_names() {
+        assert!(serial_sorted.column(col)?.series_equal(par_sorted.column(col)?));
+    }
+    assert_eq!(df.par_groupby(&["label"])?.count()?.height(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_dynamic_windows() -> AxionResult<()> {
+    let df = df!(
+        "t": i64 => vec![1, 3, 4, 7, 12, 13],
+        "v": i64 => vec![10, 20, 30, 40, 50, 60]
+    )?;
+
+    let tumbling = df.groupby_dynamic("t", "5i", None, None, ClosedWindow::Left)?.sum()?;
+    assert_eq!(tumbling.downcast_column::<i64>("t")?.data_internal(), vec![Some(0), Some(5), Some(10)]);
+    assert_eq!(tumbling.downcast_column::<i64>("v")?.data_internal(), vec![Some(60), Some(40), Some(110)]);
+
+    let sliding = df
+        .groupby_dynamic("t", "5i", Some("10i"), None, ClosedWindow::Left)?
+        .agg(&[("v", Agg::Count, "n"), ("v", Agg::Max, "max_v")])?;
+    assert_eq!(sliding.downcast_column::<i64>("t")?.data_internal(), vec![Some(0), Some(5), Some(10)]);
+    assert_eq!(sliding.downcast_column::<u32>("n")?.data_internal(), vec![Some(4), Some(3), Some(2)]);
+    assert_eq!(sliding.downcast_column::<i64>("max_v")?.data_internal(), vec![Some(40), Some(60), Some(60)]);
+
+    let right_closed = df.groupby_dynamic("t", "4i", None, Some("1i"), ClosedWindow::Right)?.count()?;
+    assert_eq!(right_closed.downcast_column::<i64>("t")?.data_internal(), vec![Some(-3), Some(1), Some(5), Some(9)]);
+    assert_eq!(right_closed.downcast_column::<u32>("count")?.data_internal(), vec![Some(1), Some(2), Some(1), Some(2)]);
+
+    assert!(matches!(df.groupby_dynamic("t", "0i", None, None, ClosedWindow::Left), Err(AxionError::InvalidArgument(_))));
+    assert!(matches!(df.groupby_dynamic("t", "1h", None, None, ClosedWindow::Left), Err(AxionError::InvalidArgument(_))));
+
+    // 无符号列的窗口起点不能为负
+    let unsigned = df!("t": u32 => vec![0_u32, 3, 12], "v": i64 => vec![1, 2, 3])?;
+    assert!(matches!(
+        unsigned.groupby_dynamic("t", "10", None, Some("-5"), ClosedWindow::Left),
+        Err(AxionError::InvalidArgument(_))
+    ));
+    let later = df!("t": u32 => vec![20_u32, 23, 32], "v": i64 => vec![1, 2, 3])?;
+    let shifted = later.groupby_dynamic("t", "10", None, Some("-5"), ClosedWindow::Left)?.count()?;
+    assert_eq!(shifted.downcast_column::<u32>("t")?.data_internal(), vec![Some(15), Some(25)]);
+    assert_eq!(shifted.downcast_column::<u32>("count")?.data_internal(), vec![Some(2), Some(1)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_resample_timestamps() -> AxionResult<()> {
+    let df = df!(
+        "ts": String => vec![
+            Some("2024-05-01T09:15:00".to_string()),
+            Some("2024-05-01T09:45:00".to_string()),
+            None,
+            Some("2024-05-01T12:05:00".to_string()),
+            Some("2024-05-02T00:30:00".to_string()),
+        ],
+        "amount": f64 => vec![1.0, 2.0, 100.0, 4.0, 8.0]
+    )?;
+
+    let hourly = df.resample("ts", "1h")?.agg(&[("amount", Agg::Sum, "total")])?;
+    let keys = hourly.column("ts")?;
+    assert_eq!(keys.dtype(), DataType::Datetime(TimeUnit::Microseconds, None));
+    let labels: Vec<Option<String>> = (0..keys.len()).map(|i| keys.get_str(i)).collect();
+    assert_eq!(labels, vec![
+        Some("2024-05-01 09:00:00".to_string()),
+        Some("2024-05-01 12:00:00".to_string()),
+        Some("2024-05-02 00:00:00".to_string()),
+    ]);
+    assert_eq!(hourly.downcast_column::<f64>("total")?.data_internal(), vec![Some(3.0), Some(4.0), Some(8.0)]);
+
+    let daily = df.resample("ts", "1d")?.mean()?;
+    assert_eq!(daily.height(), 2);
+    assert_eq!(daily.downcast_column::<f64>("amount")?.data_internal(), vec![Some(7.0 / 3.0), Some(8.0)]);
+
+    let shifted = df.groupby_dynamic("ts", "1d", None, Some("6h"), ClosedWindow::Left)?.count()?;
+    assert_eq!(shifted.column("ts")?.get_str(0), Some("2024-05-01 06:00:00".to_string()));
+    assert_eq!(shifted.downcast_column::<u32>("count")?.data_internal(), vec![Some(4)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_int128_columns() -> AxionResult<()> {
+    let df = df! {
+        "hash": u128 => vec![u128::MAX, 1, u128::MAX, 1, 2],
+        "counter": i128 => vec![i128::MAX / 2, 10, i128::MAX / 2, -4, 3]
+    }?;
+
+    let grouped = df.groupby(&["hash"])?.sum()?.sort(&["hash"], &[false])?;
+    assert_eq!(grouped.schema().get("hash"), Some(&DataType::UInt128));
+    assert_eq!(grouped.downcast_column::<u128>("hash")?.data_internal(), vec![Some(1), Some(2), Some(u128::MAX)]);
+    assert_eq!(grouped.downcast_column::<i128>("counter")?.data_internal(), vec![Some(6), Some(3), Some(i128::MAX - 1)]);
+
+    let stats = df.groupby(&["hash"])?.agg(&[
+        ("counter", Agg::Min, "counter_min"),
+        ("counter", Agg::Mean, "counter_mean"),
+        ("counter", Agg::NUnique, "counter_n"),
+    ])?.sort(&["hash"], &[true])?;
+    assert_eq!(stats.downcast_column::<i128>("counter_min")?.data_internal(), vec![Some(i128::MAX / 2), Some(3), Some(-4)]);
+    assert_eq!(stats.downcast_column::<f64>("counter_mean")?.get(1), Some(&3.0));
+    assert_eq!(stats.downcast_column::<u32>("counter_n")?.data_internal(), vec![Some(1), Some(1), Some(2)]);
+
+    let empty = df.filter(&Series::new("mask".to_string(), vec![false; 5]))?;
+    let empty_sum = empty.groupby(&["hash"])?.sum()?;
+    assert_eq!(empty_sum.schema().get("counter"), Some(&DataType::Int128));
+
+    let sorted = df.sort(&["counter"], &[true])?;
+    assert_eq!(sorted.downcast_column::<i128>("counter")?.get(4), Some(&-4));
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_temporal_keys() -> AxionResult<()> {
+    let days = vec![Date::from_ymd(2024, 3, 2), Date::from_ymd(2024, 3, 1), Date::from_ymd(2024, 3, 2), None];
+    let df = DataFrame::new(vec![
+        Box::new(Series::new_from_options("day".to_string(), days)),
+        Box::new(Series::new("wait".to_string(), vec![Duration(5), Duration(7), Duration(5), Duration(1)]).with_dtype(DataType::Duration(TimeUnit::Milliseconds))?),
+        Box::new(Series::new("sales".to_string(), vec![1.0, 2.0, 3.0, 4.0])),
+    ])?;
+
+    let summed = df.groupby(&["day"])?.sum()?;
+    assert_eq!(summed.column("day")?.dtype(), DataType::Date);
+    assert_eq!(summed.column("day")?.get_str(0), Some("2024-03-02".to_string()));
+    let sales: &Series<f64> = summed.downcast_column("sales")?;
+    assert_eq!(sales.data_internal(), vec![Some(4.0), Some(2.0)]);
+
+    // 含时间列的 DataFrame 也能统计不同值的个数
+    let unique = df.groupby(&["day"])?.n_unique()?;
+    let wait_unique: &Series<u32> = unique.downcast_column("wait")?;
+    assert_eq!(wait_unique.data_internal(), vec![Some(1), Some(1)]);
+
+    let by_wait = df.groupby(&["wait"])?.count()?;
+    assert_eq!(by_wait.column("wait")?.dtype(), DataType::Duration(TimeUnit::Milliseconds));
+    assert_eq!(by_wait.height(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_decimal_keys() -> AxionResult<()> {
+    let prices = Series::<Decimal>::from_strs("price".to_string(), vec![Some("1.50"), Some("2.25"), Some("1.5"), None], 10, 2)?;
+    let df = DataFrame::new(vec![
+        Box::new(prices),
+        Box::new(Series::new("qty".to_string(), vec![1_i64, 2, 3, 4])),
+    ])?;
+
+    let summed = df.groupby(&["price"])?.sum()?;
+    assert_eq!(summed.column("price")?.dtype(), DataType::Decimal(10, 2));
+    assert_eq!(summed.column("price")?.get_str(0), Some("1.50".to_string()));
+    let qty: &Series<i64> = summed.downcast_column("qty")?;
+    assert_eq!(qty.data_internal(), vec![Some(4), Some(2)]);
+
+    // 含 Decimal 列的 DataFrame 也能统计不同值的个数
+    let unique = df.groupby(&["qty"])?.n_unique()?;
+    let price_unique: &Series<u32> = unique.downcast_column("price")?;
+    assert_eq!(price_unique.data_internal(), vec![Some(1), Some(1), Some(1), Some(0)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_sum_small_integers_widens() -> AxionResult<()> {
+    let df = df![
+        "key": String => &["a", "a", "b", "a"],
+        "small": i8 => vec![Some(100_i8), Some(100), Some(-128), None],
+        "short": i16 => vec![30_000_i16, 30_000, -5, 1],
+        "byte": u8 => vec![200_u8, 200, 7, 1],
+        "word": u16 => vec![60_000_u16, 60_000, 0, 1]
+    ]?;
+
+    // 整框求和与 agg 显式求和都保留 8 位和 16 位整数列，结果扩展为 64 位，不会饱和
+    let summed = df.groupby(&["key"])?.sum()?;
+    let expected = df![
+        "key": String => &["a", "b"],
+        "small": i64 => vec![200_i64, -128],
+        "short": i64 => vec![60_001_i64, -5],
+        "byte": u64 => vec![401_u64, 7],
+        "word": u64 => vec![120_001_u64, 0]
+    ]?;
+    assert_eq!(summed, expected);
+
+    let agg = df.groupby(&["key"])?.agg(&[("small", Agg::Sum, "small_sum"), ("byte", Agg::Sum, "byte_sum")])?;
+    let small: &Series<i64> = agg.downcast_column("small_sum")?;
+    assert_eq!(small.data_internal(), vec![Some(200), Some(-128)]);
+    let bytes: &Series<u64> = agg.downcast_column("byte_sum")?;
+    assert_eq!(bytes.data_internal(), vec![Some(401), Some(7)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_groupby_min_max_temporal_columns() -> AxionResult<()> {
+    let utc_ms = DataType::Datetime(TimeUnit::Milliseconds, Some("UTC".to_string()));
+    let df = DataFrame::new(vec![
+        Box::new(Series::new("key".to_string(), vec!["a".to_string(), "b".to_string(), "a".to_string(), "b".to_string()])),
+        Box::new(Series::new_from_options("ts".to_string(), vec![Some(Datetime(5_000)), None, Some(Datetime(1_000)), Some(Datetime(-2_000))]).with_dtype(utc_ms.clone())?),
+        Box::new(Series::new_from_options("day".to_string(), vec![Date::from_ymd(2024, 3, 2), Date::from_ymd(2024, 1, 9), Date::from_ymd(2023, 12, 31), None])),
+    ])?;
+
+    // 整框 min/max 保留时间列，结果保持原有的时间单位和时区
+    let earliest = df.groupby(&["key"])?.min()?;
+    assert_eq!(earliest.columns_names(), vec!["key", "ts", "day"]);
+    assert_eq!(earliest.column("ts")?.dtype(), utc_ms);
+    let ts: &Series<Datetime> = earliest.downcast_column("ts")?;
+    assert_eq!(ts.data_internal(), vec![Some(Datetime(1_000)), Some(Datetime(-2_000))]);
+    let days: &Series<Date> = earliest.downcast_column("day")?;
+    assert_eq!(days.data_internal(), vec![Date::from_ymd(2023, 12, 31), Date::from_ymd(2024, 1, 9)]);
+
+    let latest = df.groupby(&["key"])?.agg(&[("ts", Agg::Max, "last_ts"), ("day", Agg::Max, "last_day")])?;
+    assert_eq!(latest.column("last_ts")?.dtype(), utc_ms);
+    let ts: &Series<Datetime> = latest.downcast_column("last_ts")?;
+    assert_eq!(ts.data_internal(), vec![Some(Datetime(5_000)), Some(Datetime(-2_000))]);
+    assert_eq!(latest.column("last_day")?.get_str(0), Some("2024-03-02".to_string()));
+
+    let waits = DataFrame::new(vec![
+        Box::new(Series::new("key".to_string(), vec![1, 1])),
+        Box::new(Series::new("wait".to_string(), vec![Duration(3), Duration(9)]).with_dtype(DataType::Duration(TimeUnit::Milliseconds))?),
+    ])?;
+    let longest = waits.groupby(&["key"])?.max()?;
+    assert_eq!(longest.column("wait")?.dtype(), DataType::Duration(TimeUnit::Milliseconds));
+    let wait: &Series<Duration> = longest.downcast_column("wait")?;
+    assert_eq!(wait.data_internal(), vec![Some(Duration(9))]);
+
+    Ok(())
+}
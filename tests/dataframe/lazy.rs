@@ -0,0 +1,144 @@
+use axion_data::dataframe::{Agg, JoinType};
+use axion_data::{df, AxionError, AxionResult, DataFrame, LazyFrame, Series, SeriesArithScalar, SeriesCompareScalar, SeriesTrait};
+
+fn create_passengers() -> AxionResult<DataFrame> {
+    df![
+        "id": i64 => vec![1, 2, 3, 4, 5, 6],
+        "name": String => &["Braund", "Cumings", "Heikkinen", "Futrelle", "Allen", "Moran"],
+        "pclass": i64 => vec![3, 1, 3, 1, 3, 2],
+        "age": f64 => vec![Some(22.0), Some(38.0), Some(26.0), Some(35.0), Some(35.0), None],
+        "fare": f64 => vec![7.25, 71.28, 7.92, 53.1, 8.05, 8.46]
+    ]
+}
+
+fn adults_by_fare(lf: LazyFrame) -> LazyFrame {
+    lf.with_column("fare_x2", &["fare"], |df| Ok(Box::new(df.downcast_column::<f64>("fare")?.mul_scalar(2.0)?)))
+        .filter(&["age"], |df| df.downcast_column::<f64>("age")?.gt_scalar(25.0))
+        .select(&["name", "fare_x2"])
+        .sort(&["fare_x2"], &[true])
+        .limit(3)
+}
+
+#[test]
+fn test_lazy_matches_eager() -> AxionResult<()> {
+    let optimized = adults_by_fare(create_passengers()?.lazy()).collect()?;
+    let unoptimized = adults_by_fare(create_passengers()?.lazy())
+        .with_predicate_pushdown(false)
+        .with_projection_pushdown(false)
+        .collect()?;
+
+    assert_eq!(optimized.columns_names(), vec!["name", "fare_x2"]);
+    let names: &Series<String> = optimized.downcast_column("name")?;
+    assert_eq!(names.data_internal(), vec![Some("Cumings".to_string()), Some("Futrelle".to_string()), Some("Allen".to_string())]);
+    let fares: &Series<f64> = optimized.downcast_column("fare_x2")?;
+    assert_eq!(fares.data_internal(), vec![Some(142.56), Some(106.2), Some(16.1)]);
+    for name in optimized.columns_names() {
+        assert!(optimized.column(name)?.series_equal(unoptimized.column(name)?));
+    }
+
+    // 过滤越过 select 但停在 with_column 之上，数据源只保留用到的列
+    let plan = adults_by_fare(create_passengers()?.lazy()).explain(true);
+    assert_eq!(
+        plan,
+        "LIMIT 3\n\
+         \x20 SORT BY [fare_x2 DESC]\n\
+         \x20   SELECT [name, fare_x2]\n\
+         \x20     FILTER ON [age]\n\
+         \x20       WITH COLUMN fare_x2 FROM [fare]\n\
+         \x20         DATAFRAME SCAN [id, name, pclass, age, fare]; PROJECT [name, age, fare]\n"
+    );
+    assert!(adults_by_fare(create_passengers()?.lazy()).explain(false).starts_with("LIMIT 3\n  SORT BY"));
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_filter_stays_above_column_wide_with_column() -> AxionResult<()> {
+    // 新列依赖整列的均值，先过滤会改变均值
+    let query = || -> AxionResult<LazyFrame> {
+        Ok(df!["a": i64 => vec![1, 2, 3, 4], "x": f64 => vec![10.0, 20.0, 30.0, 40.0]]?
+            .lazy()
+            .with_column("xc", &["x"], |df| {
+                let x: &Series<f64> = df.downcast_column("x")?;
+                let mean = x.mean().unwrap_or(0.0);
+                Ok(Box::new(x.sub_scalar(mean)?) as Box<dyn SeriesTrait>)
+            })
+            .filter(&["a"], |df| df.downcast_column::<i64>("a")?.gt_scalar(2)))
+    };
+
+    let optimized = query()?.collect()?;
+    let unoptimized = query()?.with_predicate_pushdown(false).collect()?;
+    let centered: &Series<f64> = optimized.downcast_column("xc")?;
+    assert_eq!(centered.data_internal(), vec![Some(5.0), Some(15.0)]);
+    assert!(optimized.column("xc")?.series_equal(unoptimized.column("xc")?));
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_groupby_and_join_pushdown() -> AxionResult<()> {
+    let classes = df![
+        "pclass": i64 => vec![1, 2, 3],
+        "deck": String => &["A", "D", "F"],
+        "fare": f64 => vec![0.0, 0.0, 0.0]
+    ]?;
+
+    let query = || -> AxionResult<LazyFrame> {
+        Ok(create_passengers()?
+            .lazy()
+            .join(classes.clone().lazy(), &["pclass"], &["pclass"], JoinType::Inner, None)
+            .filter(&["deck"], |df| df.downcast_column::<String>("deck")?.neq_scalar("D".to_string()))
+            .filter(&["age"], |df| df.downcast_column::<f64>("age")?.lt_scalar(36.0))
+            .groupby(&["deck"])
+            .agg(&[("fare", Agg::Sum, "total_fare"), ("fare_right", Agg::Max, "class_fare")])
+            .filter(&["deck"], |df| df.downcast_column::<String>("deck")?.neq_scalar("F".to_string()))
+            .sort(&["deck"], &[false]))
+    };
+
+    let result = query()?.collect()?;
+    assert_eq!(result.columns_names(), vec!["deck", "total_fare", "class_fare"]);
+    let decks: &Series<String> = result.downcast_column("deck")?;
+    assert_eq!(decks.data_internal(), vec![Some("A".to_string())]);
+    let totals: &Series<f64> = result.downcast_column("total_fare")?;
+    assert_eq!(totals.data_internal(), vec![Some(53.1)]);
+
+    let unoptimized = query()?.with_predicate_pushdown(false).with_projection_pushdown(false).collect()?;
+    assert!(result.column("total_fare")?.series_equal(unoptimized.column("total_fare")?));
+
+    let plan = query()?.explain(true);
+    assert_eq!(
+        plan,
+        "SORT BY [deck]\n\
+         \x20 GROUP BY [deck] AGG [Sum(fare) AS total_fare, Max(fare_right) AS class_fare]\n\
+         \x20   Inner JOIN ON [pclass] = [pclass]\n\
+         \x20     FILTER ON [age]\n\
+         \x20       DATAFRAME SCAN [id, name, pclass, age, fare]; PROJECT [pclass, age, fare]\n\
+         \x20     FILTER ON [deck]\n\
+         \x20       FILTER ON [deck]\n\
+         \x20         DATAFRAME SCAN [pclass, deck, fare]\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_lazy_errors_surface_on_collect() -> AxionResult<()> {
+    let missing = create_passengers()?.lazy().select(&["name", "missing"]);
+    assert!(matches!(missing.collect(), Err(AxionError::ColumnNotFound(_))));
+
+    let unused = create_passengers()?
+        .lazy()
+        .with_column("broken", &["age"], |_| Err(AxionError::ComputeError("不应被执行".to_string())))
+        .select(&["id"]);
+    assert_eq!(unused.columns_names(), vec!["id".to_string()]);
+    assert_eq!(unused.collect()?.shape(), (6, 1));
+
+    let counted = create_passengers()?
+        .lazy()
+        .with_column("one", &[], |df| Ok(Box::new(Series::new("one".to_string(), vec![1_i64; df.height()]))))
+        .select(&["one"])
+        .collect()?;
+    assert_eq!(counted.shape(), (6, 1));
+
+    Ok(())
+}
@@ -1,2 +1,4 @@
+pub mod concat;
 pub mod core;
-pub mod groupby;
\ No newline at end of file
+pub mod groupby;
+pub mod reshape;pub mod lazy;
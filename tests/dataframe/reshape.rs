@@ -0,0 +1,119 @@
+use axion_data::{df, AxionError, AxionResult, DataFrame, DataType, ListSeries, Series, SeriesTrait};
+use axion_data::dataframe::Agg;
+
+#[test]
+fn test_pivot() -> AxionResult<()> {
+    let df = df![
+        "city": String => vec![Some("bj".to_string()), Some("sh".to_string()), Some("bj".to_string()), Some("bj".to_string()), Some("sh".to_string()), None],
+        "month": String => vec![Some("jan".to_string()), Some("jan".to_string()), Some("feb".to_string()), Some("jan".to_string()), Some("mar".to_string()), Some("feb".to_string())],
+        "sales": i64 => vec![10, 20, 30, 5, 7, 1]
+    ]?;
+
+    let wide = df.pivot(&["city"], "month", "sales", Agg::Sum)?;
+    assert_eq!(wide.columns_names(), vec!["city", "jan", "feb", "mar"]);
+    let city: &Series<String> = wide.downcast_column("city")?;
+    assert_eq!(city.data_internal(), vec![Some("bj".to_string()), Some("sh".to_string()), None]);
+    let jan: &Series<i64> = wide.downcast_column("jan")?;
+    assert_eq!(jan.data_internal(), vec![Some(15), Some(20), None]);
+    let feb: &Series<i64> = wide.downcast_column("feb")?;
+    assert_eq!(feb.data_internal(), vec![Some(30), None, Some(1)]);
+    let mar: &Series<i64> = wide.downcast_column("mar")?;
+    assert_eq!(mar.data_internal(), vec![None, Some(7), None]);
+
+    let means = df.pivot(&["city"], "month", "sales", Agg::Mean)?;
+    assert_eq!(means.column("jan")?.dtype(), DataType::Float64);
+    let jan_mean: &Series<f64> = means.downcast_column("jan")?;
+    assert_eq!(jan_mean.data_internal()[0], Some(7.5));
+
+    assert!(matches!(df.pivot(&["city"], "month", "city", Agg::Count), Err(AxionError::InvalidArgument(_))));
+    assert!(matches!(df.pivot(&["city"], "month", "month", Agg::Sum), Err(AxionError::InvalidArgument(_))));
+    assert!(matches!(df.pivot(&["city"], "nope", "sales", Agg::Sum), Err(AxionError::ColumnNotFound(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_melt_round_trip() -> AxionResult<()> {
+    let wide = df![
+        "city": String => &["bj", "sh"],
+        "jan": i64 => vec![Some(1), None],
+        "feb": i64 => vec![3, 4]
+    ]?;
+
+    let long = wide.melt(&["city"], &["jan", "feb"])?;
+    assert_eq!(long.shape(), (4, 3));
+    assert_eq!(long.columns_names(), vec!["city", "variable", "value"]);
+    let city: &Series<String> = long.downcast_column("city")?;
+    assert_eq!(city.data_internal(), vec![Some("bj".to_string()), Some("sh".to_string()), Some("bj".to_string()), Some("sh".to_string())]);
+    let variable: &Series<String> = long.downcast_column("variable")?;
+    assert_eq!(variable.data_internal(), vec![Some("jan".to_string()), Some("jan".to_string()), Some("feb".to_string()), Some("feb".to_string())]);
+    let value: &Series<i64> = long.downcast_column("value")?;
+    assert_eq!(value.data_internal(), vec![Some(1), None, Some(3), Some(4)]);
+
+    let all_value_vars = wide.melt(&["city"], &[])?;
+    assert_eq!(all_value_vars.shape(), (4, 3));
+
+    let back = long.pivot(&["city"], "variable", "value", Agg::First)?;
+    assert_eq!(back.columns_names(), vec!["city", "jan", "feb"]);
+    let jan: &Series<i64> = back.downcast_column("jan")?;
+    assert_eq!(jan.data_internal(), vec![Some(1), None]);
+
+    let mixed = df![
+        "id": i32 => vec![1],
+        "a": i64 => vec![1],
+        "b": f64 => vec![1.0]
+    ]?;
+    assert!(matches!(mixed.melt(&["id"], &["a", "b"]), Err(AxionError::TypeMismatch { .. })));
+    assert!(matches!(mixed.melt(&["id"], &["missing"]), Err(AxionError::ColumnNotFound(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_explode_implode_round_trip() -> AxionResult<()> {
+    let item = |values: Vec<&str>| -> Option<Box<dyn SeriesTrait>> {
+        Some(Box::new(Series::new("item".to_string(), values.into_iter().map(String::from).collect::<Vec<_>>())))
+    };
+    let tags = ListSeries::new(
+        "tags".to_string(),
+        vec![item(vec!["a", "b"]), None, item(vec![]), item(vec!["c"])],
+        DataType::String,
+    );
+    let df = DataFrame::new(vec![
+        Box::new(Series::new("id".to_string(), vec![1_i64, 2, 3, 4])),
+        Box::new(tags),
+    ])?;
+
+    let exploded = df.explode("tags")?;
+    assert_eq!(exploded.shape(), (5, 2));
+    let id: &Series<i64> = exploded.downcast_column("id")?;
+    assert_eq!(id.data_internal(), vec![Some(1), Some(1), Some(2), Some(3), Some(4)]);
+    let exploded_tags: &Series<String> = exploded.downcast_column("tags")?;
+    assert_eq!(
+        exploded_tags.data_internal(),
+        vec![Some("a".to_string()), Some("b".to_string()), None, None, Some("c".to_string())]
+    );
+
+    let imploded = df![
+        "id": i64 => vec![2, 1, 2, 1],
+        "tag": String => &["x", "a", "y", "b"]
+    ]?.implode(&["id"])?;
+    assert_eq!(imploded.columns_names(), vec!["id", "tag"]);
+    assert_eq!(imploded.column("tag")?.dtype(), DataType::List(Box::new(DataType::String)));
+    let imploded_ids: &Series<i64> = imploded.downcast_column("id")?;
+    assert_eq!(imploded_ids.data_internal(), vec![Some(2), Some(1)]);
+    assert_eq!(imploded.column("tag")?.get_str(0), Some("[x, y]".to_string()));
+    assert_eq!(imploded.column("tag")?.get_str(1), Some("[a, b]".to_string()));
+
+    let round_trip = imploded.explode("tag")?;
+    let tag: &Series<String> = round_trip.downcast_column("tag")?;
+    assert_eq!(tag.data_internal(), vec![Some("x".to_string()), Some("y".to_string()), Some("a".to_string()), Some("b".to_string())]);
+
+    let grouped = df.explode("tags")?.groupby(&["id"])?.agg(&[("tags", Agg::Implode, "all_tags")])?;
+    assert_eq!(grouped.column("all_tags")?.dtype(), DataType::List(Box::new(DataType::String)));
+
+    assert!(matches!(df.explode("id"), Err(AxionError::TypeMismatch { .. })));
+    assert!(matches!(df.explode("missing"), Err(AxionError::ColumnNotFound(_))));
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
-use axion_data::{read_csv, AxionError, AxionResult, DataFrame, DataType, ReadCsvOptions, Series, WriteCsvOptions};
+use axion_data::dataframe::Agg;
+use axion_data::{read_csv, AxionError, AxionResult, CategoricalSeries, DataFrame, DataType, Date, Datetime, Decimal, LazyFrame, ReadCsvOptions, Series, SeriesCompareScalar, TimeUnit, WriteCsvOptions};
 use tempfile::NamedTempFile;
 use std::collections::HashMap;
 use std::io::Write;
@@ -425,4 +426,156 @@ fn test_csv_roundtrip() -> AxionResult<()> {
     assert_eq!(col_b_read.get_opt(1), Some(Some(&"text2".to_string())));
 
     Ok(())
-}
\ No newline at end of file
+}
+#[test]
+fn test_read_csv_temporal_inference() -> AxionResult<()> {
+    let content = "day,local,utc,wait,mixed\n\
+                   2024-01-31,2024-01-31T08:30:00,2024-01-31T08:30:00+08:00,PT1H30M,2024-01-31T08:30:00Z\n\
+                   2024-02-01,2024-02-01 09:00:00.25,2024-02-01T00:00:00Z,P1D,2024-02-01T09:00:00\n\
+                   ,,,,";
+    let file = create_test_csv(content);
+    let df = read_csv(file.path(), None)?;
+
+    let schema = df.schema();
+    assert_eq!(schema.get("day"), Some(&DataType::Date));
+    assert_eq!(schema.get("local"), Some(&DataType::Datetime(TimeUnit::Microseconds, None)));
+    assert_eq!(schema.get("utc"), Some(&DataType::Datetime(TimeUnit::Microseconds, Some("UTC".to_string()))));
+    assert_eq!(schema.get("wait"), Some(&DataType::Duration(TimeUnit::Microseconds)));
+    assert_eq!(schema.get("mixed"), Some(&DataType::String));
+
+    let utc = df.column("utc")?;
+    assert_eq!(utc.get_str(0), Some("2024-01-31 00:30:00+00:00".to_string()));
+    assert!(utc.is_null_at(2));
+    assert_eq!(df.column("local")?.get_str(1), Some("2024-02-01 09:00:00.250000".to_string()));
+    assert_eq!(df.column("wait")?.get_str(0), Some("PT1H30M".to_string()));
+    let day = df.column("day")?.as_any().downcast_ref::<Series<Date>>().unwrap();
+    assert_eq!(day.get_opt(1), Some(Some(&Date::from_ymd(2024, 2, 1).unwrap())));
+
+    let mut dtypes = HashMap::new();
+    dtypes.insert("local".to_string(), DataType::Datetime(TimeUnit::Milliseconds, None));
+    let options = ReadCsvOptions::builder().with_dtypes(dtypes).build();
+    let df = read_csv(file.path(), Some(options))?;
+    let local = df.column("local")?.as_any().downcast_ref::<Series<Datetime>>().unwrap();
+    assert_eq!(local.dtype(), DataType::Datetime(TimeUnit::Milliseconds, None));
+    assert_eq!(local.get_opt(0), Some(Some(&Datetime(1_706_689_800_000))));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_categorical_columns() -> AxionResult<()> {
+    let options = ReadCsvOptions::builder()
+        .add_dtype("Sex".to_string(), DataType::Categorical)
+        .add_dtype("Embarked".to_string(), DataType::Categorical)
+        .build();
+    let df = read_csv("data/train.csv", Some(options))?;
+
+    let sex = df.column("Sex")?.as_any().downcast_ref::<CategoricalSeries>().unwrap();
+    assert_eq!(df.schema().get("Sex"), Some(&DataType::Categorical));
+    assert_eq!(sex.categories(), &["male".to_string(), "female".to_string()]);
+    assert_eq!(sex.codes().len(), df.height());
+
+    let counts = df.groupby(&["Sex"])?.count()?;
+    assert_eq!(counts.height(), 2);
+    let total: u32 = counts.downcast_column::<u32>("count")?.iter_valid().sum();
+    assert_eq!(total as usize, df.height());
+
+    let embarked = df.column("Embarked")?;
+    assert!((0..embarked.len()).any(|i| embarked.is_null_at(i)));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_decimal_columns() -> AxionResult<()> {
+    let options = ReadCsvOptions::builder()
+        .add_dtype("Fare".to_string(), DataType::Decimal(12, 4))
+        .build();
+    let df = read_csv("data/train.csv", Some(options))?;
+    assert_eq!(df.schema().get("Fare"), Some(&DataType::Decimal(12, 4)));
+
+    let fare = df.downcast_column::<Decimal>("Fare")?;
+    let fare_column = df.column("Fare")?;
+    assert_eq!(fare_column.get_str(0), Some("7.2500".to_string()));
+    assert_eq!(fare_column.get_str(1), Some("71.2833".to_string()));
+    assert_eq!(fare.null_count(), 0);
+
+    let sums = df.groupby(&["Pclass"])?.agg(&[("Fare", Agg::Sum, "Fare_sum"), ("Fare", Agg::Mean, "Fare_mean")])?;
+    assert_eq!(sums.schema().get("Fare_sum"), Some(&DataType::Decimal(38, 4)));
+    assert_eq!(sums.schema().get("Fare_mean"), Some(&DataType::Decimal(12, 4)));
+    let total: i128 = sums.downcast_column::<Decimal>("Fare_sum")?.iter_valid().map(|v| v.0).sum();
+    assert_eq!(Some(Decimal(total)), fare.sum());
+
+    let content = "item,amount\na,19.99\nb,0.015\nc,-3\n";
+    let file = create_test_csv(content);
+    let options = ReadCsvOptions::builder()
+        .add_dtype("amount".to_string(), DataType::Decimal(6, 2))
+        .build();
+    let df = read_csv(file.path(), Some(options))?;
+    let amount = df.column("amount")?;
+    assert_eq!(amount.get_str(0), Some("19.99".to_string()));
+    assert!(amount.is_null_at(1));
+    assert_eq!(amount.get_str(2), Some("-3.00".to_string()));
+
+    let mut buffer = Vec::new();
+    df.to_csv_writer(&mut buffer, None)?;
+    assert!(String::from_utf8(buffer).unwrap().contains("a,19.99\n"));
+
+    Ok(())
+}
+
+#[test]
+fn test_csv_int128_round_trip() -> AxionResult<()> {
+    let content = format!("hash,counter\n{},{}\n7,-3\n,1\n", u128::MAX, i128::MIN);
+    let file = create_test_csv(&content);
+    let options = ReadCsvOptions::builder()
+        .add_dtype("hash".to_string(), DataType::UInt128)
+        .add_dtype("counter".to_string(), DataType::Int128)
+        .build();
+    let df = read_csv(file.path(), Some(options))?;
+    assert_eq!(df.schema().get("hash"), Some(&DataType::UInt128));
+    assert_eq!(df.downcast_column::<u128>("hash")?.data_internal(), vec![Some(u128::MAX), Some(7), None]);
+    assert_eq!(df.downcast_column::<i128>("counter")?.data_internal(), vec![Some(i128::MIN), Some(-3), Some(1)]);
+
+    let mut buffer = Vec::new();
+    df.to_csv_writer(&mut buffer, None)?;
+    let written = String::from_utf8(buffer).unwrap();
+    assert!(written.starts_with(&format!("hash,counter\n{},{}\n", u128::MAX, i128::MIN)));
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_csv_projection_pushdown() -> AxionResult<()> {
+    let content = "id,name,score,notes\n\
+                   1,a,90,x\n\
+                   2,b,75,y\n\
+                   3,c,82,z";
+    let file = create_test_csv(content);
+
+    let query = || -> AxionResult<LazyFrame> {
+        Ok(LazyFrame::scan_csv(file.path(), None)?
+            .filter(&["score"], |df| df.downcast_column::<i64>("score")?.gt_scalar(80))
+            .select(&["name"]))
+    };
+
+    let plan = query()?.explain(true);
+    assert!(plan.contains("; PROJECT [name, score]"));
+    assert!(plan.starts_with("SELECT [name]\n  FILTER ON [score]\n    CSV SCAN"));
+
+    let df = query()?.collect()?;
+    assert_eq!(df.columns_names(), vec!["name"]);
+    let names: &Series<String> = df.downcast_column("name")?;
+    assert_eq!(names.data_internal(), vec![Some("a".to_string()), Some("c".to_string())]);
+
+    let limited = ReadCsvOptions::builder().use_columns(vec!["score".to_string(), "id".to_string()]).build();
+    let lf = LazyFrame::scan_csv(file.path(), Some(limited))?;
+    assert_eq!(lf.columns_names(), vec!["score".to_string(), "id".to_string()]);
+    assert_eq!(lf.collect()?.shape(), (3, 2));
+
+    let wrong = ReadCsvOptions::builder().use_columns(vec!["nope".to_string()]).build();
+    assert!(matches!(LazyFrame::scan_csv(file.path(), Some(wrong)), Err(AxionError::CsvError(_))));
+    assert!(matches!(LazyFrame::scan_csv("/nonexistent/data.csv", None), Err(AxionError::IoError(_))));
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use axion_data::{AxionResult, CategoricalSeries, DataFrame, DataType, Series, SeriesTrait};
+
+fn create_embarked() -> CategoricalSeries {
+    CategoricalSeries::from_strings(
+        "Embarked".to_string(),
+        vec![Some("S"), Some("C"), Some("S"), None, Some("Q"), Some("S")],
+    )
+}
+
+#[test]
+fn test_categorical_encoding_and_cast() -> AxionResult<()> {
+    let embarked = create_embarked();
+    assert_eq!(embarked.dtype(), DataType::Categorical);
+    assert_eq!(embarked.categories(), &["S".to_string(), "C".to_string(), "Q".to_string()]);
+    assert_eq!(embarked.codes().data_internal(), vec![Some(0), Some(1), Some(0), None, Some(2), Some(0)]);
+    assert_eq!(embarked.get(4), Some("Q"));
+    assert_eq!(embarked.get_str(3), Some("null".to_string()));
+    assert_eq!(embarked.to_string(), "[S, C, S, null, Q, S]");
+
+    let strings = embarked.cast(DataType::String)?;
+    let strings = strings.as_any().downcast_ref::<Series<String>>().unwrap();
+    assert_eq!(strings.get(1), Some(&"C".to_string()));
+    assert!(strings.is_null_at(3));
+
+    let back = SeriesTrait::cast(strings, DataType::Categorical)?;
+    assert!(back.series_equal(&embarked));
+
+    let numbers: Box<dyn SeriesTrait> = Box::new(Series::new("n".to_string(), vec![3_i64, 1, 3]));
+    let numeric_categories = numbers.cast(DataType::Categorical)?;
+    let numeric_categories = numeric_categories.as_any().downcast_ref::<CategoricalSeries>().unwrap();
+    assert_eq!(numeric_categories.categories(), &["3".to_string(), "1".to_string()]);
+    let round_trip = numeric_categories.cast(DataType::Int64)?;
+    assert!(round_trip.series_equal(numbers.as_ref()));
+
+    let filtered = embarked.filter(&Series::new("m".to_string(), vec![false, true, false, true, true, false]))?;
+    let filtered = filtered.as_any().downcast_ref::<CategoricalSeries>().unwrap();
+    assert!(filtered.same_dictionary(&embarked));
+    assert_eq!(filtered.iter().collect::<Vec<_>>(), vec![Some("C"), None, Some("Q")]);
+
+    let mut combined = filtered.clone();
+    combined.append(&CategoricalSeries::from_strings("other".to_string(), vec![Some("X"), Some("C")]));
+    assert_eq!(combined.iter().collect::<Vec<_>>(), vec![Some("C"), None, Some("Q"), Some("X"), Some("C")]);
+    assert_eq!(combined.categories().len(), 4);
+    assert_eq!(embarked.categories().len(), 3);
+
+    Ok(())
+}
+
+#[test]
+fn test_categorical_groupby_sort_and_join() -> AxionResult<()> {
+    let df = DataFrame::new(vec![
+        Box::new(create_embarked()),
+        Box::new(Series::new("fare".to_string(), vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0])),
+    ])?;
+
+    let sums = df.groupby(&["Embarked"])?.sum()?;
+    let keys = sums.column("Embarked")?;
+    assert_eq!(keys.dtype(), DataType::Categorical);
+    let labels: Vec<Option<String>> = (0..keys.len()).map(|i| keys.get_str(i)).collect();
+    assert_eq!(labels, vec![Some("S".to_string()), Some("C".to_string()), Some("Q".to_string())]);
+    assert_eq!(sums.downcast_column::<f64>("fare")?.data_internal(), vec![Some(100.0), Some(20.0), Some(50.0)]);
+
+    let sorted = df.sort(&["Embarked"], &[false])?;
+    let sorted_keys = sorted.column("Embarked")?;
+    assert_eq!(sorted_keys.get_str(0), Some("C".to_string()));
+    assert!(sorted_keys.is_null_at(5));
+
+    // 共享字典时按编码连接，字典不同时按类别字符串连接
+    let ports = DataFrame::new(vec![
+        Box::new(CategoricalSeries::from_strings("port".to_string(), vec![Some("Q"), Some("S"), Some("C")])),
+        Box::new(Series::new("city".to_string(), vec!["Queenstown", "Southampton", "Cherbourg"])),
+    ])?;
+    let joined = df.inner_join(&ports, &["Embarked"], &["port"])?;
+    assert_eq!(joined.height(), 5);
+    let cities: Vec<Option<String>> = (0..joined.height()).map(|i| joined.column("city").unwrap().get_str(i)).collect();
+    assert_eq!(cities[1], Some("Cherbourg".to_string()));
+
+    let same_dictionary = df.head(2).inner_join(&df.tail(2), &["Embarked"], &["Embarked"])?;
+    assert_eq!(same_dictionary.height(), 1);
+
+    Ok(())
+}
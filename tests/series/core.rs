@@ -1,4 +1,4 @@
-use axion_data::{DataType, Series, SeriesCompare, SeriesArithScalar, SeriesArithSeries};
+use axion_data::{new_list_series, Bitmap, DataType, Date, Datetime, Duration, Series, SeriesCompare, SeriesArithScalar, SeriesArithSeries, SeriesTrait, TimeUnit};
 use axion_data::error::{AxionError, AxionResult};
 
 fn create_float_series_with_none() -> Series<f64> {
@@ -22,18 +22,18 @@ fn test_series_creation() {
     assert_eq!(s1.name(), "s1");
     assert_eq!(s1.dtype(), DataType::Int32);
     assert_eq!(s1.len(), 3);
-    assert_eq!(s1.data_internal(), &vec![Some(1), Some(2), Some(3)]);
+    assert_eq!(s1.data_internal(), vec![Some(1), Some(2), Some(3)]);
 
     let s2 = Series::new("s2".into(), [10.0f64, 20.0]);
     assert_eq!(s2.name(), "s2");
     assert_eq!(s2.dtype(), DataType::Float64);
-    assert_eq!(s2.data_internal(), &vec![Some(10.0), Some(20.0)]);
+    assert_eq!(s2.data_internal(), vec![Some(10.0), Some(20.0)]);
 
     // From 创建
     let s3 = Series::from(("s3_from".to_string(), vec![true, false]));
     assert_eq!(s3.name(), "s3_from");
     assert_eq!(s3.dtype(), DataType::Bool);
-    assert_eq!(s3.data_internal(), &vec![Some(true), Some(false)]);
+    assert_eq!(s3.data_internal(), vec![Some(true), Some(false)]);
 
     // 空Series创建
     let s_empty: Series<i32> = Series::new_empty("empty_int".into(), DataType::Int32);
@@ -89,11 +89,11 @@ fn test_modification_operations() {
     s.push(Some(10));
     assert_eq!(s.len(), 1);
     assert_eq!(s.dtype(), DataType::Int32);
-    assert_eq!(s.data_internal(), &vec![Some(10)]);
+    assert_eq!(s.data_internal(), vec![Some(10)]);
     s.push(None);
     s.push(Some(20));
     assert_eq!(s.len(), 3);
-    assert_eq!(s.data_internal(), &vec![Some(10), None, Some(20)]);
+    assert_eq!(s.data_internal(), vec![Some(10), None, Some(20)]);
 
     // Clear操作
     let mut s2 = create_int_series();
@@ -142,14 +142,14 @@ fn test_flags_and_sorting() {
     s2.sort(false);
     assert_eq!(
         s2.data_internal(),
-        &vec![None, None, Some(1), Some(1), Some(3), Some(4)]
+        vec![None, None, Some(1), Some(1), Some(3), Some(4)]
     );
     assert!(s2.is_sorted_ascending());
 
     s2.sort(true);
     assert_eq!(
         s2.data_internal(),
-        &vec![Some(4), Some(3), Some(1), Some(1), None, None]
+        vec![Some(4), Some(3), Some(1), Some(1), None, None]
     );
     assert!(s2.is_sorted_descending());
 }
@@ -165,7 +165,7 @@ fn test_functional_operations() {
     assert_eq!(s_mapped.dtype(), DataType::String);
     assert_eq!(
         s_mapped.data_internal(),
-        &vec![Some("val: 2".to_string()), None, Some("val: 6".to_string())]
+        vec![Some("val: 2".to_string()), None, Some("val: 6".to_string())]
     );
 
     // Filter操作
@@ -174,18 +174,18 @@ fn test_functional_operations() {
     let s_filtered = s_filter.filter(&mask);
     assert_eq!(s_filtered.name(), "s_int");
     assert_eq!(s_filtered.len(), 2);
-    assert_eq!(s_filtered.data_internal(), &vec![Some(10), Some(40)]);
+    assert_eq!(s_filtered.data_internal(), vec![Some(10), Some(40)]);
 
     // Apply操作
     let series_int = Series::new_from_options("nums".into(), vec![Some(1), Some(2), None, Some(4)]);
     let series_plus_10 = series_int.apply(|opt_v| opt_v.map(|v| v + 10));
-    assert_eq!(series_plus_10.data_internal(), &vec![Some(11), Some(12), None, Some(14)]);
+    assert_eq!(series_plus_10.data_internal(), vec![Some(11), Some(12), None, Some(14)]);
 
     let series_str = series_int.apply(|opt_v| opt_v.map(|v| format!("val: {}", v)));
     assert_eq!(series_str.dtype(), DataType::String);
     assert_eq!(
         series_str.data_internal(),
-        &vec![Some("val: 1".to_string()), Some("val: 2".to_string()), None, Some("val: 4".to_string())]
+        vec![Some("val: 1".to_string()), Some("val: 2".to_string()), None, Some("val: 4".to_string())]
     );
 }
 
@@ -255,14 +255,14 @@ fn test_series_compare_operations() -> AxionResult<()> {
     assert_eq!(gt_result.name(), "numbers_gt_scalar");
     assert_eq!(
         gt_result.data_internal(),
-        &vec![Some(false), Some(true), None, Some(true)]
+        vec![Some(false), Some(true), None, Some(true)]
     );
 
     let eq_result = series.eq(20).unwrap();
     assert_eq!(eq_result.name(), "numbers_eq_scalar");
     assert_eq!(
         eq_result.data_internal(),
-        &vec![Some(false), Some(true), None, Some(false)]
+        vec![Some(false), Some(true), None, Some(false)]
     );
 
     // 字符串比较
@@ -270,7 +270,7 @@ fn test_series_compare_operations() -> AxionResult<()> {
     let gt_str_result = series_str.gt("banana".to_string()).unwrap();
     assert_eq!(
         gt_str_result.data_internal(),
-        &vec![Some(false), Some(false), None, Some(true)]
+        vec![Some(false), Some(false), None, Some(true)]
     );
 
     // Series间比较
@@ -281,13 +281,13 @@ fn test_series_compare_operations() -> AxionResult<()> {
     assert_eq!(gt_series_result.name(), "a_gt_series");
     assert_eq!(
         gt_series_result.data_internal(),
-        &vec![Some(false), Some(false), None, None, Some(false)]
+        vec![Some(false), Some(false), None, None, Some(false)]
     );
 
     let eq_series_result = series_a.eq(&series_b)?;
     assert_eq!(
         eq_series_result.data_internal(),
-        &vec![Some(false), Some(true), None, None, Some(false)]
+        vec![Some(false), Some(true), None, None, Some(false)]
     );
 
     // 长度不匹配错误
@@ -313,20 +313,20 @@ fn test_series_arithmetic_operations() -> AxionResult<()> {
     // 与标量运算
     let add_result = series_int.add_scalar(5)?;
     assert_eq!(add_result.name(), "int_add_scalar");
-    assert_eq!(add_result.data_internal(), &vec![Some(15), Some(25), None, Some(45)]);
+    assert_eq!(add_result.data_internal(), vec![Some(15), Some(25), None, Some(45)]);
 
     let mul_result = series_int.mul_scalar(2)?;
     assert_eq!(mul_result.name(), "int_mul_scalar");
-    assert_eq!(mul_result.data_internal(), &vec![Some(20), Some(40), None, Some(80)]);
+    assert_eq!(mul_result.data_internal(), vec![Some(20), Some(40), None, Some(80)]);
 
     let div_result = series_int.div_scalar(5)?;
     assert_eq!(div_result.name(), "int_div_scalar");
-    assert_eq!(div_result.data_internal(), &vec![Some(2), Some(4), None, Some(8)]);
+    assert_eq!(div_result.data_internal(), vec![Some(2), Some(4), None, Some(8)]);
 
     // 浮点数运算
     let series_float = Series::new_from_options("float".into(), vec![Some(10.0), Some(20.5), None, Some(40.0)]);
     let add_float_result = series_float.add_scalar(2.0)?;
-    assert_eq!(add_float_result.data_internal(), &vec![Some(12.0), Some(22.5), None, Some(42.0)]);
+    assert_eq!(add_float_result.data_internal(), vec![Some(12.0), Some(22.5), None, Some(42.0)]);
 
     // Series间运算
     let series_a = Series::new_from_options("a_int".into(), vec![Some(10), Some(20), None, Some(40)]);
@@ -334,10 +334,10 @@ fn test_series_arithmetic_operations() -> AxionResult<()> {
 
     let add_series_result = series_a.add_series(&series_b)?;
     assert_eq!(add_series_result.name(), "a_int_add_series_b_int");
-    assert_eq!(add_series_result.data_internal(), &vec![Some(15), Some(22), None, None]);
+    assert_eq!(add_series_result.data_internal(), vec![Some(15), Some(22), None, None]);
 
     let mul_series_result = series_a.mul_series(&series_b)?;
-    assert_eq!(mul_series_result.data_internal(), &vec![Some(50), Some(40), None, None]);
+    assert_eq!(mul_series_result.data_internal(), vec![Some(50), Some(40), None, None]);
 
     // 长度不匹配错误
     let series_short = Series::new_from_options("short".into(), vec![Some(1), Some(2)]);
@@ -405,7 +405,7 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(contains_o.dtype(), DataType::Bool);
     assert_eq!(
         contains_o.data_internal(),
-        &vec![Some(true), Some(true), None, Some(false), Some(false), Some(true)]
+        vec![Some(true), Some(true), None, Some(false), Some(false), Some(true)]
     );
 
     // StartsWith操作
@@ -413,7 +413,7 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(starts_h.name(), "text_startswith_h");
     assert_eq!(
         starts_h.data_internal(),
-        &vec![Some(true), Some(false), None, Some(false), Some(false), Some(true)]
+        vec![Some(true), Some(false), None, Some(false), Some(false), Some(true)]
     );
 
     // EndsWith操作
@@ -421,14 +421,14 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(ends_o.name(), "text_endswith_o");
     assert_eq!(
         ends_o.data_internal(),
-        &vec![Some(true), Some(false), None, Some(false), Some(false), Some(false)]
+        vec![Some(true), Some(false), None, Some(false), Some(false), Some(false)]
     );
 
     let ends_rust = series_str.str().endswith("rust")?;
     assert_eq!(ends_rust.name(), "text_endswith_rust");
     assert_eq!(
         ends_rust.data_internal(),
-        &vec![Some(false), Some(false), None, Some(true), Some(false), Some(true)]
+        vec![Some(false), Some(false), None, Some(true), Some(false), Some(true)]
     );
 
     // 长度计算
@@ -437,7 +437,7 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(lengths.dtype(), DataType::UInt32);
     assert_eq!(
         lengths.data_internal(),
-        &vec![Some(5), Some(5), None, Some(4), Some(0), Some(10)]
+        vec![Some(5), Some(5), None, Some(4), Some(0), Some(10)]
     );
 
     // 替换操作
@@ -445,7 +445,7 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(replaced.name(), "text_replace");
     assert_eq!(
         replaced.data_internal(),
-        &vec![
+        vec![
             Some("heXXo".to_string()),
             Some("worXd".to_string()),
             None,
@@ -465,14 +465,14 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(lower.name(), "mixed_lower");
     assert_eq!(
         lower.data_internal(),
-        &vec![Some("hello".to_string()), Some("world".to_string()), None, Some("rust".to_string())]
+        vec![Some("hello".to_string()), Some("world".to_string()), None, Some("rust".to_string())]
     );
 
     let upper = series_mixed.str().to_uppercase()?;
     assert_eq!(upper.name(), "mixed_upper");
     assert_eq!(
         upper.data_internal(),
-        &vec![Some("HELLO".to_string()), Some("WORLD".to_string()), None, Some("RUST".to_string())]
+        vec![Some("HELLO".to_string()), Some("WORLD".to_string()), None, Some("RUST".to_string())]
     );
 
     // 去除空白
@@ -491,7 +491,7 @@ fn test_series_string_operations() -> AxionResult<()> {
     assert_eq!(stripped.name(), "whitespace_strip");
     assert_eq!(
         stripped.data_internal(),
-        &vec![
+        vec![
             Some("hello".to_string()),
             Some("world".to_string()),
             None,
@@ -514,10 +514,10 @@ fn test_null_handling() {
     let s = Series::<i32>::new_from_options("a".into(), vec![Some(1), None, Some(3), None]);
     
     let is_null = s.is_null();
-    assert_eq!(is_null.data_internal(), &vec![Some(false), Some(true), Some(false), Some(true)]);
+    assert_eq!(is_null.data_internal(), vec![Some(false), Some(true), Some(false), Some(true)]);
 
     let filled = s.fill_null(99);
-    assert_eq!(filled.data_internal(), &vec![Some(1), Some(99), Some(3), Some(99)]);
+    assert_eq!(filled.data_internal(), vec![Some(1), Some(99), Some(3), Some(99)]);
 }
 
 #[test]
@@ -532,7 +532,7 @@ fn test_parallel_apply() {
     assert_eq!(s_doubled.dtype(), DataType::Int32);
     assert_eq!(
         s_doubled.data_internal(),
-        &vec![Some(2i32), Some(4), None, Some(8), Some(10)]
+        vec![Some(2i32), Some(4), None, Some(8), Some(10)]
     );
 
     // 类型转换
@@ -540,7 +540,7 @@ fn test_parallel_apply() {
     assert_eq!(s_stringified.dtype(), DataType::String);
     assert_eq!(
         s_stringified.data_internal(),
-        &vec![Some("1".to_string()), Some("2".to_string()), None, Some("4".to_string()), Some("5".to_string())]
+        vec![Some("1".to_string()), Some("2".to_string()), None, Some("4".to_string()), Some("5".to_string())]
     );
 
     // 空Series
@@ -556,7 +556,7 @@ fn test_parallel_apply() {
         vec![None, None, None],
     );
     let s_processed = s_all_none.par_apply(|opt_x| opt_x.map(|x| x.powi(2)));
-    assert_eq!(s_processed.data_internal(), &vec![None, None, None]);
+    assert_eq!(s_processed.data_internal(), vec![None, None, None]);
 }
 
 #[test]
@@ -564,4 +564,244 @@ fn test_take_inner() {
     let s = Series::new("take_test".into(), vec![10, 20]);
     let inner_vec = s.take_inner();
     assert_eq!(inner_vec, vec![Some(10), Some(20)]);
-}
\ No newline at end of file
+}
+#[test]
+fn test_validity_bitmap() -> AxionResult<()> {
+    let s = create_float_series_with_none();
+    assert_eq!(s.null_count(), 1);
+    assert_eq!(s.values().len(), 4);
+    assert_eq!(s.validity().len(), 4);
+    assert!(s.is_valid(0));
+    assert!(!s.is_valid(1));
+    assert!(!s.is_valid(10));
+
+    // 切片保留有效性
+    let sliced = s.slice(1, 2);
+    assert!(sliced.is_null_at(0));
+    assert_eq!(sliced.get_str(0), Some("null".to_string()));
+    assert_eq!(sliced.get_str(1), Some("3.5".to_string()));
+
+    // 按索引取值，越界索引视为 null
+    let taken = s.take_indices_option(&[Some(3), None, Some(1)])?;
+    assert_eq!(taken.len(), 3);
+    assert!(!taken.is_null_at(0));
+    assert!(taken.is_null_at(1));
+    assert!(taken.is_null_at(2));
+
+    // 从原始缓冲区构建
+    let validity: Bitmap = vec![true, false, true].into_iter().collect();
+    let built = Series::from_parts("parts".to_string(), vec![1_i64, 0, 3], validity)?;
+    assert_eq!(built.data_internal(), vec![Some(1), None, Some(3)]);
+    assert_eq!(built.dtype(), DataType::Int64);
+
+    let mismatched = Series::from_parts("bad".to_string(), vec![1_i64, 2], Bitmap::new_with_value(3, true));
+    assert!(matches!(mismatched, Err(AxionError::MismatchedLengths { .. })));
+
+    let (values, validity) = built.into_parts();
+    assert_eq!(values.len(), 3);
+    assert_eq!(validity.unset_count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_append_and_extend() -> AxionResult<()> {
+    let mut s = Series::new("a".to_string(), vec![1_i32, 2]);
+    s.sort(false);
+    assert!(s.is_sorted_ascending());
+    s.append(&Series::new_from_options("b".to_string(), vec![None, Some(0)]));
+    s.extend(vec![Some(7), None]);
+    assert_eq!(s.name(), "a");
+    assert_eq!(s.data_internal(), vec![Some(1), Some(2), None, Some(0), Some(7), None]);
+    assert_eq!(s.null_count(), 2);
+    assert!(!s.is_sorted_ascending());
+
+    let mut boxed: Box<dyn SeriesTrait> = Box::new(Series::new("x".to_string(), vec![1.0_f64]));
+    boxed.append_series(&Series::new("y".to_string(), vec![2.0_f64]))?;
+    assert_eq!(boxed.len(), 2);
+    let wrong = boxed.append_series(&Series::new("z".to_string(), vec![3_i32]));
+    assert!(matches!(wrong, Err(AxionError::TypeMismatch { .. })));
+
+    let int_list = |values: Vec<i32>| -> Box<dyn SeriesTrait> { Box::new(Series::new("item".to_string(), values)) };
+    let mut list = new_list_series("lists".to_string(), vec![int_list(vec![1, 2])])?;
+    list.append(&new_list_series("more".to_string(), vec![int_list(vec![3])])?)?;
+    list.extend(vec![None, Some(int_list(vec![]))])?;
+    assert_eq!(list.len(), 4);
+    assert_eq!(list.get_str(1), Some("[3]".to_string()));
+    assert!(list.is_null_at(2));
+
+    let float_item: Box<dyn SeriesTrait> = Box::new(Series::new("item".to_string(), vec![1.0_f64]));
+    assert!(matches!(list.extend(vec![Some(float_item)]), Err(AxionError::TypeMismatch { .. })));
+    assert_eq!(list.len(), 4);
+    let float_list = new_list_series("floats".to_string(), vec![Box::new(Series::new("item".to_string(), vec![1.0_f64])) as Box<dyn SeriesTrait>])?;
+    assert!(matches!(list.append(&float_list), Err(AxionError::TypeMismatch { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_cast_matrix() -> AxionResult<()> {
+    let ints = Series::new_from_options("ints".to_string(), vec![Some(1_i64), None, Some(300), Some(-5)]);
+    let widened: Series<f32> = ints.cast()?;
+    assert_eq!(widened.data_internal(), vec![Some(1.0), None, Some(300.0), Some(-5.0)]);
+    assert_eq!(widened.dtype(), DataType::Float32);
+    assert!(matches!(ints.cast::<u8>(), Err(AxionError::CastError(_))));
+    assert_eq!(ints.cast_lenient::<u8>().data_internal(), vec![Some(1), None, None, None]);
+    assert_eq!(ints.cast::<bool>()?.data_internal(), vec![Some(true), None, Some(true), Some(true)]);
+    assert_eq!(ints.cast::<String>()?.data_internal(), vec![Some("1".to_string()), None, Some("300".to_string()), Some("-5".to_string())]);
+
+    let floats = Series::new("floats".to_string(), vec![1.9_f64, -2.7, f64::NAN, 1e10]);
+    assert_eq!(floats.cast_lenient::<i32>().data_internal(), vec![Some(1), Some(-2), None, None]);
+    assert!(floats.cast::<i64>().is_err());
+    let huge = Series::new("huge".to_string(), vec![1e300_f64, 0.5]);
+    assert_eq!(huge.cast_lenient::<f32>().data_internal(), vec![None, Some(0.5)]);
+    let single = Series::new("single".to_string(), vec![1.1_f32]);
+    assert_eq!(single.cast::<String>()?.data_internal(), vec![Some("1.1".to_string())]);
+
+    let strings = Series::new("strings".to_string(), vec![" 42 ", "x", "TRUE", "3.5"]);
+    assert_eq!(strings.cast_lenient::<i16>().data_internal(), vec![Some(42), None, None, None]);
+    assert_eq!(strings.cast_lenient::<f64>().data_internal(), vec![Some(42.0), None, None, Some(3.5)]);
+    assert_eq!(strings.cast_lenient::<bool>().data_internal(), vec![None, None, Some(true), None]);
+    assert!(matches!(strings.cast::<u64>(), Err(AxionError::CastError(_))));
+
+    let bools = Series::new("bools".to_string(), vec![true, false]);
+    assert_eq!(bools.cast::<u16>()?.data_internal(), vec![Some(1), Some(0)]);
+    assert_eq!(bools.cast::<f64>()?.data_internal(), vec![Some(1.0), Some(0.0)]);
+
+    let column: Box<dyn SeriesTrait> = Box::new(Series::new("age".to_string(), vec!["30", "n/a", "25"]));
+    let strict = column.cast(DataType::Int32);
+    assert!(matches!(strict, Err(AxionError::CastError(_))));
+    let retyped = column.cast_lenient(DataType::UInt8)?;
+    assert_eq!(retyped.dtype(), DataType::UInt8);
+    assert_eq!(retyped.name(), "age");
+    assert!(retyped.is_null_at(1));
+    assert_eq!(retyped.get_str(2), Some("25".to_string()));
+    assert!(matches!(column.cast(DataType::List(Box::new(DataType::Int32))), Err(AxionError::UnsupportedOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_temporal_series() -> AxionResult<()> {
+    let dates = Series::new_from_options("d".to_string(), vec![Date::from_ymd(2024, 2, 29), None, Date::from_ymd(1969, 12, 31)]);
+    assert_eq!(dates.dtype(), DataType::Date);
+    assert_eq!(Date::from_ymd(2023, 2, 29), None);
+    assert_eq!(dates.get_str(0), Some("2024-02-29".to_string()));
+    assert_eq!(dates.get_str(2), Some("1969-12-31".to_string()));
+    assert_eq!(Date::from_ymd(1969, 12, 31).unwrap().ymd(), (1969, 12, 31));
+
+    let ts = Series::new("ts".to_string(), vec![Datetime(1_700_000_000_123), Datetime(-1)])
+        .with_dtype(DataType::Datetime(TimeUnit::Milliseconds, Some("+08:00".to_string())))?;
+    assert_eq!(ts.get_str(0), Some("2023-11-15 06:13:20.123+08:00".to_string()));
+    assert_eq!(ts.get_str(1), Some("1970-01-01 07:59:59.999+08:00".to_string()));
+    let sliced = ts.slice(0, 1);
+    assert_eq!(sliced.dtype(), DataType::Datetime(TimeUnit::Milliseconds, Some("+08:00".to_string())));
+    assert!(matches!(
+        Series::new("bad".to_string(), vec![Datetime(0)]).with_dtype(DataType::Datetime(TimeUnit::Milliseconds, Some("Mars/Base".to_string()))),
+        Err(AxionError::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        Series::new("bad".to_string(), vec![Datetime(0)]).with_dtype(DataType::Date),
+        Err(AxionError::TypeMismatch { .. })
+    ));
+
+    let start = Series::new("start".to_string(), vec![Datetime(0), Datetime(3_600_000_000)]);
+    let end = Series::new("end".to_string(), vec![Datetime(90_061_500), Datetime(0)])
+        .with_dtype(DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    let elapsed = end.sub_datetime(&start)?;
+    assert_eq!(elapsed.dtype(), DataType::Duration(TimeUnit::Milliseconds));
+    assert_eq!(elapsed.get_str(0), Some("P1DT1H1M1.5S".to_string()));
+    assert_eq!(elapsed.get_str(1), Some("-PT1H".to_string()));
+    let shifted = start.add_duration(&elapsed)?;
+    assert_eq!(shifted.data_internal(), vec![Some(Datetime(90_061_500_000)), Some(Datetime(0))]);
+    assert_eq!(shifted.sub_duration(&elapsed)?.data_internal(), start.data_internal());
+
+    let utc = start.clone().with_dtype(DataType::Datetime(TimeUnit::Microseconds, Some("UTC".to_string())))?;
+    assert!(matches!(utc.sub_datetime(&start), Err(AxionError::TypeMismatch { .. })));
+
+    let days = dates.sub_date(&Series::new("base".to_string(), vec![Date(0), Date(0), Date(0)]))?;
+    assert_eq!(days.get_str(0), Some("P19782D".to_string()));
+    assert!(days.is_null_at(1));
+    let next = dates.add_duration(&Series::new("step".to_string(), vec![Duration(36 * 3_600_000_000); 3]))?;
+    assert_eq!(next.get_str(0), Some("2024-03-01".to_string()));
+    assert_eq!(Duration(5) - Duration(7), -Duration(2));
+
+    Ok(())
+}
+
+#[test]
+fn test_int128_series() -> AxionResult<()> {
+    let big = Series::new_from_options("big".to_string(), vec![Some(i128::MAX - 1), None, Some(-(1_i128 << 100)), Some(7)]);
+    assert_eq!(big.dtype(), DataType::Int128);
+    assert!(big.dtype().is_numeric());
+    assert_eq!(big.get_str(0), Some((i128::MAX - 1).to_string()));
+    assert_eq!(big.min(), Some(-(1_i128 << 100)));
+    assert_eq!(big.add_scalar(1)?.get(0), Some(&i128::MAX));
+    let small = Series::new("small".to_string(), vec![1_i128 << 60, -3]);
+    assert_eq!(small.mul_series(&small)?.data_internal(), vec![Some(1_i128 << 120), Some(9)]);
+
+    let hashes = Series::new("hash".to_string(), vec![u128::MAX, 3, 12]);
+    assert_eq!(hashes.dtype(), DataType::UInt128);
+    assert_eq!(hashes.max(), Some(u128::MAX));
+    assert_eq!(hashes.rem_scalar(5)?.data_internal(), vec![Some(u128::MAX % 5), Some(3), Some(2)]);
+    assert_eq!(hashes.get_as_f64(1)?, Some(3.0));
+
+    assert_eq!(hashes.cast::<String>()?.get(0), Some(&u128::MAX.to_string()));
+    assert_eq!(hashes.cast_lenient::<i128>().data_internal(), vec![None, Some(3), Some(12)]);
+    assert_eq!(big.cast_lenient::<u128>().data_internal(), vec![Some((i128::MAX - 1) as u128), None, None, Some(7)]);
+    assert_eq!(hashes.cast_lenient::<f64>().get(0), Some(&(u128::MAX as f64)));
+
+    let strings: Box<dyn SeriesTrait> = Box::new(Series::new("s".to_string(), vec![u128::MAX.to_string(), "-1".to_string()]));
+    let parsed = strings.cast_lenient(DataType::UInt128)?;
+    assert_eq!(parsed.dtype(), DataType::UInt128);
+    assert_eq!(parsed.get_str(0), Some(u128::MAX.to_string()));
+    assert!(parsed.is_null_at(1));
+
+    let mut sorted = hashes.clone();
+    sorted.sort(false);
+    assert_eq!(sorted.data_internal(), vec![Some(3), Some(12), Some(u128::MAX)]);
+
+    Ok(())
+}
+
+#[test]
+fn test_temporal_cast() -> AxionResult<()> {
+    let dates = Series::new_from_options("d".to_string(), vec![Date::from_ymd(1970, 1, 1), None, Date::from_ymd(2024, 2, 29)]);
+    let strings = SeriesTrait::cast(&dates, DataType::String)?;
+    assert_eq!(strings.get_str(0), Some("1970-01-01".to_string()));
+    assert_eq!(strings.get_str(2), Some("2024-02-29".to_string()));
+    let days = SeriesTrait::cast(&dates, DataType::Int64)?;
+    assert_eq!(days.get_str(2), Some("19782".to_string()));
+    assert!(matches!(SeriesTrait::cast(&dates, DataType::Bool), Err(AxionError::UnsupportedOperation(_))));
+
+    // Date 与 Datetime 之间按当地时间换算
+    let shanghai = DataType::Datetime(TimeUnit::Milliseconds, Some("+08:00".to_string()));
+    let midnight = SeriesTrait::cast(&dates, shanghai.clone())?;
+    assert_eq!(midnight.dtype(), shanghai);
+    assert_eq!(midnight.get_str(0), Some("1970-01-01 00:00:00+08:00".to_string()));
+    let back = SeriesTrait::cast(midnight.as_ref(), DataType::Date)?;
+    assert_eq!(back.get_str(2), Some("2024-02-29".to_string()));
+    let utc = SeriesTrait::cast(midnight.as_ref(), DataType::Datetime(TimeUnit::Microseconds, None))?;
+    assert_eq!(utc.get_str(0), Some("1969-12-31 16:00:00".to_string()));
+
+    // 字符串按 ISO-8601 解析，严格模式下无法解析的值报错
+    let text = Series::new_from_options("t".to_string(), vec![Some("2024-03-10T16:30:00Z".to_string()), None]);
+    let parsed = SeriesTrait::cast(&text, DataType::Datetime(TimeUnit::Milliseconds, None))?;
+    assert_eq!(parsed.get_str(0), Some("2024-03-10 16:30:00".to_string()));
+    let round_trip = SeriesTrait::cast(strings.as_ref(), DataType::Date)?;
+    assert_eq!(round_trip.get_str(2), Some("2024-02-29".to_string()));
+    let bad = Series::new("b".to_string(), vec!["2024-02-30"]);
+    assert!(matches!(SeriesTrait::cast(&bad, DataType::Date), Err(AxionError::CastError(_))));
+    assert!(SeriesTrait::cast_lenient(&bad, DataType::Date)?.is_null_at(0));
+
+    // 整数直接作为底层值
+    let raw = Series::new("r".to_string(), vec![90_000_i64]);
+    assert_eq!(SeriesTrait::cast(&raw, DataType::Duration(TimeUnit::Milliseconds))?.get_str(0), Some("PT1M30S".to_string()));
+    let waits = Series::new("w".to_string(), vec![Duration(90_000)]).with_dtype(DataType::Duration(TimeUnit::Milliseconds))?;
+    let micros = SeriesTrait::cast(&waits, DataType::Duration(TimeUnit::Microseconds))?;
+    assert_eq!(micros.get_str(0), Some("PT1M30S".to_string()));
+    assert_eq!(SeriesTrait::cast(micros.as_ref(), DataType::Int64)?.get_str(0), Some("90000000".to_string()));
+    assert!(matches!(SeriesTrait::cast(&waits, DataType::Date), Err(AxionError::UnsupportedOperation(_))));
+
+    Ok(())
+}
+
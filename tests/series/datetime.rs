@@ -0,0 +1,80 @@
+use axion_data::series::datetime::DatetimeAccessor;
+use axion_data::{AxionError, AxionResult, DataType, Date, Datetime, Series, SeriesTrait, TimeUnit};
+
+fn create_timestamp_strings() -> Series<String> {
+    Series::new_from_options(
+        "ts".to_string(),
+        vec![
+            Some("2024-02-29T10:45:30.5".to_string()),
+            Some("2023-12-31 23:59:59".to_string()),
+            None,
+            Some("not a date".to_string()),
+        ],
+    )
+}
+
+#[test]
+fn test_dt_components_from_strings() -> AxionResult<()> {
+    let dt = create_timestamp_strings().dt();
+    assert_eq!(dt.year()?.data_internal(), vec![Some(2024), Some(2023), None, None]);
+    assert_eq!(dt.month()?.data_internal(), vec![Some(2), Some(12), None, None]);
+    assert_eq!(dt.day()?.data_internal(), vec![Some(29), Some(31), None, None]);
+    assert_eq!(dt.hour()?.data_internal(), vec![Some(10), Some(23), None, None]);
+    assert_eq!(dt.weekday()?.data_internal(), vec![Some(4), Some(7), None, None]);
+    assert_eq!(dt.ordinal_day()?.data_internal(), vec![Some(60), Some(365), None, None]);
+    assert_eq!(dt.year()?.name(), "ts_year");
+
+    let truncated = dt.truncate("1h")?;
+    assert_eq!(truncated.dtype(), DataType::Datetime(TimeUnit::Microseconds, None));
+    assert_eq!(truncated.get_str(0), Some("2024-02-29 10:00:00".to_string()));
+    assert!(truncated.is_null_at(3));
+    assert_eq!(dt.truncate("15m")?.get_str(1), Some("2023-12-31 23:45:00".to_string()));
+    assert_eq!(dt.truncate("1w")?.get_str(0), Some("2024-02-26 00:00:00".to_string()));
+    assert_eq!(dt.truncate("3mo")?.get_str(0), Some("2024-01-01 00:00:00".to_string()));
+    assert_eq!(dt.round("1h")?.get_str(0), Some("2024-02-29 11:00:00".to_string()));
+    assert_eq!(dt.round("1d")?.get_str(1), Some("2024-01-01 00:00:00".to_string()));
+    assert!(matches!(dt.truncate("1mo2d"), Err(AxionError::InvalidArgument(_))));
+    assert!(matches!(dt.truncate("1x"), Err(AxionError::InvalidArgument(_))));
+
+    assert_eq!(
+        dt.strftime("%a %d %b %Y, %I:%M:%S %p (day %j)")?.data_internal(),
+        vec![
+            Some("Thu 29 Feb 2024, 10:45:30 AM (day 060)".to_string()),
+            Some("Sun 31 Dec 2023, 11:59:59 PM (day 365)".to_string()),
+            None,
+            None,
+        ]
+    );
+    assert!(matches!(dt.strftime("%Q"), Err(AxionError::InvalidArgument(_))));
+    assert_eq!(dt.epoch("ms")?.get(0), Some(&1_709_203_530_500));
+    assert_eq!(dt.epoch("d")?.get(1), Some(&19_722));
+    assert!(dt.epoch("weeks").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dt_on_native_temporal_series() -> AxionResult<()> {
+    // 2024-03-10 16:30:00 UTC
+    let ts = Series::new("ts".to_string(), vec![Datetime(1_710_088_200_000)])
+        .with_dtype(DataType::Datetime(TimeUnit::Milliseconds, Some("+08:00".to_string())))?;
+    let dt = ts.dt();
+    assert_eq!(dt.day()?.get(0), Some(&11));
+    assert_eq!(dt.hour()?.get(0), Some(&0));
+    let daily = dt.truncate("1d")?;
+    assert_eq!(daily.dtype(), ts.dtype());
+    assert_eq!(daily.get_str(0), Some("2024-03-11 00:00:00+08:00".to_string()));
+    assert_eq!(dt.strftime("%F %T%z")?.get(0), Some(&"2024-03-11 00:30:00+0800".to_string()));
+    assert_eq!(dt.epoch("s")?.get(0), Some(&1_710_088_200));
+
+    let dates = Series::new("d".to_string(), vec![Date::from_ymd(2024, 8, 17).unwrap()]);
+    assert_eq!(dates.dt().weekday()?.get(0), Some(&6));
+    assert_eq!(dates.dt().truncate("1y")?.get_str(0), Some("2024-01-01 00:00:00".to_string()));
+
+    let column: Box<dyn SeriesTrait> = Box::new(dates);
+    assert_eq!(DatetimeAccessor::from_series(column.as_ref())?.month()?.get(0), Some(&8));
+    let numbers: Box<dyn SeriesTrait> = Box::new(Series::new("n".to_string(), vec![1_i64]));
+    assert!(matches!(DatetimeAccessor::from_series(numbers.as_ref()), Err(AxionError::TypeMismatch { .. })));
+
+    Ok(())
+}
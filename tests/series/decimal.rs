@@ -0,0 +1,113 @@
+use axion_data::{AxionError, AxionResult, DataType, Decimal, Series, SeriesArithScalar, SeriesArithSeries, SeriesTrait};
+
+fn create_prices() -> AxionResult<Series<Decimal>> {
+    Series::<Decimal>::from_strs(
+        "price".to_string(),
+        vec![Some("0.10"), Some("0.20"), None, Some("-1.05"), Some("12")],
+        10,
+        2,
+    )
+}
+
+#[test]
+fn test_decimal_parse_and_format() -> AxionResult<()> {
+    let prices = create_prices()?;
+    assert_eq!(prices.dtype(), DataType::Decimal(10, 2));
+    assert_eq!(prices.data_internal(), vec![Some(Decimal(10)), Some(Decimal(20)), None, Some(Decimal(-105)), Some(Decimal(1200))]);
+    assert_eq!(prices.get_str(0), Some("0.10".to_string()));
+    assert_eq!(prices.get_str(2), Some("null".to_string()));
+    assert_eq!(prices.get_str(3), Some("-1.05".to_string()));
+    assert_eq!(prices.get_str(4), Some("12.00".to_string()));
+
+    assert_eq!(Decimal::parse("-0.5", 3), Some(Decimal(-500)));
+    assert_eq!(Decimal::parse("1.230", 2), Some(Decimal(123)));
+    assert_eq!(Decimal::parse("1.235", 2), None);
+    assert_eq!(Decimal(-5).to_string_with_scale(2), "-0.05");
+
+    let too_precise = Series::<Decimal>::from_strs("p".to_string(), vec![Some("0.125")], 10, 2);
+    assert!(matches!(too_precise, Err(AxionError::CastError(_))));
+    let too_wide = Series::<Decimal>::from_strs("p".to_string(), vec![Some("1000")], 5, 2);
+    assert!(matches!(too_wide, Err(AxionError::CastError(_))));
+    let invalid = Series::<Decimal>::from_strs("p".to_string(), vec![Some("1")], 39, 2);
+    assert!(matches!(invalid, Err(AxionError::InvalidArgument(_))));
+    assert!(Series::new("p".to_string(), vec![Decimal(1)]).with_dtype(DataType::Decimal(4, 5)).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_exact_sum_and_mean() -> AxionResult<()> {
+    let values = vec![Some("0.1"); 10];
+    let tenths = Series::<Decimal>::from_strs("x".to_string(), values, 10, 1)?;
+    assert_eq!(tenths.sum(), Some(Decimal(10)));
+    assert_eq!(tenths.sum().unwrap().to_string_with_scale(tenths.scale()), "1.0");
+
+    let prices = create_prices()?;
+    // (0.10 + 0.20 - 1.05 + 12.00) / 4 = 2.8125，四舍五入到 2 位小数
+    assert_eq!(prices.mean_decimal(), Some(Decimal(281)));
+    assert_eq!(prices.get_as_f64(3)?, Some(-1.05));
+
+    let empty = Series::<Decimal>::from_strs("e".to_string(), vec![None::<&str>], 10, 2)?;
+    assert_eq!(empty.mean_decimal(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_arithmetic() -> AxionResult<()> {
+    let a = Series::<Decimal>::from_strs("a".to_string(), vec![Some("1.5"), Some("10.25"), None, Some("7")], 6, 2)?;
+    let b = Series::<Decimal>::from_strs("b".to_string(), vec![Some("0.125"), Some("3"), Some("1"), Some("0")], 6, 3)?;
+
+    let sum = a.add_series(&b)?;
+    assert_eq!(sum.name(), "a_add_series_b");
+    assert_eq!(sum.dtype(), DataType::Decimal(8, 3));
+    assert_eq!(sum.get_str(0), Some("1.625".to_string()));
+    assert!(sum.is_null_at(2));
+
+    let product = a.mul_series(&b)?;
+    assert_eq!(product.scale(), 5);
+    assert_eq!(product.get_str(1), Some("30.75000".to_string()));
+
+    let quotient = a.div_series(&b)?;
+    assert_eq!(quotient.scale(), 6);
+    assert_eq!(quotient.get_str(1), Some("3.416667".to_string()));
+    assert!(quotient.is_null_at(3));
+
+    let remainder = a.rem_series(&b)?;
+    assert_eq!(remainder.get_str(1), Some("1.250".to_string()));
+
+    let shifted = a.sub_scalar(Decimal(50))?;
+    assert_eq!(shifted.dtype(), DataType::Decimal(7, 2));
+    assert_eq!(shifted.get_str(0), Some("1.00".to_string()));
+    assert_eq!(a.div_scalar(Decimal(0))?.null_count(), a.len());
+
+    let short = Series::<Decimal>::from_strs("s".to_string(), vec![Some("1")], 6, 2)?;
+    assert!(matches!(a.add_series(&short), Err(AxionError::MismatchedLengths { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_decimal_cast() -> AxionResult<()> {
+    let floats = Series::new("f".to_string(), vec![0.1_f64, 2.675, -3.0]);
+    let decimals = SeriesTrait::cast(&floats, DataType::Decimal(10, 2))?;
+    assert_eq!(decimals.dtype(), DataType::Decimal(10, 2));
+    assert_eq!(decimals.get_str(1), Some("2.68".to_string()));
+
+    let ints = decimals.cast(DataType::Int64)?;
+    let ints = ints.as_any().downcast_ref::<Series<i64>>().unwrap();
+    assert_eq!(ints.data_internal(), vec![Some(0), Some(2), Some(-3)]);
+
+    let strings = decimals.cast(DataType::String)?;
+    assert_eq!(strings.get_str(2), Some("-3.00".to_string()));
+
+    let rescaled = decimals.cast(DataType::Decimal(10, 1))?;
+    assert_eq!(rescaled.get_str(1), Some("2.7".to_string()));
+
+    let words = Series::new("w".to_string(), vec!["1.5".to_string(), "abc".to_string()]);
+    assert!(SeriesTrait::cast(&words, DataType::Decimal(10, 2)).is_err());
+    let lenient = SeriesTrait::cast_lenient(&words, DataType::Decimal(10, 2))?;
+    assert!(lenient.is_null_at(1));
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use axion_data::{new_list_series, AxionError, AxionResult, DataType, Date, Decimal, ListSeries, Series, SeriesTrait};
+
+fn int_list(values: Vec<Option<i64>>) -> Option<Box<dyn SeriesTrait>> {
+    Some(Box::new(Series::new_from_options("item".to_string(), values)))
+}
+
+fn create_scores() -> ListSeries {
+    ListSeries::new(
+        "scores".to_string(),
+        vec![
+            int_list(vec![Some(3), Some(1), Some(3), None]),
+            None,
+            int_list(vec![]),
+            int_list(vec![Some(7), Some(-2)]),
+        ],
+        DataType::Int64,
+    )
+}
+
+#[test]
+fn test_list_len_get_and_contains() -> AxionResult<()> {
+    let scores = create_scores();
+
+    let lengths = scores.list().len()?;
+    assert_eq!(lengths.name(), "scores_len");
+    assert_eq!(lengths.data_internal(), vec![Some(4), None, Some(0), Some(2)]);
+
+    let first = scores.list().get(0)?;
+    let first = first.as_any().downcast_ref::<Series<i64>>().unwrap();
+    assert_eq!(first.data_internal(), vec![Some(3), None, None, Some(7)]);
+    let last = scores.list().get(-1)?;
+    let last = last.as_any().downcast_ref::<Series<i64>>().unwrap();
+    assert_eq!(last.data_internal(), vec![None, None, None, Some(-2)]);
+    let out_of_range = scores.list().get(10)?;
+    assert!((0..out_of_range.len()).all(|idx| out_of_range.is_null_at(idx)));
+
+    let has_seven = scores.list().contains(7_i64)?;
+    assert_eq!(has_seven.data_internal(), vec![Some(false), None, Some(false), Some(true)]);
+    assert!(matches!(scores.list().contains(7_i32), Err(AxionError::TypeMismatch { .. })));
+
+    Ok(())
+}
+
+#[test]
+fn test_list_aggregations() -> AxionResult<()> {
+    let scores = create_scores();
+
+    let sums = scores.list().sum()?;
+    assert_eq!(sums.name(), "scores_sum");
+    let sums = sums.as_any().downcast_ref::<Series<i64>>().unwrap();
+    assert_eq!(sums.data_internal(), vec![Some(7), None, None, Some(5)]);
+
+    let means = scores.list().mean()?;
+    let means = means.as_any().downcast_ref::<Series<f64>>().unwrap();
+    assert_eq!(means.data_internal(), vec![Some(7.0 / 3.0), None, None, Some(2.5)]);
+
+    let mins = scores.list().min()?;
+    assert_eq!(mins.get_str(0), Some("1".to_string()));
+    assert_eq!(mins.get_str(3), Some("-2".to_string()));
+    let maxs = scores.list().max()?;
+    assert_eq!(maxs.get_str(0), Some("3".to_string()));
+    assert!(maxs.is_null_at(2));
+
+    let words = new_list_series(
+        "words".to_string(),
+        vec![Box::new(Series::new("w".to_string(), vec!["a".to_string(), "b".to_string()]))],
+    )?;
+    assert!(matches!(words.list().sum(), Err(AxionError::UnsupportedOperation(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_list_join_unique_and_sort() -> AxionResult<()> {
+    let scores = create_scores();
+
+    let joined = scores.list().join("-")?;
+    assert_eq!(joined.data_internal(), vec![Some("3-1-3".to_string()), None, Some(String::new()), Some("7--2".to_string())]);
+
+    let unique = scores.list().unique()?;
+    assert_eq!(unique.name(), "scores");
+    assert_eq!(unique.dtype(), DataType::List(Box::new(DataType::Int64)));
+    assert_eq!(unique.get_str(0), Some("[3, 1, null]".to_string()));
+    assert!(unique.is_null_at(1));
+
+    let ascending = scores.list().sort(false)?;
+    assert_eq!(ascending.get_str(0), Some("[1, 3, 3, null]".to_string()));
+    assert_eq!(ascending.get_str(3), Some("[-2, 7]".to_string()));
+    let descending = scores.list().sort(true)?;
+    assert_eq!(descending.get_str(0), Some("[null, 3, 3, 1]".to_string()));
+    assert_eq!(descending.get_str(2), Some("[]".to_string()));
+
+    Ok(())
+}
+
+#[test]
+fn test_list_unique_decimal_and_temporal() -> AxionResult<()> {
+    let prices = new_list_series(
+        "prices".to_string(),
+        vec![Box::new(Series::<Decimal>::from_strs("p".to_string(), vec![Some("1.10"), Some("1.1"), Some("2")], 10, 2)?)],
+    )?;
+    let unique = prices.list().unique()?;
+    assert_eq!(unique.dtype(), DataType::List(Box::new(DataType::Decimal(10, 2))));
+    assert_eq!(unique.get_str(0), Some("[1.10, 2.00]".to_string()));
+
+    let days = new_list_series(
+        "days".to_string(),
+        vec![Box::new(Series::new_from_options("d".to_string(), vec![Date::from_ymd(2024, 3, 1), None, Date::from_ymd(2024, 3, 1)]))],
+    )?;
+    let unique = days.list().unique()?;
+    assert_eq!(unique.dtype(), DataType::List(Box::new(DataType::Date)));
+    assert_eq!(unique.get_str(0), Some("[2024-03-01, null]".to_string()));
+
+    Ok(())
+}
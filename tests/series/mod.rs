@@ -1 +1,6 @@
-pub mod core;
\ No newline at end of file
+pub mod categorical;
+pub mod core;
+pub mod datetime;
+pub mod decimal;
+pub mod struct_series;
+pub mod list;
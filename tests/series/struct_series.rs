@@ -0,0 +1,158 @@
+use axion_data::{
+    AxionError, AxionResult, Bitmap, CategoricalSeries, DataFrame, DataType, Datetime, Decimal, Series, SeriesTrait,
+    StructSeries, TimeUnit,
+};
+
+fn create_passengers() -> AxionResult<StructSeries> {
+    let passengers = StructSeries::new(
+        "passenger".to_string(),
+        vec![
+            Box::new(Series::new("name".to_string(), vec!["Braund".to_string(), "Cumings".to_string(), "Heikkinen".to_string()])),
+            Box::new(Series::new_from_options("age".to_string(), vec![Some(22_i64), Some(38), None])),
+        ],
+    )?;
+    passengers.with_validity(Bitmap::from_iter([true, false, true]))
+}
+
+#[test]
+fn test_struct_construction_and_field_access() -> AxionResult<()> {
+    let passengers = create_passengers()?;
+    assert_eq!(
+        passengers.dtype(),
+        DataType::Struct(vec![("name".to_string(), DataType::String), ("age".to_string(), DataType::Int64)])
+    );
+    assert_eq!(passengers.field_names(), vec!["name", "age"]);
+    assert_eq!(passengers.get_str(0), Some("{name: Braund, age: 22}".to_string()));
+    assert_eq!(passengers.get_str(1), Some("null".to_string()));
+    assert_eq!(passengers.get_str(2), Some("{name: Heikkinen, age: null}".to_string()));
+    assert!(passengers.is_null_at(1));
+
+    // 整行为 null 的位置在字段中也为 null
+    let names = passengers.field("name")?;
+    assert_eq!(names.get_str(0), Some("Braund".to_string()));
+    assert!(names.is_null_at(1));
+    assert!(matches!(passengers.field("fare"), Err(AxionError::ColumnNotFound(_))));
+
+    assert!(matches!(StructSeries::new("s".to_string(), vec![]), Err(AxionError::NoColumnsProvided)));
+    let mismatched = StructSeries::new(
+        "s".to_string(),
+        vec![Box::new(Series::new("a".to_string(), vec![1_i32, 2])), Box::new(Series::new("b".to_string(), vec![1_i32]))],
+    );
+    assert!(matches!(mismatched, Err(AxionError::MismatchedLengths { .. })));
+    let duplicated = StructSeries::new(
+        "s".to_string(),
+        vec![Box::new(Series::new("a".to_string(), vec![1_i32])), Box::new(Series::new("a".to_string(), vec![2_i32]))],
+    );
+    assert!(matches!(duplicated, Err(AxionError::DuplicateColumnName(_))));
+
+    Ok(())
+}
+
+#[test]
+fn test_struct_take_filter_and_sort() -> AxionResult<()> {
+    let passengers = create_passengers()?;
+
+    let taken = passengers.take_indices(&[2, 0])?;
+    assert_eq!(taken.get_str(0), Some("{name: Heikkinen, age: null}".to_string()));
+    assert_eq!(taken.get_str(1), Some("{name: Braund, age: 22}".to_string()));
+
+    let mask = Series::new("mask".to_string(), vec![false, true, true]);
+    let filtered = passengers.filter(&mask)?;
+    assert_eq!(filtered.len(), 2);
+    assert!(filtered.is_null_at(0));
+
+    let df = DataFrame::new(vec![Box::new(passengers)])?;
+    let sorted = df.sort(&["passenger"], &[false])?;
+    let column = sorted.column("passenger")?;
+    assert_eq!(column.get_str(0), Some("{name: Braund, age: 22}".to_string()));
+    assert_eq!(column.get_str(1), Some("{name: Heikkinen, age: null}".to_string()));
+    assert!(column.is_null_at(2));
+
+    Ok(())
+}
+
+#[test]
+fn test_struct_serde_round_trip() -> AxionResult<()> {
+    let fare = Series::<Decimal>::from_strs("fare".to_string(), vec![Some("7.25"), None, Some("71.28")], 10, 2)?;
+    let boarded = Series::new("boarded".to_string(), vec![Datetime(0), Datetime(1_000_000), Datetime(-1)])
+        .with_dtype(DataType::Datetime(TimeUnit::Milliseconds, Some("UTC".to_string())))?;
+    let sex = CategoricalSeries::from_strings("sex".to_string(), vec![Some("male"), Some("female"), None]);
+    let inner = StructSeries::new("ticket".to_string(), vec![Box::new(fare), Box::new(boarded)])?
+        .with_validity(Bitmap::from_iter([true, true, false]))?;
+    let record = StructSeries::new("record".to_string(), vec![Box::new(inner), Box::new(sex)])?
+        .with_validity(Bitmap::from_iter([true, false, true]))?;
+
+    let json = serde_json::to_string(&record).map_err(|e| AxionError::Other(e.to_string()))?;
+    let restored: StructSeries = serde_json::from_str(&json).map_err(|e| AxionError::Other(e.to_string()))?;
+
+    assert_eq!(restored.name(), "record");
+    assert_eq!(restored.dtype(), record.dtype());
+    for idx in 0..record.len() {
+        assert_eq!(restored.get_str(idx), record.get_str(idx));
+    }
+    let restored_ticket = restored.field("ticket")?;
+    let restored_ticket = restored_ticket.as_any().downcast_ref::<StructSeries>().unwrap();
+    assert_eq!(restored_ticket.field("fare")?.get_str(0), Some("7.25".to_string()));
+    assert!(restored_ticket.field("fare")?.is_null_at(2));
+
+    assert!(serde_json::from_str::<StructSeries>("{\"name\": \"x\"}").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_struct_serde_non_finite_floats() -> AxionResult<()> {
+    let wide = Series::new_from_options("wide".to_string(), vec![Some(f64::NAN), Some(f64::INFINITY), Some(f64::NEG_INFINITY), None, Some(-0.5)]);
+    let narrow = Series::new_from_options("narrow".to_string(), vec![Some(f32::NEG_INFINITY), None, Some(f32::NAN), Some(1.5), Some(f32::INFINITY)]);
+    let record = StructSeries::new("record".to_string(), vec![Box::new(wide), Box::new(narrow)])?;
+
+    let json = serde_json::to_string(&record).map_err(|e| AxionError::Other(e.to_string()))?;
+    let restored: StructSeries = serde_json::from_str(&json).map_err(|e| AxionError::Other(e.to_string()))?;
+
+    let wide = restored.field("wide")?;
+    let wide = wide.as_any().downcast_ref::<Series<f64>>().unwrap();
+    assert!(wide.get(0).unwrap().is_nan());
+    assert_eq!(wide.get(1), Some(&f64::INFINITY));
+    assert_eq!(wide.get(2), Some(&f64::NEG_INFINITY));
+    // 缺失值仍然还原为 null，而不是与 NaN 混淆
+    assert!(wide.is_null_at(3));
+    assert_eq!(wide.get(4), Some(&-0.5));
+
+    let narrow = restored.field("narrow")?;
+    let narrow = narrow.as_any().downcast_ref::<Series<f32>>().unwrap();
+    assert_eq!(narrow.get(0), Some(&f32::NEG_INFINITY));
+    assert!(narrow.is_null_at(1));
+    assert!(narrow.get(2).unwrap().is_nan());
+    assert_eq!(narrow.get(3), Some(&1.5));
+    assert_eq!(narrow.get(4), Some(&f32::INFINITY));
+
+    let unknown = json.replacen("\"NaN\"", "\"nan?\"", 1);
+    assert!(serde_json::from_str::<StructSeries>(&unknown).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_dataframe_unnest() -> AxionResult<()> {
+    let passengers = create_passengers()?;
+    let ids = Series::new("id".to_string(), vec![1_i64, 2, 3]);
+    let survived = Series::new("survived".to_string(), vec![false, true, true]);
+    let df = DataFrame::new(vec![Box::new(ids), Box::new(passengers), Box::new(survived)])?;
+
+    let flat = df.unnest(&["passenger"])?;
+    assert_eq!(flat.columns_names(), vec!["id", "name", "age", "survived"]);
+    assert_eq!(flat.column("name")?.get_str(0), Some("Braund".to_string()));
+    assert!(flat.column("name")?.is_null_at(1));
+    assert!(flat.column("age")?.is_null_at(2));
+
+    assert!(matches!(df.unnest(&["id"]), Err(AxionError::TypeMismatch { .. })));
+    assert!(matches!(df.unnest(&["missing"]), Err(AxionError::ColumnNotFound(_))));
+
+    let clashing = DataFrame::new(vec![
+        Box::new(Series::new("name".to_string(), vec![0_i32, 1, 2])),
+        Box::new(create_passengers()?),
+    ])?;
+    assert!(matches!(clashing.unnest(&["passenger"]), Err(AxionError::DuplicateColumnName(_))));
+
+    Ok(())
+}